@@ -0,0 +1,152 @@
+use crate::display::primitives::Vec3;
+use cgmath::InnerSpace;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Wind, in the horizontal plane. The wind varies sinusoidally around the mean velocity if a
+/// period is specified, this is deterministic such that matches can be reproduced.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct WindConfig {
+    /// Mean wind velocity in x, meters per second.
+    #[serde(default)]
+    pub x: f32,
+    /// Mean wind velocity in y, meters per second.
+    #[serde(default)]
+    pub y: f32,
+    /// Amplitude of the variation in x.
+    #[serde(default)]
+    pub variation_x: f32,
+    /// Amplitude of the variation in y, this is a quarter period out of phase with x.
+    #[serde(default)]
+    pub variation_y: f32,
+    /// Period of the variation in seconds, the wind is constant if this is zero.
+    #[serde(default)]
+    pub period: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct AtmosphereConfig {
+    /// Quadratic drag coefficient for projectiles, the deceleration is this coefficient times the
+    /// square of the airspeed. Zero disables drag.
+    #[serde(default)]
+    pub drag_coefficient: f32,
+    #[serde(default)]
+    pub wind: WindConfig,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Atmosphere {
+    config: AtmosphereConfig,
+}
+
+impl Atmosphere {
+    pub fn new(config: AtmosphereConfig) -> Self {
+        Atmosphere { config }
+    }
+
+    pub fn config(&self) -> AtmosphereConfig {
+        self.config
+    }
+
+    pub fn drag_coefficient(&self) -> f32 {
+        self.config.drag_coefficient
+    }
+
+    /// The wind velocity at the provided time.
+    pub fn wind(&self, time: f32) -> Vec3 {
+        let wind = self.config.wind;
+        let (sin, cos) = if wind.period > 0.0 {
+            (2.0 * std::f32::consts::PI * time / wind.period).sin_cos()
+        } else {
+            (0.0, 0.0)
+        };
+        Vec3::new(
+            wind.x + wind.variation_x * sin,
+            wind.y + wind.variation_y * cos,
+            0.0,
+        )
+    }
+
+    /// The acceleration due to drag on an object with this velocity, at the provided time.
+    pub fn drag_acceleration(&self, velocity: Vec3, time: f32) -> Vec3 {
+        let airspeed = velocity - self.wind(time);
+        -self.config.drag_coefficient * airspeed.magnitude() * airspeed
+    }
+}
+impl Component for Atmosphere {}
+
+use crate::components::clock::Clock;
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::wind::*;
+
+#[derive(Default)]
+pub struct WindModule {}
+
+impl WindModule {
+    pub fn new() -> Self {
+        WindModule {}
+    }
+}
+
+impl UnitModule for WindModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        let time = world
+            .component_iter::<Clock>()
+            .next()
+            .map(|(_entity, clock)| clock.elapsed_as_f32())
+            .unwrap_or(0.0);
+        let (wind, drag_coefficient) = world
+            .component_iter::<Atmosphere>()
+            .next()
+            .map(|(_entity, atmosphere)| (atmosphere.wind(time), atmosphere.drag_coefficient()))
+            .unwrap_or((Vec3::new(0.0, 0.0, 0.0), 0.0));
+        registers.insert(REG_WIND_X, Register::new_f32("x", wind.x).read_only());
+        registers.insert(REG_WIND_Y, Register::new_f32("y", wind.y).read_only());
+        registers.insert(
+            REG_WIND_DRAG_COEFFICIENT,
+            Register::new_f32("drag_coefficient", drag_coefficient).read_only(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+    #[test]
+    fn test_atmosphere_wind_drag() {
+        let atmosphere = Atmosphere::new(AtmosphereConfig {
+            drag_coefficient: 0.1,
+            wind: WindConfig {
+                x: 1.0,
+                y: 0.0,
+                variation_x: 0.5,
+                variation_y: 0.5,
+                period: 4.0,
+            },
+        });
+        approx_equal!(atmosphere.wind(0.0).x, 1.0, 0.0001);
+        approx_equal!(atmosphere.wind(0.0).y, 0.5, 0.0001);
+        approx_equal!(atmosphere.wind(1.0).x, 1.5, 0.0001);
+        approx_equal!(atmosphere.wind(1.0).y, 0.0, 0.0001);
+
+        // Moving along with the wind at its speed yields no drag.
+        let drag = atmosphere.drag_acceleration(atmosphere.wind(1.0), 1.0);
+        approx_equal!(drag.magnitude(), 0.0, 0.0001);
+
+        // Standing still at t=1.0 is an airspeed of -1.5 in x.
+        let drag = atmosphere.drag_acceleration(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        approx_equal!(drag.x, 0.1 * 1.5 * 1.5, 0.0001);
+
+        // Without a period the wind is constant.
+        let atmosphere = Atmosphere::new(AtmosphereConfig {
+            wind: WindConfig {
+                x: 2.0,
+                variation_x: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        approx_equal!(atmosphere.wind(3.0).x, 2.0, 0.0001);
+    }
+}
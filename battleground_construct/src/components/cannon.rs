@@ -48,12 +48,21 @@ impl Cannon {
         self.is_triggered = false;
     }
 
+    pub fn reload_time(&self) -> f32 {
+        self.config.reload_time
+    }
+
+    pub fn set_reload_time(&mut self, reload_time: f32) {
+        self.config.reload_time = reload_time;
+    }
+
     pub fn effect(&self) -> CannonFireEffect {
         self.config.fire_effect.clone()
     }
 }
 impl Component for Cannon {}
 
+use crate::components::subsystem::subsystem_integrity;
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::cannon::*;
 pub struct CannonModule {
@@ -72,19 +81,30 @@ impl UnitModule for CannonModule {
         if let Some(cannon) = world.component::<Cannon>(self.entity) {
             registers.insert(
                 REG_CANNON_TRIGGER,
-                Register::new_i32("trigger", cannon.is_triggered() as i32),
+                Register::new_i32("trigger", cannon.is_triggered() as i32)
+                    .with_range(0.0, 1.0)
+                    .with_description("set to fire the cannon when ready"),
             );
             registers.insert(
                 REG_CANNON_IS_TRIGGERED,
-                Register::new_i32("is_triggered", cannon.is_triggered() as i32),
+                Register::new_i32("is_triggered", cannon.is_triggered() as i32).read_only(),
             );
             registers.insert(
                 REG_CANNON_READY,
-                Register::new_i32("ready", cannon.is_ready() as i32),
+                Register::new_i32("ready", cannon.is_ready() as i32).read_only(),
             );
             registers.insert(
                 REG_CANNON_RELOAD_TIME,
-                Register::new_f32("reload_time", cannon.config.reload_time),
+                Register::new_f32("reload_time", cannon.config.reload_time)
+                    .with_unit("s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_CANNON_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
             );
         }
     }
@@ -20,6 +20,9 @@ pub struct Capturable {
     strength: f32,
     /// How the capture should change.
     capture_type: CaptureType,
+    /// A locked capturable can't change owner or strength.
+    #[serde(default)]
+    locked: bool,
 }
 
 impl Capturable {
@@ -28,6 +31,7 @@ impl Capturable {
             owner,
             strength: strength.clamp(0.0, 1.0),
             capture_type,
+            locked: false,
         }
     }
 
@@ -47,7 +51,18 @@ impl Capturable {
         self.strength = strength.clamp(0.0, 1.0);
     }
 
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
+
     pub fn update(&mut self, contenders: &[(TeamId, f32)]) {
+        if self.locked {
+            return;
+        }
         let mut subtracted_strengths = contenders.to_vec();
         let start_strengths = contenders.to_vec();
         let start_value = self.strength;
@@ -176,6 +191,20 @@ mod test {
         approx_equal!(c.strength(), 0.0, 0.0001);
     }
 
+    #[test]
+    fn test_capturable_locked() {
+        let t1 = make_team_id(1);
+        let t2 = make_team_id(2);
+        let mut c = Capturable::new(Some(t1), 0.5, CaptureType::Exclusive);
+        c.set_locked(true);
+        c.update(&[(t2, 0.3)]);
+        assert_eq!(c.owner(), Some(t1));
+        approx_equal!(c.strength(), 0.5, 0.0001);
+        c.set_locked(false);
+        c.update(&[(t2, 0.3)]);
+        approx_equal!(c.strength(), 0.2, 0.0001);
+    }
+
     #[test]
     fn test_capturable() {
         let t1 = make_team_id(1);
@@ -0,0 +1,107 @@
+use crate::components::team::TeamId;
+use crate::components::unit::UnitId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The state a carriable flag is in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum FlagState {
+    /// The flag is at its base.
+    AtBase,
+    /// The flag is carried by a unit, the body entity is the entity the flag follows.
+    Carried { unit: UnitId, body_entity: EntityId },
+    /// The flag was dropped at this time, it remains at the position it was dropped.
+    Dropped { time: f32 },
+}
+
+/// A flag that belongs to a team and can be carried by units of other teams.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct CarriableFlag {
+    team: TeamId,
+    base_x: f32,
+    base_y: f32,
+    radius: f32,
+    state: FlagState,
+}
+
+impl CarriableFlag {
+    pub fn new(team: TeamId, base_x: f32, base_y: f32, radius: f32) -> Self {
+        CarriableFlag {
+            team,
+            base_x,
+            base_y,
+            radius,
+            state: FlagState::AtBase,
+        }
+    }
+
+    /// The team this flag belongs to.
+    pub fn team(&self) -> TeamId {
+        self.team
+    }
+
+    /// Position of the flag's base.
+    pub fn base(&self) -> (f32, f32) {
+        (self.base_x, self.base_y)
+    }
+
+    /// Radius around the flag and base within which the flag is picked up, returned or captured.
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn state(&self) -> FlagState {
+        self.state
+    }
+
+    pub fn is_at_base(&self) -> bool {
+        self.state == FlagState::AtBase
+    }
+
+    /// The unit carrying the flag, if any.
+    pub fn carrier(&self) -> Option<UnitId> {
+        if let FlagState::Carried { unit, .. } = self.state {
+            Some(unit)
+        } else {
+            None
+        }
+    }
+
+    pub fn pick_up(&mut self, unit: UnitId, body_entity: EntityId) {
+        self.state = FlagState::Carried { unit, body_entity };
+    }
+
+    pub fn drop(&mut self, time: f32) {
+        self.state = FlagState::Dropped { time };
+    }
+
+    pub fn return_to_base(&mut self) {
+        self.state = FlagState::AtBase;
+    }
+}
+impl Component for CarriableFlag {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    use crate::components::unit::make_unit_id;
+    #[test]
+    fn test_carriable_flag_state() {
+        let mut world = World::new();
+        let body = world.add_entity();
+        let mut flag = CarriableFlag::new(make_team_id(1), 3.0, 4.0, 1.0);
+        assert!(flag.is_at_base());
+        assert_eq!(flag.carrier(), None);
+        let unit = make_unit_id(5);
+        flag.pick_up(unit, body);
+        assert!(!flag.is_at_base());
+        assert_eq!(flag.carrier(), Some(unit));
+        flag.drop(2.0);
+        assert_eq!(flag.state(), FlagState::Dropped { time: 2.0 });
+        assert_eq!(flag.carrier(), None);
+        flag.return_to_base();
+        assert!(flag.is_at_base());
+        assert_eq!(flag.base(), (3.0, 4.0));
+    }
+}
@@ -48,7 +48,9 @@ impl UnitModule for ClockModule {
         if let Some((_entity, clock)) = world.component_iter::<Clock>().next() {
             registers.insert(
                 battleground_unit_control::modules::clock::REG_CLOCK_ELAPSED,
-                Register::new_f32("elapsed", clock.elapsed_as_f32()),
+                Register::new_f32("elapsed", clock.elapsed_as_f32())
+                    .with_unit("s")
+                    .read_only(),
             );
         }
     }
@@ -0,0 +1,166 @@
+use super::capture_marker::CaptureMarker;
+use super::group::Group;
+use super::health::Health;
+use super::orders::Orders;
+use super::pose::world_pose;
+use super::team::TeamId;
+use super::team_member::TeamMember;
+use super::unit::Unit;
+use engine::prelude::*;
+
+/// The team commander, coordinating the units of its team.
+#[derive(Debug, Clone, Copy)]
+pub struct Commander {
+    team: TeamId,
+}
+
+impl Commander {
+    pub fn new(team: TeamId) -> Self {
+        Commander { team }
+    }
+
+    pub fn team(&self) -> TeamId {
+        self.team
+    }
+}
+impl Component for Commander {}
+
+/// The unit entities that belong to a team, ordered by unit id.
+pub fn team_units(world: &World, team: TeamId) -> Vec<EntityId> {
+    let mut units = world
+        .component_iter::<Unit>()
+        .filter(|(entity, _unit)| {
+            world
+                .component::<TeamMember>(*entity)
+                .map(|v| v.team() == team)
+                .unwrap_or(false)
+        })
+        .map(|(entity, unit)| (unit.id(), entity))
+        .collect::<Vec<_>>();
+    units.sort_by_key(|v| v.0);
+    units.drain(..).map(|v| v.1).collect()
+}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::commander::*;
+use battleground_unit_control::modules::orders::ORDERS_SIZE_LIMIT;
+
+pub struct CommanderModule {
+    entity: EntityId,
+}
+
+impl CommanderModule {
+    pub fn new(entity: EntityId) -> Self {
+        CommanderModule { entity }
+    }
+}
+
+impl UnitModule for CommanderModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        let commander = if let Some(commander) = world.component::<Commander>(self.entity) {
+            *commander
+        } else {
+            return;
+        };
+
+        let units = team_units(world, commander.team());
+        registers.insert(
+            REG_COMMANDER_UNIT_COUNT,
+            Register::new_i32("unit_count", units.len() as i32).read_only(),
+        );
+        for (i, unit_entity) in units.iter().enumerate() {
+            let unit = world.component::<Unit>(*unit_entity).unwrap();
+            let health = world
+                .component::<Health>(*unit_entity)
+                .map(|v| v.health())
+                .unwrap_or(0.0);
+
+            // The body carries the capture marker, use its pose as the unit's position.
+            use crate::util::cgmath::ToRollPitchYaw;
+            use crate::util::cgmath::ToTranslation;
+            let body = world.component::<Group>(*unit_entity).and_then(|g| {
+                g.entities()
+                    .iter()
+                    .find(|e| world.component::<CaptureMarker>(**e).is_some())
+                    .copied()
+            });
+            let (x, y, yaw) = if let Some(body) = body {
+                let pose = world_pose(world, body);
+                let translation = pose.to_translation();
+                (translation.x, translation.y, pose.to_rpy().z)
+            } else {
+                (0.0, 0.0, 0.0)
+            };
+
+            let (order, sequence) = world
+                .component::<Orders>(*unit_entity)
+                .map(|v| (v.order().to_vec(), v.sequence()))
+                .unwrap_or_default();
+
+            let base = REG_COMMANDER_UNIT_START + REG_COMMANDER_UNIT_STRIDE * i as u32;
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_UNIT_ID,
+                Register::new_i32("unit_id", unit.id().as_u64() as i32).read_only(),
+            );
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_UNIT_TYPE,
+                Register::new_i32("unit_type", unit.unit_type() as i32).read_only(),
+            );
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_X,
+                Register::new_f32("x", x).read_only(),
+            );
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_Y,
+                Register::new_f32("y", y).read_only(),
+            );
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_YAW,
+                Register::new_f32("yaw", yaw).read_only(),
+            );
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_HEALTH,
+                Register::new_f32("health", health).read_only(),
+            );
+            let mut order_register = Register::new_bytes_max("order", ORDERS_SIZE_LIMIT);
+            *order_register.value_bytes_mut().unwrap() = order;
+            registers.insert(base + REG_COMMANDER_UNIT_OFFSET_ORDER, order_register);
+            registers.insert(
+                base + REG_COMMANDER_UNIT_OFFSET_ORDER_SEQUENCE,
+                Register::new_i32("order_sequence", sequence as i32).read_only(),
+            );
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        let commander = if let Some(commander) = world.component::<Commander>(self.entity) {
+            *commander
+        } else {
+            return;
+        };
+        let time = world
+            .component_iter::<super::clock::Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        // The units are ordered the same as when the registers were retrieved.
+        let units = team_units(world, commander.team());
+        for (i, unit_entity) in units.iter().enumerate() {
+            let base = REG_COMMANDER_UNIT_START + REG_COMMANDER_UNIT_STRIDE * i as u32;
+            let order =
+                if let Some(register) = registers.get(&(base + REG_COMMANDER_UNIT_OFFSET_ORDER)) {
+                    register.value_bytes().expect("wrong value type")
+                } else {
+                    continue;
+                };
+            if let Some(mut orders) = world.component_mut::<Orders>(*unit_entity) {
+                if orders.order() != order {
+                    orders.post(order, time);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use battleground_unit_control::control_log::ControlLog;
+use engine::prelude::*;
+
+/// Pointer to the control logs, by the entity id of the controlled unit.
+pub type ControlLogStorage =
+    std::rc::Rc<std::cell::RefCell<std::collections::BTreeMap<usize, ControlLog>>>;
+
+/// Component for recording the register reads and writes of all unit controllers.
+#[derive(Default)]
+pub struct ControlRecording {
+    logs: ControlLogStorage,
+}
+
+impl ControlRecording {
+    pub fn new() -> Self {
+        ControlRecording::default()
+    }
+
+    /// Retrieve the internal storage.
+    pub fn logs(&self) -> ControlLogStorage {
+        self.logs.clone()
+    }
+
+    /// The file the control logs are written to, alongside the recording.
+    pub fn path_for_recording(recording_path: &str) -> String {
+        format!("{recording_path}.control")
+    }
+
+    /// Load control logs from a file.
+    pub fn load_file(
+        path: &str,
+    ) -> Result<std::collections::BTreeMap<usize, ControlLog>, Box<dyn std::error::Error>> {
+        let data = std::fs::read(path)?;
+        Ok(bincode::deserialize(&data)?)
+    }
+
+    /// Write the control logs to a file.
+    pub fn write_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let data = bincode::serialize(&*self.logs.borrow())?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
+impl Component for ControlRecording {}
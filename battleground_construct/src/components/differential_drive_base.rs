@@ -71,10 +71,17 @@ impl DifferentialDriveBase {
         &mut self.wheel_velocity_vel
     }
 
-    pub fn wheel_velocity_bounds(self) -> (f32, f32) {
+    pub fn wheel_velocity_bounds(&self) -> (f32, f32) {
         self.config.wheel_velocity_bounds
     }
 
+    /// Set the wheel velocity bounds, the current command is clamped to the new bounds.
+    pub fn set_wheel_velocity_bounds(&mut self, min: f32, max: f32) {
+        self.config.wheel_velocity_bounds = (min, max);
+        let (left, right) = self.wheel_velocity_cmd;
+        self.set_velocities(left, right);
+    }
+
     pub fn wheel_acceleration_bounds(&self) -> Option<(f32, f32)> {
         self.config.wheel_acceleration_bounds
     }
@@ -97,6 +104,7 @@ impl DifferentialDriveBase {
 }
 impl Component for DifferentialDriveBase {}
 
+use crate::components::subsystem::subsystem_integrity;
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::differential_drive::*;
 pub struct DifferentialDriveBaseModule {
@@ -117,35 +125,71 @@ impl UnitModule for DifferentialDriveBaseModule {
 
             registers.insert(
                 REG_DIFF_DRIVE_LEFT_VEL,
-                Register::new_f32("left_wheel_vel", vels.0),
+                Register::new_f32("left_wheel_vel", vels.0)
+                    .with_unit("m/s")
+                    .read_only(),
             );
             registers.insert(
                 REG_DIFF_DRIVE_RIGHT_VEL,
-                Register::new_f32("right_wheel_vel", vels.1),
+                Register::new_f32("right_wheel_vel", vels.1)
+                    .with_unit("m/s")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_DIFF_DRIVE_LEFT_CMD,
-                Register::new_f32("left_wheel_cmd", vels.0),
+                Register::new_f32("left_wheel_cmd", vels.0)
+                    .with_unit("m/s")
+                    .with_description("commanded left track velocity"),
             );
             registers.insert(
                 REG_DIFF_DRIVE_RIGHT_CMD,
-                Register::new_f32("right_wheel_cmd", vels.1),
+                Register::new_f32("right_wheel_cmd", vels.1)
+                    .with_unit("m/s")
+                    .with_description("commanded right track velocity"),
             );
 
             let accel_bounds = base.wheel_acceleration_bounds().unwrap_or((0.0, 0.0));
             registers.insert(
                 REG_DIFF_DRIVE_ACCELERATION_LOWER,
-                Register::new_f32("acceleration_lower", accel_bounds.0),
+                Register::new_f32("acceleration_lower", accel_bounds.0)
+                    .with_unit("m/s^2")
+                    .read_only(),
             );
             registers.insert(
                 REG_DIFF_DRIVE_ACCELERATION_UPPER,
-                Register::new_f32("acceleration_upper", accel_bounds.1),
+                Register::new_f32("acceleration_upper", accel_bounds.1)
+                    .with_unit("m/s^2")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_DIFF_DRIVE_TRACK_WIDTH,
-                Register::new_f32("track_width", base.track_width()),
+                Register::new_f32("track_width", base.track_width())
+                    .with_unit("m")
+                    .read_only(),
+            );
+
+            let velocity_bounds = base.wheel_velocity_bounds();
+            registers.insert(
+                REG_DIFF_DRIVE_VELOCITY_LOWER,
+                Register::new_f32("velocity_lower", velocity_bounds.0)
+                    .with_unit("m/s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_DIFF_DRIVE_VELOCITY_UPPER,
+                Register::new_f32("velocity_upper", velocity_bounds.1)
+                    .with_unit("m/s")
+                    .read_only(),
+            );
+
+            registers.insert(
+                REG_DIFF_DRIVE_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
             );
         }
     }
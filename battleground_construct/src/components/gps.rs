@@ -3,6 +3,66 @@ use engine::prelude::*;
 
 use battleground_unit_control::modules::gps::*;
 
+/// A position and orientation measured by the gps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsFix {
+    /// Time at which the fix was taken.
+    pub time: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub roll: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+}
+
+/// The state of the gps, updated by the localization. Without a fix the gps is exact.
+#[derive(Debug, Clone, Copy)]
+pub struct Gps {
+    fix: Option<GpsFix>,
+    valid: bool,
+}
+impl Default for Gps {
+    fn default() -> Self {
+        Gps::new()
+    }
+}
+
+impl Gps {
+    pub fn new() -> Self {
+        Gps {
+            fix: None,
+            valid: true,
+        }
+    }
+
+    pub fn fix(&self) -> Option<GpsFix> {
+        self.fix
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Set a new fix, marking the gps as valid.
+    pub fn set_fix(&mut self, fix: GpsFix) {
+        self.fix = Some(fix);
+        self.valid = true;
+    }
+
+    /// Keep the last fix, but mark it as stale.
+    pub fn set_stale(&mut self) {
+        self.valid = false;
+    }
+
+    /// Discard the fix, the gps reports NaN values.
+    pub fn set_invalid(&mut self) {
+        self.fix = None;
+        self.valid = false;
+    }
+}
+impl Component for Gps {}
+
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 pub struct GpsModule {
     entity: EntityId,
@@ -18,15 +78,82 @@ impl UnitModule for GpsModule {
     fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
         use crate::util::cgmath::ToRollPitchYaw;
         use crate::util::cgmath::ToTranslation;
-        let pose = world_pose(world, self.entity);
-        let translation = pose.to_translation();
-        registers.insert(REG_GPS_X, Register::new_f32("x", translation.x));
-        registers.insert(REG_GPS_Y, Register::new_f32("y", translation.y));
-        registers.insert(REG_GPS_Z, Register::new_f32("z", translation.z));
-
-        let rpy = pose.to_rpy();
-        registers.insert(REG_GPS_ROLL, Register::new_f32("r", rpy.x));
-        registers.insert(REG_GPS_PITCH, Register::new_f32("p", rpy.y));
-        registers.insert(REG_GPS_YAW, Register::new_f32("y", rpy.z));
+        let gps = world.component::<Gps>(self.entity).map(|v| *v);
+        let (fix, valid) = match gps {
+            Some(gps) if gps.fix().is_some() => (gps.fix().unwrap(), gps.is_valid()),
+            Some(gps) if !gps.is_valid() => {
+                let nan = f32::NAN;
+                let fix = GpsFix {
+                    time: nan,
+                    x: nan,
+                    y: nan,
+                    z: nan,
+                    roll: nan,
+                    pitch: nan,
+                    yaw: nan,
+                };
+                (fix, false)
+            }
+            _ => {
+                let pose = world_pose(world, self.entity);
+                let translation = pose.to_translation();
+                let rpy = pose.to_rpy();
+                let time = world
+                    .component_iter::<crate::components::clock::Clock>()
+                    .next()
+                    .map(|(_e, clock)| clock.elapsed_as_f32())
+                    .unwrap_or(0.0);
+                let fix = GpsFix {
+                    time,
+                    x: translation.x,
+                    y: translation.y,
+                    z: translation.z,
+                    roll: rpy.x,
+                    pitch: rpy.y,
+                    yaw: rpy.z,
+                };
+                (fix, true)
+            }
+        };
+        registers.insert(
+            REG_GPS_X,
+            Register::new_f32("x", fix.x).with_unit("m").read_only(),
+        );
+        registers.insert(
+            REG_GPS_Y,
+            Register::new_f32("y", fix.y).with_unit("m").read_only(),
+        );
+        registers.insert(
+            REG_GPS_Z,
+            Register::new_f32("z", fix.z).with_unit("m").read_only(),
+        );
+
+        registers.insert(
+            REG_GPS_ROLL,
+            Register::new_f32("r", fix.roll)
+                .with_unit("rad")
+                .read_only(),
+        );
+        registers.insert(
+            REG_GPS_PITCH,
+            Register::new_f32("p", fix.pitch)
+                .with_unit("rad")
+                .read_only(),
+        );
+        registers.insert(
+            REG_GPS_YAW,
+            Register::new_f32("y", fix.yaw).with_unit("rad").read_only(),
+        );
+
+        registers.insert(
+            REG_GPS_VALID,
+            Register::new_i32("valid", valid as i32).read_only(),
+        );
+        registers.insert(
+            REG_GPS_FIX_TIME,
+            Register::new_f32("fix_time", fix.time)
+                .with_unit("s")
+                .read_only(),
+        );
     }
 }
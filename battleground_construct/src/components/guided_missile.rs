@@ -0,0 +1,246 @@
+use crate::components::radar::{Radar, RadarConfig};
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::cgmath::prelude::*;
+use cgmath::InnerSpace;
+use engine::prelude::*;
+
+/// The seeker head of a missile, determines what the missile steers towards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MissileSeeker {
+    /// No guidance, the missile flies straight ahead.
+    None,
+    /// Steer towards this point in the world frame.
+    Point(Vec3),
+    /// Steer towards the strongest radar reflection in the seeker's cone.
+    Radar,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GuidedMissileConfig {
+    /// Speed while the missile has fuel.
+    pub speed: f32,
+    /// Maximum turn rate in radians per second.
+    pub turn_rate: f32,
+    /// Duration the missile can fly under its own power.
+    pub fuel: f32,
+    /// Maximum range of the radar seeker.
+    pub seeker_range: f32,
+    /// Detection angle of the radar seeker, symmetric around the missile's heading.
+    pub seeker_angle: f32,
+}
+
+impl Default for GuidedMissileConfig {
+    fn default() -> Self {
+        GuidedMissileConfig {
+            speed: 6.0,
+            turn_rate: 1.5,
+            fuel: 5.0,
+            seeker_range: 15.0,
+            seeker_angle: 30.0f32.to_radians(),
+        }
+    }
+}
+
+/// A missile in flight, the velocity is tracked in the world frame by this component and the
+/// missile's pose is aligned with it, such that the display follows the direction of flight.
+#[derive(Debug, Clone)]
+pub struct GuidedMissile {
+    config: GuidedMissileConfig,
+    seeker: MissileSeeker,
+    fuel: f32,
+    velocity: Vec3,
+    radar: Radar,
+}
+
+impl GuidedMissile {
+    pub fn new(config: GuidedMissileConfig, seeker: MissileSeeker, direction: Vec3) -> Self {
+        let radar = Radar::new_with_config(RadarConfig {
+            range_max: config.seeker_range,
+            detection_angle_yaw: config.seeker_angle,
+            detection_angle_pitch: config.seeker_angle,
+            signal_strength: 1.0,
+        });
+        GuidedMissile {
+            config,
+            seeker,
+            fuel: config.fuel,
+            velocity: direction.normalize() * config.speed,
+            radar,
+        }
+    }
+
+    pub fn config(&self) -> GuidedMissileConfig {
+        self.config
+    }
+
+    pub fn seeker(&self) -> MissileSeeker {
+        self.seeker
+    }
+
+    /// Remaining fuel in seconds.
+    pub fn fuel(&self) -> f32 {
+        self.fuel
+    }
+
+    pub fn has_fuel(&self) -> bool {
+        self.fuel > 0.0
+    }
+
+    /// The velocity of the missile in the world frame.
+    pub fn velocity(&self) -> Vec3 {
+        self.velocity
+    }
+
+    /// Determine the direction towards the target in the world frame, None if there is no target.
+    /// The reflectors are only used by the radar seeker.
+    pub fn seek(&mut self, missile_pose: &Mat4, reflectors: &[(Mat4, f32)]) -> Option<Vec3> {
+        match self.seeker {
+            MissileSeeker::None => None,
+            MissileSeeker::Point(target) => Some(target - missile_pose.to_translation()),
+            MissileSeeker::Radar => {
+                self.radar.update_reflections(missile_pose, reflectors);
+                let strongest = self
+                    .radar
+                    .reflections()
+                    .iter()
+                    .max_by(|a, b| a.strength.total_cmp(&b.strength))
+                    .copied()?;
+                let local = Vec3::new(
+                    strongest.pitch.cos() * strongest.yaw.cos(),
+                    strongest.pitch.cos() * strongest.yaw.sin(),
+                    strongest.pitch.sin(),
+                );
+                Some((missile_pose * local.extend(0.0)).truncate())
+            }
+        }
+    }
+
+    /// Turn the velocity towards the desired direction, limited by the turn rate and consume fuel.
+    /// The heading is kept if the desired direction has no length, like when the missile is at the
+    /// target.
+    pub fn steer(&mut self, desired: Option<Vec3>, dt: f32) {
+        let desired = desired.filter(|v| v.magnitude2() > 1e-12);
+        if let (Some(desired), true) = (desired, self.velocity.magnitude2() > 1e-12) {
+            let current = self.velocity.normalize();
+            let desired = desired.normalize();
+            let angle = current.dot(desired).clamp(-1.0, 1.0).acos();
+            let max_angle = self.config.turn_rate * dt;
+            let direction = if angle <= max_angle {
+                desired
+            } else {
+                // Rotate around the axis perpendicular to both, pick any perpendicular axis if
+                // the target is exactly behind us.
+                let mut axis = current.cross(desired);
+                if axis.magnitude2() < 1e-12 {
+                    axis = current.cross(Vec3::unit_z());
+                }
+                if axis.magnitude2() < 1e-12 {
+                    axis = current.cross(Vec3::unit_x());
+                }
+                let rotation =
+                    cgmath::Matrix3::from_axis_angle(axis.normalize(), cgmath::Rad(max_angle));
+                rotation * current
+            };
+            self.velocity = direction.normalize() * self.config.speed;
+        }
+        self.fuel = (self.fuel - dt).max(0.0);
+    }
+
+    /// Apply the acceleration to the velocity, used once the missile is out of fuel.
+    pub fn fall(&mut self, acceleration: Vec3, dt: f32) {
+        self.velocity += acceleration * dt;
+    }
+
+    /// The pose of a missile at this position, with the x axis aligned with the velocity.
+    pub fn aligned_pose(&self, position: Vec3) -> Mat4 {
+        let v = self.velocity;
+        let yaw = v.y.atan2(v.x);
+        let pitch = (-v.z).atan2((v.x * v.x + v.y * v.y).sqrt());
+        Mat4::from_translation(position)
+            * Mat4::from_angle_z(cgmath::Rad(yaw))
+            * Mat4::from_angle_y(cgmath::Rad(pitch))
+    }
+}
+impl Component for GuidedMissile {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+    #[test]
+    fn test_guided_missile_steer() {
+        let config = GuidedMissileConfig {
+            speed: 2.0,
+            turn_rate: 1.0,
+            fuel: 1.0,
+            ..Default::default()
+        };
+        let mut missile = GuidedMissile::new(
+            config,
+            MissileSeeker::Point(Vec3::new(0.0, 10.0, 0.0)),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        let pose = missile.aligned_pose(Vec3::new(0.0, 0.0, 0.0));
+        let desired = missile.seek(&pose, &[]);
+        approx_equal!(desired.unwrap().y, 10.0, 0.0001);
+
+        // Turn rate is limited, so the target 90 degrees to the left is not reached at once.
+        missile.steer(desired, 0.5);
+        let v = missile.velocity();
+        approx_equal!(v.magnitude(), 2.0, 0.0001);
+        approx_equal!(v.y.atan2(v.x), 0.5, 0.0001);
+        approx_equal!(missile.fuel(), 0.5, 0.0001);
+
+        // The aligned pose points the x axis along the velocity.
+        let pose = missile.aligned_pose(Vec3::new(1.0, 2.0, 3.0));
+        let x_axis = (pose * Vec3::unit_x().extend(0.0)).truncate();
+        approx_equal!(x_axis.dot(v.normalize()), 1.0, 0.0001);
+
+        missile.steer(desired, 10.0);
+        approx_equal!(missile.velocity().normalize().y, 1.0, 0.0001);
+        assert!(!missile.has_fuel());
+    }
+
+    #[test]
+    fn test_guided_missile_radar_seeker() {
+        let mut missile = GuidedMissile::new(
+            GuidedMissileConfig::default(),
+            MissileSeeker::Radar,
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        let pose = missile.aligned_pose(Vec3::new(0.0, 0.0, 0.0));
+        let reflectors = vec![
+            // Outside of the seeker's cone.
+            (Mat4::from_translation(Vec3::new(0.0, 5.0, 0.0)), 1.0),
+            (Mat4::from_translation(Vec3::new(5.0, 1.0, 0.0)), 1.0),
+        ];
+        let desired = missile.seek(&pose, &reflectors).unwrap();
+        approx_equal!(desired.y.atan2(desired.x), (1.0f32).atan2(5.0), 0.0001);
+        assert!(missile.seek(&pose, &reflectors[0..1]).is_none());
+    }
+
+    #[test]
+    fn test_guided_missile_steer_degenerate() {
+        let target = Vec3::new(1.0, 2.0, 3.0);
+        let mut missile = GuidedMissile::new(
+            GuidedMissileConfig::default(),
+            MissileSeeker::Point(target),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        // At the target the desired direction has no length, the heading is kept.
+        let pose = missile.aligned_pose(target);
+        let desired = missile.seek(&pose, &[]);
+        missile.steer(desired, 0.1);
+        approx_equal!(missile.velocity().normalize().x, 1.0, 0.0001);
+
+        // Falling straight down, with the target straight above.
+        let mut missile = GuidedMissile::new(
+            GuidedMissileConfig::default(),
+            MissileSeeker::None,
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+        missile.steer(Some(Vec3::new(0.0, 0.0, 1.0)), 0.1);
+        let v = missile.velocity();
+        assert!(v.x.is_finite() && v.y.is_finite() && v.z.is_finite());
+    }
+}
@@ -138,6 +138,20 @@ impl GunBattery {
     pub fn gun_pose(&self, index: usize) -> Option<Mat4> {
         self.config.poses.get(index).copied()
     }
+    pub fn gun_reload(&self) -> f32 {
+        self.config.gun_reload
+    }
+
+    pub fn battery_reload(&self) -> f32 {
+        self.config.battery_reload
+    }
+
+    /// Set both the individual gun reload and the battery reload durations.
+    pub fn set_reload(&mut self, gun_reload: f32, battery_reload: f32) {
+        self.config.gun_reload = gun_reload;
+        self.config.battery_reload = battery_reload;
+    }
+
     pub fn gun_status(&self, index: usize) -> Option<GunStatus> {
         self.status.get(index).copied()
     }
@@ -243,6 +257,7 @@ mod test {
     }
 }
 
+use crate::components::subsystem::subsystem_integrity;
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::gun_battery::*;
 pub struct GunBatteryModule {
@@ -261,36 +276,51 @@ impl UnitModule for GunBatteryModule {
         if let Some(gun_battery) = world.component::<GunBattery>(self.entity) {
             registers.insert(
                 REG_GUN_BATTERY_FIRING,
-                Register::new_i32("firing", gun_battery.is_triggered() as i32),
+                Register::new_i32("firing", gun_battery.is_triggered() as i32)
+                    .with_range(0.0, 1.0)
+                    .with_description("set to fire the guns in sequence when ready"),
             );
             registers.insert(
                 REG_GUN_BATTERY_IS_TRIGGERED,
-                Register::new_i32("is_triggered", gun_battery.is_triggered() as i32),
+                Register::new_i32("is_triggered", gun_battery.is_triggered() as i32).read_only(),
             );
             registers.insert(
                 REG_GUN_BATTERY_READY,
-                Register::new_i32("ready", gun_battery.is_ready() as i32),
+                Register::new_i32("ready", gun_battery.is_ready() as i32).read_only(),
             );
             registers.insert(
                 REG_GUN_BATTERY_GUN_RELOAD,
-                Register::new_f32("gun_reload", gun_battery.config.gun_reload),
+                Register::new_f32("gun_reload", gun_battery.config.gun_reload)
+                    .with_unit("s")
+                    .read_only(),
             );
             registers.insert(
                 REG_GUN_BATTERY_INTER_GUN_DURATION,
-                Register::new_f32("inter_gun_duration", gun_battery.config.inter_gun_duration),
+                Register::new_f32("inter_gun_duration", gun_battery.config.inter_gun_duration)
+                    .with_unit("s")
+                    .read_only(),
             );
             registers.insert(
                 REG_GUN_BATTERY_RELOAD,
-                Register::new_f32("battery_reload", gun_battery.config.battery_reload),
+                Register::new_f32("battery_reload", gun_battery.config.battery_reload)
+                    .with_unit("s")
+                    .read_only(),
             );
             registers.insert(
                 REG_GUN_BATTERY_FIRE_INDEX,
-                Register::new_i32("fire_index", gun_battery.gun_index() as i32),
+                Register::new_i32("fire_index", gun_battery.gun_index() as i32).read_only(),
+            );
+            registers.insert(
+                REG_GUN_BATTERY_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_GUN_BATTERY_COUNT,
-                Register::new_i32("gun_count", gun_battery.gun_count() as i32),
+                Register::new_i32("gun_count", gun_battery.gun_count() as i32).read_only(),
             );
             for i in 0..gun_battery.gun_count() {
                 use crate::util::cgmath::ToRollPitchYaw;
@@ -299,36 +329,36 @@ impl UnitModule for GunBatteryModule {
                 let status = gun_battery.gun_status(i).unwrap();
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_X,
-                    Register::new_f32("x", pose.w.x),
+                    Register::new_f32("x", pose.w.x).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_Y,
-                    Register::new_f32("y", pose.w.y),
+                    Register::new_f32("y", pose.w.y).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_Z,
-                    Register::new_f32("z", pose.w.z),
+                    Register::new_f32("z", pose.w.z).read_only(),
                 );
                 let rpy = pose.to_rpy();
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_ROLL,
-                    Register::new_f32("roll", rpy.x),
+                    Register::new_f32("roll", rpy.x).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_PITCH,
-                    Register::new_f32("pitch", rpy.y),
+                    Register::new_f32("pitch", rpy.y).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_YAW,
-                    Register::new_f32("yaw", rpy.z),
+                    Register::new_f32("yaw", rpy.z).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_LAST_FIRE_TIME,
-                    Register::new_f32("last_fire_time", status.last_fire_time),
+                    Register::new_f32("last_fire_time", status.last_fire_time).read_only(),
                 );
                 registers.insert(
                     offset + REG_GUN_BATTERY_OFFSET_READY,
-                    Register::new_i32("ready", status.is_ready as i32),
+                    Register::new_i32("ready", status.is_ready as i32).read_only(),
                 );
             }
         }
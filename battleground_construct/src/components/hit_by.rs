@@ -78,3 +78,159 @@ impl HitByHistory {
     }
 }
 impl Component for HitByHistory {}
+
+use crate::components::health::Health;
+use crate::components::pose::world_pose;
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::damage::*;
+
+pub struct DamageModule {
+    unit_entity: EntityId,
+    body_entity: EntityId,
+}
+
+impl DamageModule {
+    pub fn new(unit_entity: EntityId, body_entity: EntityId) -> Self {
+        DamageModule {
+            unit_entity,
+            body_entity,
+        }
+    }
+}
+
+impl UnitModule for DamageModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        use crate::util::cgmath::prelude::*;
+        registers.clear();
+        let health = world
+            .component::<Health>(self.unit_entity)
+            .map(|v| v.health())
+            .unwrap_or(0.0);
+        registers.insert(
+            REG_DAMAGE_HEALTH,
+            Register::new_f32("health", health).read_only(),
+        );
+
+        let history = world.component::<HitByHistory>(self.unit_entity);
+        let hits = history.as_ref().map(|v| v.hits()).unwrap_or(&[]);
+        registers.insert(
+            REG_DAMAGE_TOTAL_HITS,
+            Register::new_i32("total_hits", hits.len() as i32).read_only(),
+        );
+
+        let recent = &hits[hits.len().saturating_sub(DAMAGE_HITS_MAX)..];
+        registers.insert(
+            REG_DAMAGE_HIT_COUNT,
+            Register::new_i32("hits", recent.len() as i32).read_only(),
+        );
+
+        // Express the impacts in the body frame, like the radar does for reflections.
+        let body_pose = world_pose(world, self.body_entity);
+        for (i, hit) in recent.iter().enumerate() {
+            let local =
+                (body_pose.transform().to_inv_h() * hit.impact().position()).to_translation();
+            let distance = local.euclid_norm();
+            let yaw = local.y.atan2(local.x);
+            let pitch = if distance > 0.0 {
+                (local.z / distance).asin()
+            } else {
+                0.0
+            };
+            let source = hit
+                .source()
+                .map(|v| v.as_u64() as i32)
+                .unwrap_or(DAMAGE_HIT_SOURCE_UNKNOWN);
+
+            let offset = i as u32 * REG_DAMAGE_HIT_STRIDE + REG_DAMAGE_HIT_START;
+            registers.insert(
+                offset + REG_DAMAGE_HIT_OFFSET_TIME,
+                Register::new_f32("time", hit.time()).read_only(),
+            );
+            registers.insert(
+                offset + REG_DAMAGE_HIT_OFFSET_DAMAGE,
+                Register::new_f32("damage", hit.damage()).read_only(),
+            );
+            registers.insert(
+                offset + REG_DAMAGE_HIT_OFFSET_BEARING_YAW,
+                Register::new_f32("bearing_yaw", yaw).read_only(),
+            );
+            registers.insert(
+                offset + REG_DAMAGE_HIT_OFFSET_BEARING_PITCH,
+                Register::new_f32("bearing_pitch", pitch).read_only(),
+            );
+            registers.insert(
+                offset + REG_DAMAGE_HIT_OFFSET_SOURCE,
+                Register::new_i32("source", source).read_only(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::pose::Pose;
+    use crate::components::unit::make_unit_id;
+    use crate::systems::process_hit_by::ProcessHitBy;
+    use crate::util::test_util::approx_equal;
+
+    #[test]
+    fn test_damage_module() {
+        let mut world = World::new();
+        let unit_entity = world.add_entity();
+        world.add_component(unit_entity, Health::new());
+        // The body is turned to the left, the impact is in front of the origin.
+        let body_entity = world.add_entity();
+        world.add_component(
+            body_entity,
+            Pose::from_mat4(cgmath::Matrix4::from_angle_z(cgmath::Deg(90.0))),
+        );
+
+        let mut hit_by = HitBy::new();
+        let position = cgmath::Matrix4::from_translation(cgmath::Vector3::new(1.0, 0.0, 1.0));
+        hit_by.add_hit(
+            0.25,
+            Impact::new(None, position, None),
+            Some(make_unit_id(3)),
+            2.0,
+        );
+        hit_by.add_hit(0.25, Impact::new(None, position, None), None, 2.5);
+        world.add_component(unit_entity, hit_by);
+        ProcessHitBy {}.update(&mut world);
+
+        let module = DamageModule::new(unit_entity, body_entity);
+        let mut registers = RegisterMap::new();
+        module.get_registers(&world, &mut registers);
+        let f32_value = |r: u32| registers.get(&r).unwrap().value_f32().unwrap();
+        let i32_value = |r: u32| registers.get(&r).unwrap().value_i32().unwrap();
+        approx_equal!(f32_value(REG_DAMAGE_HEALTH), 0.5, 0.0001);
+        assert_eq!(i32_value(REG_DAMAGE_TOTAL_HITS), 2);
+        assert_eq!(i32_value(REG_DAMAGE_HIT_COUNT), 2);
+
+        assert_eq!(
+            i32_value(REG_DAMAGE_HIT_START + REG_DAMAGE_HIT_OFFSET_SOURCE),
+            3
+        );
+        let offset = REG_DAMAGE_HIT_START + REG_DAMAGE_HIT_STRIDE;
+        approx_equal!(f32_value(offset + REG_DAMAGE_HIT_OFFSET_TIME), 2.5, 0.0001);
+        approx_equal!(
+            f32_value(offset + REG_DAMAGE_HIT_OFFSET_DAMAGE),
+            0.25,
+            0.0001
+        );
+        approx_equal!(
+            f32_value(offset + REG_DAMAGE_HIT_OFFSET_BEARING_YAW),
+            -std::f32::consts::FRAC_PI_2,
+            0.0001
+        );
+        approx_equal!(
+            f32_value(offset + REG_DAMAGE_HIT_OFFSET_BEARING_PITCH),
+            std::f32::consts::FRAC_PI_4,
+            0.0001
+        );
+        assert_eq!(
+            i32_value(offset + REG_DAMAGE_HIT_OFFSET_SOURCE),
+            DAMAGE_HIT_SOURCE_UNKNOWN
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::display::primitives::Vec3;
+use engine::prelude::*;
+
+/// Inertial measurement unit, measures in the frame of the entity it is on.
+#[derive(Debug, Clone, Copy)]
+pub struct Imu {
+    previous_velocity: Option<Vec3>,
+    angular_rate: Vec3,
+    acceleration: Vec3,
+}
+
+impl Default for Imu {
+    fn default() -> Self {
+        Imu::new()
+    }
+}
+
+impl Imu {
+    pub fn new() -> Self {
+        Imu {
+            previous_velocity: None,
+            angular_rate: Vec3::new(0.0, 0.0, 0.0),
+            acceleration: Vec3::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    pub fn angular_rate(&self) -> Vec3 {
+        self.angular_rate
+    }
+
+    pub fn acceleration(&self) -> Vec3 {
+        self.acceleration
+    }
+
+    /// Update from the body frame velocities, returns the true angular rate and acceleration.
+    /// The acceleration excludes gravity.
+    pub fn update(&mut self, v: Vec3, w: Vec3, dt: f32) -> (Vec3, Vec3) {
+        let dv = self
+            .previous_velocity
+            .map(|p| (v - p) / dt)
+            .unwrap_or_else(|| Vec3::new(0.0, 0.0, 0.0));
+        self.previous_velocity = Some(v);
+        // In a rotating frame, the acceleration also has the centripetal component.
+        (w, dv + w.cross(v))
+    }
+
+    pub fn set_measurement(&mut self, angular_rate: Vec3, acceleration: Vec3) {
+        self.angular_rate = angular_rate;
+        self.acceleration = acceleration;
+    }
+}
+impl Component for Imu {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::imu::*;
+
+pub struct ImuModule {
+    entity: EntityId,
+}
+
+impl ImuModule {
+    pub fn new(entity: EntityId) -> Self {
+        ImuModule { entity }
+    }
+}
+
+impl UnitModule for ImuModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(imu) = world.component::<Imu>(self.entity) {
+            let w = imu.angular_rate();
+            let a = imu.acceleration();
+            registers.insert(
+                REG_IMU_ANGULAR_RATE_X,
+                Register::new_f32("angular_rate_x", w.x).read_only(),
+            );
+            registers.insert(
+                REG_IMU_ANGULAR_RATE_Y,
+                Register::new_f32("angular_rate_y", w.y).read_only(),
+            );
+            registers.insert(
+                REG_IMU_ANGULAR_RATE_Z,
+                Register::new_f32("angular_rate_z", w.z).read_only(),
+            );
+            registers.insert(
+                REG_IMU_ACCELERATION_X,
+                Register::new_f32("acceleration_x", a.x).read_only(),
+            );
+            registers.insert(
+                REG_IMU_ACCELERATION_Y,
+                Register::new_f32("acceleration_y", a.y).read_only(),
+            );
+            registers.insert(
+                REG_IMU_ACCELERATION_Z,
+                Register::new_f32("acceleration_z", a.z).read_only(),
+            );
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::components::hit_box::HitBox;
+use crate::components::hit_plane::HitPlane;
+use crate::components::hit_sphere::HitSphere;
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::box_collision::AxisAlignedBox;
+use crate::util::cgmath::prelude::*;
+use cgmath::InnerSpace;
+use engine::prelude::*;
+
+/// The hit volumes a lidar ray can be obstructed by.
+#[derive(Copy, Debug, Clone)]
+pub enum LidarObstacle {
+    HitBox(HitBox),
+    HitSphere(HitSphere),
+    HitPlane(HitPlane),
+}
+
+impl LidarObstacle {
+    /// Distance along the ray at which it enters this obstacle, zero if the origin is inside it.
+    /// The origin and direction are in world coordinates, the direction must be normalized. The
+    /// inverse of the obstacle's pose is passed, such that it is only inverted once for all rays.
+    pub fn ray_distance(
+        &self,
+        inv_pose: &Mat4,
+        origin: Vec3,
+        direction: Vec3,
+        range: f32,
+    ) -> Option<f32> {
+        // Express the ray in the local frame of the obstacle.
+        let origin = (inv_pose * origin.extend(1.0)).truncate();
+        let direction = (inv_pose * direction.extend(0.0)).truncate();
+        let distance = match self {
+            LidarObstacle::HitBox(hitbox) => {
+                let b = AxisAlignedBox::new(hitbox.length(), hitbox.width(), hitbox.height());
+                let (tmin, _tmax) = b.intersections(origin, origin + direction * range)?;
+                tmin.max(0.0) * range
+            }
+            LidarObstacle::HitSphere(sphere) => {
+                let b = origin.dot(direction);
+                let c = origin.dot(origin) - sphere.radius() * sphere.radius();
+                if c <= 0.0 {
+                    0.0 // origin is inside the sphere.
+                } else {
+                    let discriminant = b * b - c;
+                    if discriminant < 0.0 {
+                        return None;
+                    }
+                    let t = -b - discriminant.sqrt();
+                    if t < 0.0 {
+                        return None; // sphere is behind the ray.
+                    }
+                    t
+                }
+            }
+            LidarObstacle::HitPlane(plane) => {
+                let denominator = plane.direction.dot(direction);
+                if denominator.abs() < f32::EPSILON {
+                    return None; // parallel to the plane.
+                }
+                let t = -plane.direction.dot(origin) / denominator;
+                if t < 0.0 {
+                    return None;
+                }
+                t
+            }
+        };
+        if distance <= range {
+            Some(distance)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Copy, Debug, Clone)]
+pub struct LidarConfig {
+    /// Maximum range of the rays, rays that hit nothing report this range.
+    pub range_max: f32,
+    /// Total angle spanned by the fan of rays, symmetric around local x, in the local xy plane.
+    pub fan_angle: f32,
+    /// Number of rays in the fan, spread evenly over the fan angle.
+    pub ray_count: usize,
+}
+impl Default for LidarConfig {
+    fn default() -> Self {
+        Self {
+            range_max: 20.0,
+            fan_angle: 90.0f32.to_radians(),
+            ray_count: 32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lidar {
+    config: LidarConfig,
+    ranges: Vec<f32>,
+}
+
+impl Lidar {
+    pub fn new_with_config(config: LidarConfig) -> Self {
+        Self {
+            config,
+            ranges: vec![config.range_max; config.ray_count],
+        }
+    }
+
+    pub fn config(&self) -> LidarConfig {
+        self.config
+    }
+
+    /// The measured range for each ray, ordered from the most negative yaw to the most positive.
+    pub fn ranges(&self) -> &[f32] {
+        &self.ranges
+    }
+
+    /// The yaw of a ray in the lidar frame.
+    pub fn ray_yaw(&self, index: usize) -> f32 {
+        if self.config.ray_count <= 1 {
+            return 0.0;
+        }
+        let step = self.config.fan_angle / (self.config.ray_count - 1) as f32;
+        -self.config.fan_angle / 2.0 + step * index as f32
+    }
+
+    /// Cast the fan of rays from the lidar pose against the obstacles, each obstacle is given with
+    /// the inverse of its pose.
+    pub fn update_ranges(&mut self, lidar_pose: &Mat4, obstacles: &[(Mat4, LidarObstacle)]) {
+        let origin = lidar_pose.to_translation();
+        self.ranges = (0..self.config.ray_count)
+            .map(|i| {
+                let (s, c) = self.ray_yaw(i).sin_cos();
+                let direction = (lidar_pose * Vec3::new(c, s, 0.0).extend(0.0))
+                    .truncate()
+                    .normalize();
+                obstacles
+                    .iter()
+                    .filter_map(|(inv_pose, obstacle)| {
+                        obstacle.ray_distance(inv_pose, origin, direction, self.config.range_max)
+                    })
+                    .fold(self.config.range_max, f32::min)
+            })
+            .collect();
+    }
+}
+impl Component for Lidar {}
+
+use crate::components::subsystem::subsystem_integrity;
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::lidar::*;
+
+pub struct LidarModule {
+    entity: EntityId,
+}
+
+impl LidarModule {
+    pub fn new(entity: EntityId) -> Self {
+        LidarModule { entity }
+    }
+}
+
+impl UnitModule for LidarModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(lidar) = world.component::<Lidar>(self.entity) {
+            let config = lidar.config();
+            registers.insert(
+                REG_LIDAR_RANGE_MAX,
+                Register::new_f32("range_max", config.range_max)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_LIDAR_FAN_ANGLE,
+                Register::new_f32("fan_angle", config.fan_angle)
+                    .with_unit("rad")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_LIDAR_RAY_COUNT,
+                Register::new_i32("ray_count", config.ray_count as i32).read_only(),
+            );
+            registers.insert(
+                REG_LIDAR_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
+            );
+
+            let mut ranges = Register::new_bytes("ranges").read_only();
+            *ranges.value_bytes_mut().unwrap() = lidar
+                .ranges()
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect();
+            registers.insert(REG_LIDAR_RANGES, ranges);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+    use cgmath::vec3;
+
+    #[test]
+    fn test_lidar_ranges() {
+        let mut lidar = Lidar::new_with_config(LidarConfig {
+            range_max: 20.0,
+            fan_angle: 90.0f32.to_radians(),
+            ray_count: 3,
+        });
+        approx_equal!(lidar.ray_yaw(0), -45.0f32.to_radians(), 0.0001);
+        approx_equal!(lidar.ray_yaw(1), 0.0, 0.0001);
+
+        let obstacles = vec![
+            // Box straight ahead, front face at x = 5.
+            (
+                Mat4::from_translation(vec3(6.0f32, 0.0, 0.0)).to_inv_h(),
+                LidarObstacle::HitBox(HitBox::new(2.0, 2.0, 2.0)),
+            ),
+            // Sphere on the left diagonal, 10m away, surface at 9m.
+            (
+                Mat4::from_translation(vec3(10.0f32, 10.0, 0.0) / 2.0f32.sqrt()).to_inv_h(),
+                LidarObstacle::HitSphere(HitSphere::with_radius(1.0)),
+            ),
+            // Ground plane below, parallel to the rays so never hit.
+            (
+                Mat4::from_translation(vec3(0.0f32, 0.0, -1.0)).to_inv_h(),
+                LidarObstacle::HitPlane(HitPlane::new()),
+            ),
+        ];
+        let lidar_pose = Mat4::from_angle_z(cgmath::Deg(90.0));
+        // Rotated by 90 degrees, the center ray points along world y, the right ray to the sphere.
+        lidar.update_ranges(&lidar_pose, &obstacles);
+        approx_equal!(lidar.ranges()[0], 9.0, 0.0001);
+        approx_equal!(lidar.ranges()[1], 20.0, 0.0001);
+        approx_equal!(lidar.ranges()[2], 20.0, 0.0001);
+
+        lidar.update_ranges(&Mat4::from_translation(vec3(0.0, 0.0, 0.0)), &obstacles);
+        approx_equal!(lidar.ranges()[0], 20.0, 0.0001);
+        approx_equal!(lidar.ranges()[1], 5.0, 0.0001);
+        approx_equal!(lidar.ranges()[2], 9.0, 0.0001);
+
+        // Pitched down, the plane is hit.
+        let plane = HitPlane::new();
+        let d = LidarObstacle::HitPlane(plane).ray_distance(
+            &Mat4::from_translation(vec3(0.0f32, 0.0, 1.0)),
+            vec3(0.0, 0.0, 0.0),
+            vec3(1.0f32, 0.0, -1.0).normalize(),
+            20.0,
+        );
+        approx_equal!(d.unwrap(), 2.0f32.sqrt(), 0.0001);
+    }
+}
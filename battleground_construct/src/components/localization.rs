@@ -0,0 +1,169 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Noise, bias and update rate of the gps. Noise is uniformly distributed within plus and minus
+/// the noise value.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct GpsNoiseConfig {
+    /// Noise on x, y and z, meters.
+    #[serde(default)]
+    pub position_noise: f32,
+    /// Noise on roll, pitch and yaw, radians.
+    #[serde(default)]
+    pub orientation_noise: f32,
+    /// Constant offset added to x, meters.
+    #[serde(default)]
+    pub bias_x: f32,
+    /// Constant offset added to y, meters.
+    #[serde(default)]
+    pub bias_y: f32,
+    /// Constant offset added to yaw, radians.
+    #[serde(default)]
+    pub bias_yaw: f32,
+    /// Number of fixes per second, between fixes the previous fix is reported. Zero provides a
+    /// fix every step.
+    #[serde(default)]
+    pub update_rate: f32,
+}
+
+/// Noise on the velocities the odometry integrates.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct OdometryNoiseConfig {
+    /// Noise on the forward velocity, meters per second.
+    #[serde(default)]
+    pub velocity_noise: f32,
+    /// Noise on the yaw rate, radians per second.
+    #[serde(default)]
+    pub yaw_rate_noise: f32,
+}
+
+/// Noise and bias of the inertial measurement unit.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImuNoiseConfig {
+    /// Noise on the angular rates, radians per second.
+    #[serde(default)]
+    pub angular_rate_noise: f32,
+    /// Constant offset added to the angular rate around z, radians per second.
+    #[serde(default)]
+    pub angular_rate_bias: f32,
+    /// Noise on the accelerations, meters per second squared.
+    #[serde(default)]
+    pub acceleration_noise: f32,
+}
+
+/// What the gps reports inside a denied zone.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GpsDenial {
+    /// The last fix from outside the zone keeps being reported.
+    #[default]
+    Stale,
+    /// The gps reports NaN values.
+    Invalid,
+}
+
+/// Circular area in which units have no gps fix.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct GpsDeniedZone {
+    pub x: f32,
+    pub y: f32,
+    pub radius: f32,
+    #[serde(default)]
+    pub denial: GpsDenial,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct LocalizationConfig {
+    /// Seed for the noise, the noise is deterministic such that matches can be reproduced.
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default)]
+    pub gps: GpsNoiseConfig,
+    #[serde(default)]
+    pub odometry: OdometryNoiseConfig,
+    #[serde(default)]
+    pub imu: ImuNoiseConfig,
+    #[serde(default)]
+    pub denied_zones: Vec<GpsDeniedZone>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Localization {
+    config: LocalizationConfig,
+}
+
+impl Localization {
+    pub fn new(config: LocalizationConfig) -> Self {
+        Localization { config }
+    }
+
+    pub fn config(&self) -> &LocalizationConfig {
+        &self.config
+    }
+
+    /// The denial that applies at this position, if it is inside a denied zone.
+    pub fn denial(&self, x: f32, y: f32) -> Option<GpsDenial> {
+        self.config
+            .denied_zones
+            .iter()
+            .find(|z| (z.x - x).powi(2) + (z.y - y).powi(2) <= z.radius.powi(2))
+            .map(|z| z.denial)
+    }
+
+    /// Deterministic noise in [-1.0, 1.0], different for each combination of entity, time and
+    /// channel.
+    pub fn noise(&self, entity: EntityId, time: f32, channel: u64) -> f32 {
+        crate::util::noise::noise(self.config.seed, usize::from(entity) as u64, time, channel)
+    }
+}
+impl Component for Localization {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_localization_noise_and_denial() {
+        let mut world = World::new();
+        let a = world.add_entity();
+        let b = world.add_entity();
+        let localization = Localization::new(LocalizationConfig {
+            seed: 3,
+            denied_zones: vec![GpsDeniedZone {
+                x: 5.0,
+                y: 0.0,
+                radius: 2.0,
+                denial: GpsDenial::Invalid,
+            }],
+            ..Default::default()
+        });
+
+        // Noise is reproducible, but differs between entities, times and channels.
+        assert_eq!(
+            localization.noise(a, 0.01, 0),
+            localization.noise(a, 0.01, 0)
+        );
+        assert_ne!(
+            localization.noise(a, 0.01, 0),
+            localization.noise(b, 0.01, 0)
+        );
+        assert_ne!(
+            localization.noise(a, 0.01, 0),
+            localization.noise(a, 0.02, 0)
+        );
+        assert_ne!(
+            localization.noise(a, 0.01, 0),
+            localization.noise(a, 0.01, 1)
+        );
+
+        // And it is bounded, with a mean close to zero.
+        let samples: Vec<f32> = (0..1000)
+            .map(|i| localization.noise(a, i as f32 * 0.01, 0))
+            .collect();
+        assert!(samples.iter().all(|v| (-1.0..=1.0).contains(v)));
+        let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+        assert!(mean.abs() < 0.1);
+
+        assert_eq!(localization.denial(5.0, 1.0), Some(GpsDenial::Invalid));
+        assert_eq!(localization.denial(0.0, 0.0), None);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::components;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Asymmetric match; the attacker must capture the capture points in order, the defender wins by
+/// holding out until the time limit.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchAttackDefend {
+    attacker: TeamId,
+    defender: TeamId,
+    /// The capture points, in the order in which they must be captured.
+    points: Vec<EntityId>,
+    /// Number of points captured by the attacker.
+    captured: usize,
+    /// Attacker's capture strength on the currently active point.
+    active_progress: f32,
+}
+
+impl MatchAttackDefend {
+    pub fn new(attacker: TeamId, defender: TeamId, points: &[EntityId]) -> Self {
+        Self {
+            attacker,
+            defender,
+            points: points.to_vec(),
+            captured: 0,
+            active_progress: 0.0,
+        }
+    }
+
+    pub fn attacker(&self) -> TeamId {
+        self.attacker
+    }
+
+    pub fn defender(&self) -> TeamId {
+        self.defender
+    }
+
+    pub fn points(&self) -> &[EntityId] {
+        &self.points
+    }
+
+    pub fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn captured(&self) -> usize {
+        self.captured
+    }
+
+    /// The point that can currently be captured, if any remain.
+    pub fn active_point(&self) -> Option<EntityId> {
+        self.points.get(self.captured).copied()
+    }
+
+    pub fn active_progress(&self) -> f32 {
+        self.active_progress
+    }
+
+    pub fn set_active_progress(&mut self, progress: f32) {
+        self.active_progress = progress;
+    }
+
+    /// Mark the active point as captured.
+    pub fn capture_active(&mut self) {
+        self.captured = (self.captured + 1).min(self.points.len());
+        self.active_progress = 0.0;
+    }
+
+    /// How far the attacker got, number of points captured plus the progress on the active point.
+    pub fn progress(&self) -> f32 {
+        self.captured as f32 + self.active_progress
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.captured >= self.points.len()
+    }
+
+    /// The attacker leads only once all points are captured, until then the defender holds.
+    pub fn get_leader(&self) -> TeamId {
+        if self.is_finished() {
+            self.attacker
+        } else {
+            self.defender
+        }
+    }
+}
+impl Component for MatchAttackDefend {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_attack_defend_progress() {
+        let mut world = World::new();
+        let points = [world.add_entity(), world.add_entity()];
+        let attacker = make_team_id(1);
+        let defender = make_team_id(2);
+        let mut m = MatchAttackDefend::new(attacker, defender, &points);
+        assert_eq!(m.active_point(), Some(points[0]));
+        assert_eq!(m.get_leader(), defender);
+        m.set_active_progress(0.5);
+        assert_eq!(m.progress(), 0.5);
+        m.capture_active();
+        assert_eq!(m.active_point(), Some(points[1]));
+        assert_eq!(m.progress(), 1.0);
+        assert!(!m.is_finished());
+        m.capture_active();
+        assert!(m.is_finished());
+        assert_eq!(m.active_point(), None);
+        assert_eq!(m.get_leader(), attacker);
+    }
+}
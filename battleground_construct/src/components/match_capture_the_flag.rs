@@ -0,0 +1,78 @@
+use crate::components;
+use components::match_finished::unique_max;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchCaptureTheFlag {
+    points: std::collections::HashMap<TeamId, i64>,
+    point_limit: Option<i64>,
+    /// Duration after which a dropped flag returns to its base by itself.
+    return_time: Option<f32>,
+}
+
+impl MatchCaptureTheFlag {
+    pub fn new(point_limit: Option<i64>, return_time: Option<f32>) -> Self {
+        Self {
+            point_limit,
+            return_time,
+            points: Default::default(),
+        }
+    }
+
+    /// The team with the most points, `None` if several teams share the most points.
+    pub fn get_leader(&self) -> Option<(TeamId, i64)> {
+        unique_max(&self.points)
+    }
+
+    pub fn point_limit(&self) -> Option<i64> {
+        self.point_limit
+    }
+
+    pub fn return_time(&self) -> Option<f32> {
+        self.return_time
+    }
+
+    pub fn points(&self) -> Vec<(TeamId, i64)> {
+        let mut v: Vec<(TeamId, i64)> = self.points.iter().map(|(t, s)| (*t, *s)).collect();
+        v.sort_by_key(|a| a.0);
+        v
+    }
+
+    pub fn add_capture(&mut self, team: TeamId) {
+        *self.points.entry(team).or_insert(0) += 1;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        if let Some(limit) = self.point_limit {
+            for (_t, v) in self.points.iter() {
+                if *v >= limit {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+impl Component for MatchCaptureTheFlag {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_capture_the_flag_tie() {
+        let (t1, t2) = (make_team_id(1), make_team_id(2));
+        let mut ctf = MatchCaptureTheFlag::new(Some(1), None);
+        assert_eq!(ctf.get_leader(), None);
+
+        // Both teams reach the point limit on the same update, neither leads.
+        ctf.add_capture(t2);
+        ctf.add_capture(t1);
+        assert!(ctf.is_finished());
+        assert_eq!(ctf.get_leader(), None);
+        ctf.add_capture(t2);
+        assert_eq!(ctf.get_leader(), Some((t2, 2)));
+    }
+}
@@ -1,8 +1,12 @@
 use super::team::TeamId;
 use engine::prelude::*;
 
+use super::match_attack_defend::MatchAttackDefend;
+use super::match_capture_the_flag::MatchCaptureTheFlag;
 use super::match_domination::MatchDomination;
 use super::match_king_of_the_hill::MatchKingOfTheHill;
+use super::match_payload::MatchPayload;
+use super::match_scoring::MatchScoring;
 use super::match_team_deathmatch::MatchTeamDeathmatch;
 
 use serde::{Deserialize, Serialize};
@@ -13,6 +17,69 @@ pub enum MatchConclusion {
     TimeLimit,
     /// Match was concluded based on the objectives criteria being met.
     Objective,
+    /// Match was concluded because a unit's controller failed.
+    ControllerFailure,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum MatchOutcome {
+    /// A single team ranked first.
+    Win(TeamId),
+    /// Multiple teams are tied for the first place.
+    Draw(Vec<TeamId>),
+    /// The match can't be decided because controllers of these teams failed.
+    NoContest(Vec<TeamId>),
+}
+
+/// Rank teams by their keys, compared in order where greater is better. Teams with equal keys
+/// share a place.
+pub fn rank_teams(keys: &[(TeamId, Vec<f32>)]) -> Vec<Vec<TeamId>> {
+    let compare = |a: &[f32], b: &[f32]| {
+        a.iter()
+            .zip(b.iter())
+            .fold(std::cmp::Ordering::Equal, |o, (a, b)| {
+                o.then(a.total_cmp(b))
+            })
+    };
+    let mut sorted = keys.to_vec();
+    sorted.sort_by(|a, b| compare(&b.1, &a.1).then(a.0.cmp(&b.0)));
+
+    let mut ranking: Vec<Vec<TeamId>> = vec![];
+    let mut previous: Option<&[f32]> = None;
+    for (team, key) in sorted.iter() {
+        match previous {
+            Some(p) if compare(p, key) == std::cmp::Ordering::Equal => {
+                ranking.last_mut().unwrap().push(*team);
+            }
+            _ => ranking.push(vec![*team]),
+        }
+        previous = Some(key);
+    }
+    ranking
+}
+
+/// The key with the greatest value, `None` if the map is empty or several keys share the greatest
+/// value, such that a tie for the lead has no leader.
+pub fn unique_max<K: Copy, V: Copy + PartialOrd>(
+    points: &std::collections::HashMap<K, V>,
+) -> Option<(K, V)> {
+    let mut best: Option<(K, V)> = None;
+    let mut tied = false;
+    for (key, value) in points.iter() {
+        match best.map(|(_, b)| value.partial_cmp(&b)) {
+            None | Some(Some(std::cmp::Ordering::Greater)) => {
+                best = Some((*key, *value));
+                tied = false;
+            }
+            Some(Some(std::cmp::Ordering::Equal)) => tied = true,
+            _ => {}
+        }
+    }
+    if tied {
+        None
+    } else {
+        best
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -20,13 +87,22 @@ pub enum ObjectiveReport {
     MatchKingOfTheHill(MatchKingOfTheHill),
     MatchTeamDeathmatch(MatchTeamDeathmatch),
     MatchDomination(MatchDomination),
+    MatchCaptureTheFlag(MatchCaptureTheFlag),
+    MatchAttackDefend(MatchAttackDefend),
+    MatchPayload(MatchPayload),
+    MatchScoring(MatchScoring),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "StoredMatchReport", into = "StoredMatchReport")]
 pub struct MatchReport {
-    /// The winner of the match, if any. Technically, this ought to be a list... to support matches
-    /// ending in a draw, but that just makes things complicated.
+    /// The winner of the match, only set if the outcome is a win.
     pub winner: Option<TeamId>,
+    /// Outcome of the match.
+    pub outcome: MatchOutcome,
+    /// Teams from first to last place, teams that are tied share a place.
+    #[serde(default)]
+    pub ranking: Vec<Vec<TeamId>>,
     /// Reports by individual objectives.
     pub reports: Vec<ObjectiveReport>,
     /// Cause of the match finish declaration.
@@ -35,6 +111,49 @@ pub struct MatchReport {
     pub duration: f32,
 }
 
+/// Match report as stored, reports from before outcomes were recorded don't have one.
+#[derive(Serialize, Deserialize)]
+struct StoredMatchReport {
+    winner: Option<TeamId>,
+    outcome: Option<MatchOutcome>,
+    #[serde(default)]
+    ranking: Vec<Vec<TeamId>>,
+    reports: Vec<ObjectiveReport>,
+    conclusion: MatchConclusion,
+    duration: f32,
+}
+
+impl From<StoredMatchReport> for MatchReport {
+    fn from(stored: StoredMatchReport) -> Self {
+        // Without an outcome, the winner is all that is known about the result.
+        let outcome = stored.outcome.unwrap_or(match stored.winner {
+            Some(team) => MatchOutcome::Win(team),
+            None => MatchOutcome::Draw(vec![]),
+        });
+        MatchReport {
+            winner: stored.winner,
+            outcome,
+            ranking: stored.ranking,
+            reports: stored.reports,
+            conclusion: stored.conclusion,
+            duration: stored.duration,
+        }
+    }
+}
+
+impl From<MatchReport> for StoredMatchReport {
+    fn from(report: MatchReport) -> Self {
+        StoredMatchReport {
+            winner: report.winner,
+            outcome: Some(report.outcome),
+            ranking: report.ranking,
+            reports: report.reports,
+            conclusion: report.conclusion,
+            duration: report.duration,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MatchFinished {
     report: Option<MatchReport>,
@@ -61,3 +180,78 @@ impl MatchFinished {
     }
 }
 impl Component for MatchFinished {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_rank_teams() {
+        let t1 = make_team_id(1);
+        let t2 = make_team_id(2);
+        let t3 = make_team_id(3);
+        let t4 = make_team_id(4);
+        let ranking = rank_teams(&[
+            (t1, vec![0.0, 5.0]),
+            (t2, vec![1.0, 0.0]),
+            (t3, vec![0.0, 5.0]),
+            (t4, vec![0.0, 6.0]),
+        ]);
+        assert_eq!(ranking, vec![vec![t2], vec![t4], vec![t1, t3]]);
+
+        // Everything equal is a single shared place.
+        let ranking = rank_teams(&[(t2, vec![1.0]), (t1, vec![1.0])]);
+        assert_eq!(ranking, vec![vec![t1, t2]]);
+        assert!(rank_teams(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_unique_max() {
+        let (t1, t2, t3) = (make_team_id(1), make_team_id(2), make_team_id(3));
+        let mut points = std::collections::HashMap::new();
+        assert_eq!(unique_max(&points), None);
+        points.insert(t1, 3);
+        assert_eq!(unique_max(&points), Some((t1, 3)));
+        points.insert(t2, 5);
+        points.insert(t3, 5);
+        assert_eq!(unique_max(&points), None);
+        points.insert(t3, 6);
+        assert_eq!(unique_max(&points), Some((t3, 6)));
+    }
+
+    #[test]
+    fn test_report_without_outcome() {
+        let report: MatchReport = serde_yaml::from_str(
+            "winner: null\nreports: []\nconclusion: TimeLimit\nduration: 3.0\n",
+        )
+        .unwrap();
+        assert_eq!(report.outcome, MatchOutcome::Draw(vec![]));
+        assert!(report.ranking.is_empty());
+
+        // An old report with a winner reads as a win for that team.
+        let report: MatchReport =
+            serde_yaml::from_str("winner: 1\nreports: []\nconclusion: Objective\nduration: 3.0\n")
+                .unwrap();
+        assert_eq!(report.winner, Some(make_team_id(1)));
+        assert_eq!(report.outcome, MatchOutcome::Win(make_team_id(1)));
+
+        // A recorded outcome is kept as is.
+        let report: MatchReport = serde_yaml::from_str(
+            "winner: null\noutcome: !Draw [1, 2]\nreports: []\nconclusion: TimeLimit\n\
+             duration: 3.0\n",
+        )
+        .unwrap();
+        assert_eq!(
+            report.outcome,
+            MatchOutcome::Draw(vec![make_team_id(1), make_team_id(2)])
+        );
+
+        // And survives the binary format of the recordings.
+        let data = bincode::serialize(&report).unwrap();
+        let report: MatchReport = bincode::deserialize(&data).unwrap();
+        assert_eq!(
+            report.outcome,
+            MatchOutcome::Draw(vec![make_team_id(1), make_team_id(2)])
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::components;
+use components::match_finished::unique_max;
 use components::team::TeamId;
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -34,12 +35,9 @@ impl MatchKingOfTheHill {
         false
     }
 
+    /// The team with the most points, `None` if several teams share the most points.
     pub fn get_leader(&self) -> Option<TeamId> {
-        self.points
-            .iter()
-            .max_by(|a, b| a.1.total_cmp(b.1))
-            .map(|(k, _v)| k)
-            .copied()
+        unique_max(&self.points).map(|(team, _points)| team)
     }
 
     pub fn point_limit(&self) -> Option<f32> {
@@ -53,3 +51,20 @@ impl MatchKingOfTheHill {
     }
 }
 impl Component for MatchKingOfTheHill {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_king_of_the_hill_tie() {
+        for order in [[3, 1, 2], [2, 3, 1], [1, 2, 3]] {
+            let mut koth = MatchKingOfTheHill::new(None);
+            let points = order.map(|v| (make_team_id(v), 5.0));
+            koth.add_points(&points);
+            assert_eq!(koth.get_leader(), None);
+            koth.add_points(&[(make_team_id(order[0]), 1.0)]);
+            assert_eq!(koth.get_leader(), Some(make_team_id(order[0])));
+        }
+    }
+}
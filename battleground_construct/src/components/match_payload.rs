@@ -0,0 +1,61 @@
+use crate::components;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Asymmetric match; the attacker must escort the payload to the end of its path, the defender
+/// wins by holding it off until the time limit.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchPayload {
+    attacker: TeamId,
+    defender: TeamId,
+    /// Distance the payload travelled along its path.
+    distance: f32,
+    /// Total length of the payload's path.
+    path_length: f32,
+}
+
+impl MatchPayload {
+    pub fn new(attacker: TeamId, defender: TeamId, path_length: f32) -> Self {
+        Self {
+            attacker,
+            defender,
+            distance: 0.0,
+            path_length,
+        }
+    }
+
+    pub fn attacker(&self) -> TeamId {
+        self.attacker
+    }
+
+    pub fn defender(&self) -> TeamId {
+        self.defender
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn set_distance(&mut self, distance: f32) {
+        self.distance = distance;
+    }
+
+    pub fn path_length(&self) -> f32 {
+        self.path_length
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.distance >= self.path_length
+    }
+
+    /// The attacker leads only once the payload reached its destination.
+    pub fn get_leader(&self) -> TeamId {
+        if self.is_finished() {
+            self.attacker
+        } else {
+            self.defender
+        }
+    }
+}
+impl Component for MatchPayload {}
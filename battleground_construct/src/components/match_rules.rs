@@ -0,0 +1,51 @@
+use crate::components;
+use components::match_scoring::ScoreSource;
+use components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Rules that apply to any match type, used to decide the outcome of the match.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MatchRules {
+    /// Values compared in order to rank teams that are tied on the objectives.
+    tie_breakers: Vec<ScoreSource>,
+    /// Whether a failing controller ends the match as no contest.
+    no_contest_on_controller_failure: bool,
+    /// Teams of which a controller failed.
+    failed_teams: Vec<TeamId>,
+}
+
+impl MatchRules {
+    pub fn new(tie_breakers: &[ScoreSource], no_contest_on_controller_failure: bool) -> Self {
+        Self {
+            tie_breakers: tie_breakers.to_vec(),
+            no_contest_on_controller_failure,
+            failed_teams: vec![],
+        }
+    }
+
+    pub fn tie_breakers(&self) -> &[ScoreSource] {
+        &self.tie_breakers
+    }
+
+    pub fn no_contest_on_controller_failure(&self) -> bool {
+        self.no_contest_on_controller_failure
+    }
+
+    pub fn add_controller_failure(&mut self, team: TeamId) {
+        if !self.failed_teams.contains(&team) {
+            self.failed_teams.push(team);
+            self.failed_teams.sort();
+        }
+    }
+
+    pub fn failed_teams(&self) -> &[TeamId] {
+        &self.failed_teams
+    }
+
+    /// Whether the match can't be decided because a controller failed.
+    pub fn is_no_contest(&self) -> bool {
+        self.no_contest_on_controller_failure && !self.failed_teams.is_empty()
+    }
+}
+impl Component for MatchRules {}
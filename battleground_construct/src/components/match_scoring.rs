@@ -0,0 +1,256 @@
+use crate::components;
+use components::hit_by::HitByHistory;
+use components::match_attack_defend::MatchAttackDefend;
+use components::match_capture_the_flag::MatchCaptureTheFlag;
+use components::match_king_of_the_hill::MatchKingOfTheHill;
+use components::match_payload::MatchPayload;
+use components::match_team_deathmatch::MatchTeamDeathmatch;
+use components::team::TeamId;
+use components::team_member::TeamMember;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Per team values that can contribute to the score.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreSource {
+    /// Points accrued by holding capture points.
+    KingOfTheHill,
+    /// Kills, friendly fire subtracts.
+    TeamDeathmatch,
+    /// Flags captured.
+    CaptureTheFlag,
+    /// Capture points captured by the attacker, including progress on the active point.
+    AttackDefend,
+    /// Distance the payload was escorted by the attacker.
+    Payload,
+    /// Total damage dealt to units of other teams.
+    Damage,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+/// Weight applied to the values of a score source.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ScoreWeight {
+    pub source: ScoreSource,
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+}
+
+/// Combines the values of several objectives into a single weighted score per team, ties in the
+/// score are broken by comparing the tie breaker sources in order.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchScoring {
+    weights: Vec<ScoreWeight>,
+    tie_breakers: Vec<ScoreSource>,
+    teams: Vec<TeamId>,
+    values: Vec<(ScoreSource, Vec<(TeamId, f32)>)>,
+}
+
+impl MatchScoring {
+    pub fn new(weights: &[ScoreWeight], tie_breakers: &[ScoreSource]) -> Self {
+        Self {
+            weights: weights.to_vec(),
+            tie_breakers: tie_breakers.to_vec(),
+            teams: vec![],
+            values: vec![],
+        }
+    }
+
+    pub fn weights(&self) -> &[ScoreWeight] {
+        &self.weights
+    }
+
+    pub fn tie_breakers(&self) -> &[ScoreSource] {
+        &self.tie_breakers
+    }
+
+    /// All sources that are either weighted or used as tie breaker.
+    pub fn sources(&self) -> Vec<ScoreSource> {
+        let mut sources: Vec<ScoreSource> = self.weights.iter().map(|w| w.source).collect();
+        for source in self.tie_breakers.iter() {
+            if !sources.contains(source) {
+                sources.push(*source);
+            }
+        }
+        sources
+    }
+
+    pub fn set_teams(&mut self, teams: &[TeamId]) {
+        self.teams = teams.to_vec();
+        self.teams.sort();
+    }
+
+    pub fn set_values(&mut self, source: ScoreSource, values: &[(TeamId, f32)]) {
+        let mut values = values.to_vec();
+        values.sort_by_key(|a| a.0);
+        if let Some(entry) = self.values.iter_mut().find(|(s, _)| *s == source) {
+            entry.1 = values;
+        } else {
+            self.values.push((source, values));
+        }
+    }
+
+    /// The value of a source for a team, zero if the team has none.
+    pub fn value(&self, source: ScoreSource, team: TeamId) -> f32 {
+        self.values
+            .iter()
+            .find(|(s, _)| *s == source)
+            .and_then(|(_, v)| v.iter().find(|(t, _)| *t == team))
+            .map(|(_, v)| *v)
+            .unwrap_or(0.0)
+    }
+
+    /// The weighted score of a team.
+    pub fn score(&self, team: TeamId) -> f32 {
+        self.weights
+            .iter()
+            .map(|w| w.weight * self.value(w.source, team))
+            .sum()
+    }
+
+    pub fn scores(&self) -> Vec<(TeamId, f32)> {
+        self.teams.iter().map(|t| (*t, self.score(*t))).collect()
+    }
+
+    /// Compare two teams by score, then by tie breakers, greater is better.
+    pub fn compare(&self, a: TeamId, b: TeamId) -> std::cmp::Ordering {
+        let mut ordering = self.score(a).total_cmp(&self.score(b));
+        for source in self.tie_breakers.iter() {
+            ordering = ordering.then(self.value(*source, a).total_cmp(&self.value(*source, b)));
+        }
+        ordering
+    }
+
+    /// Teams ordered from best to worst.
+    pub fn ranking(&self) -> Vec<TeamId> {
+        let mut teams = self.teams.clone();
+        teams.sort_by(|a, b| self.compare(*b, *a));
+        teams
+    }
+
+    /// The best team, none if it is tied with the runner up.
+    pub fn get_leader(&self) -> Option<TeamId> {
+        let ranking = self.ranking();
+        let first = *ranking.first()?;
+        if let Some(second) = ranking.get(1) {
+            if self.compare(first, *second) == std::cmp::Ordering::Equal {
+                return None;
+            }
+        }
+        Some(first)
+    }
+}
+impl Component for MatchScoring {}
+
+/// Obtain the per team values for a score source from the objective it relates to.
+pub fn source_values(world: &World, source: ScoreSource) -> Vec<(TeamId, f32)> {
+    match source {
+        ScoreSource::KingOfTheHill => world
+            .component_iter::<MatchKingOfTheHill>()
+            .next()
+            .map(|(_e, koth)| koth.points())
+            .unwrap_or_default(),
+        ScoreSource::TeamDeathmatch => world
+            .component_iter::<MatchTeamDeathmatch>()
+            .next()
+            .map(|(_e, deathmatch)| {
+                deathmatch
+                    .points()
+                    .iter()
+                    .map(|(t, v)| (*t, *v as f32))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        ScoreSource::CaptureTheFlag => world
+            .component_iter::<MatchCaptureTheFlag>()
+            .next()
+            .map(|(_e, ctf)| ctf.points().iter().map(|(t, v)| (*t, *v as f32)).collect())
+            .unwrap_or_default(),
+        ScoreSource::AttackDefend => world
+            .component_iter::<MatchAttackDefend>()
+            .next()
+            .map(|(_e, attack_defend)| vec![(attack_defend.attacker(), attack_defend.progress())])
+            .unwrap_or_default(),
+        ScoreSource::Payload => world
+            .component_iter::<MatchPayload>()
+            .next()
+            .map(|(_e, payload)| vec![(payload.attacker(), payload.distance())])
+            .unwrap_or_default(),
+        ScoreSource::Damage => {
+            // Hit histories live on the unit entities, which are never removed.
+            let mut damage: std::collections::HashMap<TeamId, f32> = Default::default();
+            for (entity, history) in world.component_iter::<HitByHistory>() {
+                let target_team = world.component::<TeamMember>(entity).map(|t| t.team());
+                for record in history.hits() {
+                    let source_team = record
+                        .source()
+                        .and_then(|unit| components::unit::get_unit_entity(world, unit))
+                        .and_then(|unit_entity| world.component::<TeamMember>(unit_entity))
+                        .map(|t| t.team());
+                    if let Some(source_team) = source_team {
+                        if Some(source_team) != target_team {
+                            *damage.entry(source_team).or_insert(0.0) += record.damage();
+                        }
+                    }
+                }
+            }
+            damage.drain().collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    use crate::util::test_util::*;
+
+    #[test]
+    fn test_match_scoring() {
+        let t1 = make_team_id(1);
+        let t2 = make_team_id(2);
+        let mut scoring = MatchScoring::new(
+            &[
+                ScoreWeight {
+                    source: ScoreSource::KingOfTheHill,
+                    weight: 1.0,
+                },
+                ScoreWeight {
+                    source: ScoreSource::TeamDeathmatch,
+                    weight: 5.0,
+                },
+            ],
+            &[ScoreSource::Damage],
+        );
+        scoring.set_teams(&[t2, t1]);
+        assert_eq!(
+            scoring.sources(),
+            vec![
+                ScoreSource::KingOfTheHill,
+                ScoreSource::TeamDeathmatch,
+                ScoreSource::Damage
+            ]
+        );
+
+        // Nothing happened, tied.
+        assert_eq!(scoring.get_leader(), None);
+
+        scoring.set_values(ScoreSource::KingOfTheHill, &[(t1, 10.0), (t2, 5.0)]);
+        scoring.set_values(ScoreSource::TeamDeathmatch, &[(t2, 1.0)]);
+        approx_equal!(scoring.score(t1), 10.0, 0.0001);
+        approx_equal!(scoring.score(t2), 10.0, 0.0001);
+        assert_eq!(scoring.get_leader(), None);
+
+        // Damage breaks the tie.
+        scoring.set_values(ScoreSource::Damage, &[(t1, 0.5), (t2, 1.5)]);
+        assert_eq!(scoring.get_leader(), Some(t2));
+        assert_eq!(scoring.ranking(), vec![t2, t1]);
+
+        // Weighted score takes precedence over the tie breaker.
+        scoring.set_values(ScoreSource::KingOfTheHill, &[(t1, 11.0), (t2, 5.0)]);
+        assert_eq!(scoring.get_leader(), Some(t1));
+    }
+}
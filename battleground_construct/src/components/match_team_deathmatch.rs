@@ -1,4 +1,5 @@
 use crate::components;
+use components::match_finished::unique_max;
 use components::team::TeamId;
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -23,11 +24,9 @@ impl MatchTeamDeathmatch {
         }
     }
 
+    /// The team with the most points, `None` if several teams share the most points.
     pub fn get_leader(&self) -> Option<(TeamId, i64)> {
-        self.points
-            .iter()
-            .max_by(|a, b| a.1.cmp(b.1))
-            .map(|t| (*t.0, *t.1))
+        unique_max(&self.points)
     }
 
     pub fn point_limit(&self) -> Option<i64> {
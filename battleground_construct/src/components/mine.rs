@@ -0,0 +1,222 @@
+use crate::components::team::TeamId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct MineLayerConfig {
+    /// Number of mines that can be deployed.
+    pub count: usize,
+    /// Duration between deployment and the mine being armed.
+    pub arming_delay: f32,
+    /// Horizontal distance within which a unit's body triggers the mine.
+    pub trigger_radius: f32,
+    /// Minimum duration between two deployments.
+    pub deploy_interval: f32,
+    /// Splash damage at the center of the detonation.
+    pub damage: f32,
+    /// Radius of the splash damage.
+    pub splash_radius: f32,
+}
+
+impl Default for MineLayerConfig {
+    fn default() -> Self {
+        MineLayerConfig {
+            count: 3,
+            arming_delay: 3.0,
+            trigger_radius: 0.75,
+            deploy_interval: 1.0,
+            damage: 0.6,
+            splash_radius: 1.5,
+        }
+    }
+}
+
+pub struct MineLayer {
+    config: MineLayerConfig,
+    remaining: usize,
+    last_deploy_time: f32,
+    is_triggered: bool,
+}
+
+impl MineLayer {
+    pub fn new(config: MineLayerConfig) -> Self {
+        MineLayer {
+            config,
+            remaining: config.count,
+            last_deploy_time: -config.deploy_interval,
+            is_triggered: false,
+        }
+    }
+
+    pub fn config(&self) -> MineLayerConfig {
+        self.config
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.is_triggered
+    }
+
+    pub fn trigger(&mut self) {
+        self.is_triggered = true;
+    }
+
+    pub fn is_ready(&self, current_time: f32) -> bool {
+        self.remaining > 0 && (current_time - self.last_deploy_time) >= self.config.deploy_interval
+    }
+
+    /// Mark a mine as deployed.
+    pub fn deployed(&mut self, current_time: f32) {
+        self.remaining = self.remaining.saturating_sub(1);
+        self.last_deploy_time = current_time;
+        self.is_triggered = false;
+    }
+}
+impl Component for MineLayer {}
+
+/// A deployed mine.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct Mine {
+    /// Team that deployed the mine, units of this team don't trigger it.
+    team: Option<TeamId>,
+    /// Time at which the mine becomes armed.
+    armed_at: f32,
+    trigger_radius: f32,
+}
+
+impl Mine {
+    pub fn new(team: Option<TeamId>, armed_at: f32, trigger_radius: f32) -> Self {
+        Mine {
+            team,
+            armed_at,
+            trigger_radius,
+        }
+    }
+
+    pub fn team(&self) -> Option<TeamId> {
+        self.team
+    }
+
+    pub fn is_armed(&self, current_time: f32) -> bool {
+        current_time >= self.armed_at
+    }
+
+    pub fn trigger_radius(&self) -> f32 {
+        self.trigger_radius
+    }
+
+    /// Whether a unit of this team triggers the mine, mines without a team are triggered by
+    /// anyone.
+    pub fn is_triggered_by(&self, team: Option<TeamId>) -> bool {
+        self.team.is_none() || self.team != team
+    }
+}
+impl Component for Mine {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::mine_layer::*;
+pub struct MineLayerModule {
+    entity: EntityId,
+}
+
+impl MineLayerModule {
+    pub fn new(entity: EntityId) -> Self {
+        MineLayerModule { entity }
+    }
+}
+
+impl UnitModule for MineLayerModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        let current_time = world
+            .component_iter::<crate::components::clock::Clock>()
+            .next()
+            .map(|(_entity, clock)| clock.elapsed_as_f32())
+            .unwrap_or(0.0);
+        if let Some(layer) = world.component::<MineLayer>(self.entity) {
+            registers.insert(
+                REG_MINE_LAYER_DEPLOY,
+                Register::new_i32("deploy", layer.is_triggered() as i32)
+                    .with_range(0.0, 1.0)
+                    .with_description("set to deploy a mine when ready"),
+            );
+            registers.insert(
+                REG_MINE_LAYER_IS_TRIGGERED,
+                Register::new_i32("is_triggered", layer.is_triggered() as i32).read_only(),
+            );
+            registers.insert(
+                REG_MINE_LAYER_READY,
+                Register::new_i32("ready", layer.is_ready(current_time) as i32).read_only(),
+            );
+            registers.insert(
+                REG_MINE_LAYER_REMAINING,
+                Register::new_i32("remaining", layer.remaining() as i32).read_only(),
+            );
+            registers.insert(
+                REG_MINE_LAYER_ARMING_DELAY,
+                Register::new_f32("arming_delay", layer.config.arming_delay)
+                    .with_unit("s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MINE_LAYER_TRIGGER_RADIUS,
+                Register::new_f32("trigger_radius", layer.config.trigger_radius)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MINE_LAYER_DEPLOY_INTERVAL,
+                Register::new_f32("deploy_interval", layer.config.deploy_interval)
+                    .with_unit("s")
+                    .read_only(),
+            );
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        if let Some(mut layer) = world.component_mut::<MineLayer>(self.entity) {
+            let deploy = registers
+                .get(&REG_MINE_LAYER_DEPLOY)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            if deploy != 0 {
+                layer.trigger();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    #[test]
+    fn test_mine_layer() {
+        let mut layer = MineLayer::new(MineLayerConfig {
+            count: 2,
+            deploy_interval: 1.0,
+            ..Default::default()
+        });
+        assert!(layer.is_ready(0.0));
+        layer.deployed(0.0);
+        assert!(!layer.is_ready(0.5));
+        assert!(layer.is_ready(1.0));
+        layer.deployed(1.0);
+        assert_eq!(layer.remaining(), 0);
+        assert!(!layer.is_ready(5.0));
+
+        let team_a = make_team_id(1);
+        let team_b = make_team_id(2);
+        let mine = Mine::new(Some(team_a), 3.0, 1.0);
+        assert!(!mine.is_armed(2.0));
+        assert!(mine.is_armed(3.0));
+        assert!(!mine.is_triggered_by(Some(team_a)));
+        assert!(mine.is_triggered_by(Some(team_b)));
+        assert!(mine.is_triggered_by(None));
+        assert!(Mine::new(None, 0.0, 1.0).is_triggered_by(Some(team_a)));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::components::guided_missile::{GuidedMissileConfig, MissileSeeker};
+use crate::display::primitives::Vec3;
+use engine::prelude::*;
+
+// This must be an Rc, as we need to be able to copy it to allow a mutable world, we cannot borrow
+// it out of the launcher.
+pub type MissileLaunchEffect = std::rc::Rc<dyn for<'a> Fn(&'a mut World, EntityId)>;
+
+pub struct MissileLauncherConfig {
+    pub reload_time: f32,
+    pub missile: GuidedMissileConfig,
+    pub launch_effect: MissileLaunchEffect,
+}
+
+pub struct MissileLauncher {
+    last_fire_time: f32,
+    is_ready: bool,
+    is_triggered: bool,
+    seeker: MissileSeeker,
+    config: MissileLauncherConfig,
+}
+
+impl MissileLauncher {
+    pub fn new(config: MissileLauncherConfig) -> Self {
+        Self {
+            last_fire_time: -config.reload_time, // spawn ready to fire.
+            is_ready: true,
+            is_triggered: false,
+            seeker: MissileSeeker::None,
+            config,
+        }
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.is_triggered
+    }
+
+    pub fn trigger(&mut self) {
+        self.is_triggered = true;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.is_ready
+    }
+
+    pub fn update(&mut self, current_time: f32) {
+        self.is_ready = (current_time - self.last_fire_time) > self.config.reload_time
+    }
+
+    pub fn fired(&mut self, current_time: f32) {
+        self.last_fire_time = current_time;
+        self.is_triggered = false;
+    }
+
+    pub fn reload_time(&self) -> f32 {
+        self.config.reload_time
+    }
+
+    pub fn set_reload_time(&mut self, reload_time: f32) {
+        self.config.reload_time = reload_time;
+    }
+
+    /// The seeker the next missile will be launched with.
+    pub fn seeker(&self) -> MissileSeeker {
+        self.seeker
+    }
+
+    pub fn set_seeker(&mut self, seeker: MissileSeeker) {
+        self.seeker = seeker;
+    }
+
+    pub fn missile_config(&self) -> GuidedMissileConfig {
+        self.config.missile
+    }
+
+    pub fn effect(&self) -> MissileLaunchEffect {
+        self.config.launch_effect.clone()
+    }
+}
+impl Component for MissileLauncher {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::missile_launcher::*;
+pub struct MissileLauncherModule {
+    entity: EntityId,
+}
+
+impl MissileLauncherModule {
+    pub fn new(entity: EntityId) -> Self {
+        MissileLauncherModule { entity }
+    }
+}
+
+impl UnitModule for MissileLauncherModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(launcher) = world.component::<MissileLauncher>(self.entity) {
+            registers.insert(
+                REG_MISSILE_LAUNCHER_TRIGGER,
+                Register::new_i32("trigger", launcher.is_triggered() as i32)
+                    .with_range(0.0, 1.0)
+                    .with_description("set to launch a missile when ready"),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_IS_TRIGGERED,
+                Register::new_i32("is_triggered", launcher.is_triggered() as i32).read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_READY,
+                Register::new_i32("ready", launcher.is_ready() as i32).read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_RELOAD_TIME,
+                Register::new_f32("reload_time", launcher.reload_time())
+                    .with_unit("s")
+                    .read_only(),
+            );
+
+            let (mode, target) = match launcher.seeker() {
+                MissileSeeker::None => (MISSILE_SEEKER_MODE_NONE, Vec3::new(0.0, 0.0, 0.0)),
+                MissileSeeker::Point(target) => (MISSILE_SEEKER_MODE_POINT, target),
+                MissileSeeker::Radar => (MISSILE_SEEKER_MODE_RADAR, Vec3::new(0.0, 0.0, 0.0)),
+            };
+            registers.insert(
+                REG_MISSILE_LAUNCHER_SEEKER_MODE,
+                Register::new_i32("seeker_mode", mode)
+                    .with_description("seeker mode used by the next missile"),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_TARGET_X,
+                Register::new_f32("target_x", target.x)
+                    .with_unit("m")
+                    .with_description("target for the next missile"),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_TARGET_Y,
+                Register::new_f32("target_y", target.y)
+                    .with_unit("m")
+                    .with_description("target for the next missile"),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_TARGET_Z,
+                Register::new_f32("target_z", target.z)
+                    .with_unit("m")
+                    .with_description("target for the next missile"),
+            );
+
+            let missile = launcher.missile_config();
+            registers.insert(
+                REG_MISSILE_LAUNCHER_MISSILE_SPEED,
+                Register::new_f32("missile_speed", missile.speed)
+                    .with_unit("m/s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_MISSILE_TURN_RATE,
+                Register::new_f32("missile_turn_rate", missile.turn_rate)
+                    .with_unit("rad/s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_MISSILE_FUEL,
+                Register::new_f32("missile_fuel", missile.fuel)
+                    .with_unit("s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_SEEKER_RANGE,
+                Register::new_f32("seeker_range", missile.seeker_range)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_MISSILE_LAUNCHER_SEEKER_ANGLE,
+                Register::new_f32("seeker_angle", missile.seeker_angle)
+                    .with_unit("rad")
+                    .read_only(),
+            );
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        if let Some(mut launcher) = world.component_mut::<MissileLauncher>(self.entity) {
+            let trigger = registers
+                .get(&REG_MISSILE_LAUNCHER_TRIGGER)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            if trigger != 0 {
+                launcher.trigger();
+            }
+
+            let read_f32 = |reg: u32| {
+                registers
+                    .get(&reg)
+                    .expect("register doesnt exist")
+                    .value_f32()
+                    .expect("wrong value type")
+            };
+            let target = Vec3::new(
+                read_f32(REG_MISSILE_LAUNCHER_TARGET_X),
+                read_f32(REG_MISSILE_LAUNCHER_TARGET_Y),
+                read_f32(REG_MISSILE_LAUNCHER_TARGET_Z),
+            );
+            let mode = registers
+                .get(&REG_MISSILE_LAUNCHER_SEEKER_MODE)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            let seeker = match mode {
+                MISSILE_SEEKER_MODE_POINT => MissileSeeker::Point(target),
+                MISSILE_SEEKER_MODE_RADAR => MissileSeeker::Radar,
+                _ => MissileSeeker::None,
+            };
+            launcher.set_seeker(seeker);
+        }
+    }
+}
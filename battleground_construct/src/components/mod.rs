@@ -1,11 +1,15 @@
 pub mod acceleration;
+pub mod atmosphere;
 pub mod camera_position;
 pub mod camera_target;
 pub mod cannon;
 pub mod capturable;
 pub mod capture_marker;
 pub mod capture_point;
+pub mod carriable_flag;
 pub mod clock;
+pub mod commander;
+pub mod control_recording;
 pub mod damage_hit;
 pub mod damage_splash;
 pub mod destroyed;
@@ -15,6 +19,7 @@ pub mod expiry;
 pub mod function_pose;
 pub mod gps;
 pub mod group;
+pub mod guided_missile;
 pub mod gun_battery;
 pub mod health;
 pub mod hit_box;
@@ -25,22 +30,39 @@ pub mod hit_plane;
 pub mod hit_sphere;
 pub mod id_generator;
 pub mod impact;
+pub mod imu;
+pub mod lidar;
+pub mod localization;
+pub mod match_attack_defend;
+pub mod match_capture_the_flag;
 pub mod match_domination;
 pub mod match_finished;
 pub mod match_king_of_the_hill;
+pub mod match_payload;
+pub mod match_rules;
+pub mod match_scoring;
 pub mod match_team_deathmatch;
 pub mod match_time_limit;
+pub mod mine;
+pub mod missile_launcher;
 pub mod objectives_module;
+pub mod odometry;
+pub mod orders;
 pub mod parent;
+pub mod payload;
 pub mod point_projectile;
 pub mod pose;
 pub mod radar;
 pub mod radar_reflector;
+pub mod radio_jammer;
+pub mod radio_propagation;
 pub mod radio_receiver;
 pub mod radio_transmitter;
 pub mod recording;
+pub mod reinforcements;
 pub mod revolute;
 pub mod select_box;
+pub mod subsystem;
 pub mod team;
 pub mod team_member;
 pub mod team_module;
@@ -52,3 +74,4 @@ pub mod unit_member;
 pub mod unit_source;
 pub mod velocity;
 pub mod victory_effect;
+pub mod visibility;
@@ -4,19 +4,22 @@ use engine::prelude::*;
 use crate::components;
 use components::capturable::Capturable;
 use components::capture_point::CapturePoint;
+use components::carriable_flag::{CarriableFlag, FlagState};
+use components::match_attack_defend::MatchAttackDefend;
+use components::match_payload::MatchPayload;
+use components::payload::Payload;
+use components::team_member::TeamMember;
+use components::visibility::Visibility;
 // use components::match_king_of_the_hill::MatchKingOfTheHill;
 
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 
-pub struct ObjectivesModule {}
-impl ObjectivesModule {
-    pub fn new() -> Self {
-        ObjectivesModule {}
-    }
+pub struct ObjectivesModule {
+    entity: EntityId,
 }
-impl Default for ObjectivesModule {
-    fn default() -> Self {
-        Self::new()
+impl ObjectivesModule {
+    pub fn new(entity: EntityId) -> Self {
+        ObjectivesModule { entity }
     }
 }
 
@@ -26,6 +29,26 @@ impl UnitModule for ObjectivesModule {
     fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
         registers.clear();
 
+        // With fog of war, objectives not observed by the team of this unit are reported unknown.
+        let team = world.component::<TeamMember>(self.entity).map(|v| v.team());
+        let visibility = world
+            .component_iter::<Visibility>()
+            .next()
+            .map(|(_e, v)| v)
+            .filter(|v| v.is_fog_of_war());
+        let is_observed = |entity: EntityId| {
+            if let Some(visibility) = visibility.as_ref() {
+                team.map(|t| visibility.can_see_entity(t, entity))
+                    .unwrap_or(false)
+            } else {
+                true
+            }
+        };
+        registers.insert(
+            REG_OBJECTIVES_FOG_OF_WAR,
+            Register::new_i32("fog_of_war", visibility.is_some() as i32).read_only(),
+        );
+
         // unit control actually doesn't need to know about the game type? Maybe they do... shrug.
         // if let Some((_e, mut koth)) = world.component_iter_mut::<MatchKingOfTheHill>().next() {
         // }
@@ -36,13 +59,21 @@ impl UnitModule for ObjectivesModule {
             if let Some(point) = world.component::<CapturePoint>(e) {
                 use crate::util::cgmath::ToTranslation;
                 let pose = world_pose(world, e).to_translation();
-                capture_points.push((pose.x, pose.y, capturable.owner(), point.radius()));
+                let owner_value = if is_observed(e) {
+                    capturable
+                        .owner()
+                        .map(|v| v.as_u64() as i32)
+                        .unwrap_or(OBJECTIVES_CAPTURE_POINT_UNOWNED)
+                } else {
+                    OBJECTIVES_CAPTURE_POINT_UNKNOWN
+                };
+                capture_points.push((pose.x, pose.y, owner_value, point.radius()));
             }
         }
 
         registers.insert(
             REG_OBJECTIVES_CAPTURE_POINT_COUNT,
-            Register::new_i32("capture_point_count", capture_points.len() as i32),
+            Register::new_i32("capture_point_count", capture_points.len() as i32).read_only(),
         );
 
         for (i, (x, y, owner, radius)) in capture_points.iter().enumerate() {
@@ -50,23 +81,180 @@ impl UnitModule for ObjectivesModule {
                 REG_OBJECTIVES_CAPTURE_POINT_START + i as u32 * REG_OBJECTIVES_CAPTURE_POINT_STRIDE;
             registers.insert(
                 base_offset + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_X,
-                Register::new_f32("x", *x),
+                Register::new_f32("x", *x).read_only(),
             );
             registers.insert(
                 base_offset + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_Y,
-                Register::new_f32("y", *y),
+                Register::new_f32("y", *y).read_only(),
             );
-            let owner_value = owner
-                .map(|v| v.as_u64() as i32)
-                .unwrap_or(OBJECTIVES_CAPTURE_POINT_UNOWNED);
             registers.insert(
                 base_offset + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_OWNER,
-                Register::new_i32("owner", owner_value),
+                Register::new_i32("owner", *owner).read_only(),
             );
             registers.insert(
                 base_offset + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_RADIUS,
-                Register::new_f32("radius", *radius),
+                Register::new_f32("radius", *radius).read_only(),
+            );
+        }
+
+        // Collect the carriable flags.
+        let mut flags = vec![];
+        for (e, flag) in world.component_iter::<CarriableFlag>() {
+            use crate::util::cgmath::ToTranslation;
+            let pose = world_pose(world, e).to_translation();
+            flags.push((pose.x, pose.y, *flag, is_observed(e)));
+        }
+
+        registers.insert(
+            REG_OBJECTIVES_FLAG_COUNT,
+            Register::new_i32("flag_count", flags.len() as i32).read_only(),
+        );
+
+        for (i, (x, y, flag, observed)) in flags.iter().enumerate() {
+            let base_offset = REG_OBJECTIVES_FLAG_START + i as u32 * REG_OBJECTIVES_FLAG_STRIDE;
+            let (base_x, base_y) = flag.base();
+            // An unobserved flag is reported at its base, with unknown state.
+            let (x, y) = if *observed {
+                (x, y)
+            } else {
+                (&base_x, &base_y)
+            };
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_X,
+                Register::new_f32("x", *x).read_only(),
             );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_Y,
+                Register::new_f32("y", *y).read_only(),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_TEAM,
+                Register::new_i32("team", flag.team().as_u64() as i32).read_only(),
+            );
+            let carrier_value = if *observed {
+                flag.carrier()
+                    .map(|v| v.as_u64() as i32)
+                    .unwrap_or(OBJECTIVES_FLAG_NO_CARRIER)
+            } else {
+                OBJECTIVES_FLAG_CARRIER_UNKNOWN
+            };
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_CARRIER,
+                Register::new_i32("carrier", carrier_value).read_only(),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_BASE_X,
+                Register::new_f32("base_x", base_x).read_only(),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_BASE_Y,
+                Register::new_f32("base_y", base_y).read_only(),
+            );
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_RADIUS,
+                Register::new_f32("radius", flag.radius()).read_only(),
+            );
+            let state_value = match flag.state() {
+                _ if !*observed => OBJECTIVES_FLAG_STATE_UNKNOWN,
+                FlagState::AtBase => OBJECTIVES_FLAG_STATE_AT_BASE,
+                FlagState::Carried { .. } => OBJECTIVES_FLAG_STATE_CARRIED,
+                FlagState::Dropped { .. } => OBJECTIVES_FLAG_STATE_DROPPED,
+            };
+            registers.insert(
+                base_offset + REG_OBJECTIVES_FLAG_OFFSET_STATE,
+                Register::new_i32("state", state_value).read_only(),
+            );
+        }
+
+        // Attack defend match information, only present in that match type.
+        if let Some((_e, attack_defend)) = world.component_iter::<MatchAttackDefend>().next() {
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_ATTACKER,
+                Register::new_i32("attacker", attack_defend.attacker().as_u64() as i32).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_DEFENDER,
+                Register::new_i32("defender", attack_defend.defender().as_u64() as i32).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_POINT_COUNT,
+                Register::new_i32("point_count", attack_defend.point_count() as i32).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_CAPTURED,
+                Register::new_i32("captured", attack_defend.captured() as i32).read_only(),
+            );
+            // If all points are captured, the last point remains the active one.
+            let active = attack_defend
+                .active_point()
+                .or_else(|| attack_defend.points().last().copied());
+            let (x, y, radius) = active
+                .map(|e| {
+                    use crate::util::cgmath::ToTranslation;
+                    let pose = world_pose(world, e).to_translation();
+                    let radius = world
+                        .component::<CapturePoint>(e)
+                        .map(|p| p.radius())
+                        .unwrap_or(0.0);
+                    (pose.x, pose.y, radius)
+                })
+                .unwrap_or((0.0, 0.0, 0.0));
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_X,
+                Register::new_f32("active_x", x).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_Y,
+                Register::new_f32("active_y", y).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_RADIUS,
+                Register::new_f32("active_radius", radius).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_PROGRESS,
+                Register::new_f32("active_progress", attack_defend.active_progress()).read_only(),
+            );
+        }
+
+        // Payload match information, only present in that match type.
+        if let Some((_e, match_payload)) = world.component_iter::<MatchPayload>().next() {
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_ATTACKER,
+                Register::new_i32("attacker", match_payload.attacker().as_u64() as i32).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_DEFENDER,
+                Register::new_i32("defender", match_payload.defender().as_u64() as i32).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_DISTANCE,
+                Register::new_f32("distance", match_payload.distance()).read_only(),
+            );
+            registers.insert(
+                REG_OBJECTIVES_PAYLOAD_PATH_LENGTH,
+                Register::new_f32("path_length", match_payload.path_length()).read_only(),
+            );
+            if let Some((e, payload)) = world.component_iter::<Payload>().next() {
+                use crate::util::cgmath::ToTranslation;
+                let pose = world_pose(world, e).to_translation();
+                registers.insert(
+                    REG_OBJECTIVES_PAYLOAD_X,
+                    Register::new_f32("x", pose.x).read_only(),
+                );
+                registers.insert(
+                    REG_OBJECTIVES_PAYLOAD_Y,
+                    Register::new_f32("y", pose.y).read_only(),
+                );
+                registers.insert(
+                    REG_OBJECTIVES_PAYLOAD_RADIUS,
+                    Register::new_f32("radius", payload.radius()).read_only(),
+                );
+                registers.insert(
+                    REG_OBJECTIVES_PAYLOAD_IS_MOVING,
+                    Register::new_i32("is_moving", payload.is_moving() as i32).read_only(),
+                );
+            }
         }
     }
 }
@@ -0,0 +1,102 @@
+use engine::prelude::*;
+
+/// Dead reckoning from the velocity of the base, relative to the pose at which it started.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Odometry {
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+    /// Measured forward velocity.
+    pub velocity: f32,
+    /// Measured yaw rate.
+    pub yaw_rate: f32,
+}
+
+impl Odometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Integrate the measured velocity and yaw rate over a timestep.
+    pub fn integrate(&mut self, velocity: f32, yaw_rate: f32, dt: f32) {
+        self.velocity = velocity;
+        self.yaw_rate = yaw_rate;
+        // Midpoint integration, exact for constant velocities over the step.
+        let yaw_mid = self.yaw + yaw_rate * dt / 2.0;
+        self.x += velocity * yaw_mid.cos() * dt;
+        self.y += velocity * yaw_mid.sin() * dt;
+        self.yaw += yaw_rate * dt;
+    }
+}
+impl Component for Odometry {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::odometry::*;
+
+pub struct OdometryModule {
+    entity: EntityId,
+}
+
+impl OdometryModule {
+    pub fn new(entity: EntityId) -> Self {
+        OdometryModule { entity }
+    }
+}
+
+impl UnitModule for OdometryModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(odometry) = world.component::<Odometry>(self.entity) {
+            registers.insert(
+                REG_ODOMETRY_X,
+                Register::new_f32("x", odometry.x)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_ODOMETRY_Y,
+                Register::new_f32("y", odometry.y)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_ODOMETRY_YAW,
+                Register::new_f32("yaw", odometry.yaw)
+                    .with_unit("rad")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_ODOMETRY_VELOCITY,
+                Register::new_f32("velocity", odometry.velocity)
+                    .with_unit("m/s")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_ODOMETRY_YAW_RATE,
+                Register::new_f32("yaw_rate", odometry.yaw_rate)
+                    .with_unit("rad/s")
+                    .read_only(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+
+    #[test]
+    fn test_odometry_integrate() {
+        let mut odometry = Odometry::new();
+        // Drive a quarter circle with radius 1.0.
+        let steps = 1000;
+        let dt = 1.0 / steps as f32;
+        for _ in 0..steps {
+            odometry.integrate(std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2, dt);
+        }
+        approx_equal!(odometry.x, 1.0, 0.001);
+        approx_equal!(odometry.y, 1.0, 0.001);
+        approx_equal!(odometry.yaw, std::f32::consts::FRAC_PI_2, 0.001);
+    }
+}
@@ -0,0 +1,68 @@
+use engine::prelude::*;
+
+/// The last order posted to a unit by its team commander.
+#[derive(Debug, Clone, Default)]
+pub struct Orders {
+    order: Vec<u8>,
+    sequence: u32,
+    time: f32,
+}
+
+impl Orders {
+    pub fn new() -> Self {
+        Orders::default()
+    }
+
+    pub fn order(&self) -> &[u8] {
+        &self.order
+    }
+
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Post a new order, it replaces the previous one.
+    pub fn post(&mut self, order: &[u8], time: f32) {
+        use battleground_unit_control::modules::orders::ORDERS_SIZE_LIMIT;
+        self.order = order[..order.len().min(ORDERS_SIZE_LIMIT)].to_vec();
+        self.sequence += 1;
+        self.time = time;
+    }
+}
+impl Component for Orders {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::orders::*;
+
+pub struct OrdersModule {
+    entity: EntityId,
+}
+
+impl OrdersModule {
+    pub fn new(entity: EntityId) -> Self {
+        OrdersModule { entity }
+    }
+}
+
+impl UnitModule for OrdersModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(orders) = world.component::<Orders>(self.entity) {
+            registers.insert(
+                REG_ORDERS_SEQUENCE,
+                Register::new_i32("sequence", orders.sequence as i32).read_only(),
+            );
+            registers.insert(
+                REG_ORDERS_TIME,
+                Register::new_f32("time", orders.time).read_only(),
+            );
+            let mut order = Register::new_bytes("order").read_only();
+            *order.value_bytes_mut().unwrap() = orders.order.clone();
+            registers.insert(REG_ORDERS_ORDER, order);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A vehicle that moves along a path of waypoints.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Payload {
+    /// Waypoints the payload moves along, starting at the first.
+    path: Vec<(f32, f32)>,
+    /// Radius within which units influence the payload.
+    radius: f32,
+    /// Speed at which the payload moves along the path.
+    speed: f32,
+    /// Distance travelled along the path.
+    distance: f32,
+    /// Whether the payload moved in the last update.
+    is_moving: bool,
+}
+
+impl Payload {
+    pub fn new(path: &[(f32, f32)], radius: f32, speed: f32) -> Self {
+        Payload {
+            path: path.to_vec(),
+            radius,
+            speed,
+            distance: 0.0,
+            is_moving: false,
+        }
+    }
+
+    pub fn path(&self) -> &[(f32, f32)] {
+        &self.path
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn is_moving(&self) -> bool {
+        self.is_moving
+    }
+
+    /// Total length of the path.
+    pub fn path_length(&self) -> f32 {
+        self.path
+            .windows(2)
+            .map(|w| ((w[1].0 - w[0].0).powi(2) + (w[1].1 - w[0].1).powi(2)).sqrt())
+            .sum()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.distance >= self.path_length()
+    }
+
+    /// Move the payload along the path, clamped to the end of the path.
+    pub fn advance(&mut self, distance: f32) {
+        self.distance = (self.distance + distance).clamp(0.0, self.path_length());
+        self.is_moving = distance > 0.0 && !self.is_finished();
+    }
+
+    /// Stop the payload.
+    pub fn halt(&mut self) {
+        self.is_moving = false;
+    }
+
+    /// Position and yaw of the payload at the current distance along the path.
+    pub fn position(&self) -> (f32, f32, f32) {
+        let mut remaining = self.distance;
+        let mut last = (0.0, 0.0, 0.0);
+        for w in self.path.windows(2) {
+            let (dx, dy) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+            let length = (dx * dx + dy * dy).sqrt();
+            let yaw = dy.atan2(dx);
+            if remaining <= length && length > 0.0 {
+                let ratio = remaining / length;
+                return (w[0].0 + dx * ratio, w[0].1 + dy * ratio, yaw);
+            }
+            remaining -= length;
+            last = (w[1].0, w[1].1, yaw);
+        }
+        if self.path.len() == 1 {
+            return (self.path[0].0, self.path[0].1, 0.0);
+        }
+        last
+    }
+}
+impl Component for Payload {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::*;
+    #[test]
+    fn test_payload_path() {
+        let mut payload = Payload::new(&[(0.0, 0.0), (3.0, 0.0), (3.0, 4.0)], 2.0, 1.0);
+        approx_equal!(payload.path_length(), 7.0, 0.0001);
+        let (x, y, yaw) = payload.position();
+        approx_equal!(x, 0.0, 0.0001);
+        approx_equal!(y, 0.0, 0.0001);
+        approx_equal!(yaw, 0.0, 0.0001);
+
+        payload.advance(4.0);
+        assert!(payload.is_moving());
+        let (x, y, yaw) = payload.position();
+        approx_equal!(x, 3.0, 0.0001);
+        approx_equal!(y, 1.0, 0.0001);
+        approx_equal!(yaw, std::f32::consts::PI / 2.0, 0.0001);
+
+        payload.advance(10.0);
+        assert!(payload.is_finished());
+        assert!(!payload.is_moving());
+        approx_equal!(payload.distance(), 7.0, 0.0001);
+        let (x, y, _yaw) = payload.position();
+        approx_equal!(x, 3.0, 0.0001);
+        approx_equal!(y, 4.0, 0.0001);
+    }
+}
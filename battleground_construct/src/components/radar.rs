@@ -17,6 +17,7 @@ pub struct Radar {
     detection_angle_pitch: f32,
 
     reflections: Vec<Reflection>,
+    detected: Vec<EntityId>,
 }
 
 #[derive(Copy, Debug, Clone)]
@@ -45,6 +46,7 @@ impl Radar {
     pub fn new_with_config(config: RadarConfig) -> Self {
         Self {
             reflections: vec![],
+            detected: vec![],
             range_max: config.range_max,
             detection_angle_yaw: config.detection_angle_yaw,
             detection_angle_pitch: config.detection_angle_pitch,
@@ -52,14 +54,37 @@ impl Radar {
         }
     }
 
+    pub fn range_max(&self) -> f32 {
+        self.range_max
+    }
+
+    pub fn set_range_max(&mut self, range_max: f32) {
+        self.range_max = range_max;
+    }
+
     pub fn reflections(&self) -> Vec<Reflection> {
         self.reflections.clone()
     }
 
-    pub fn update_reflections(&mut self, radar_pose: &Mat4, reflectors: &[(Mat4, f32)]) {
+    /// The entities that produced the current reflections.
+    pub fn detected(&self) -> &[EntityId] {
+        &self.detected
+    }
+
+    pub fn set_detected(&mut self, detected: Vec<EntityId>) {
+        self.detected = detected;
+    }
+
+    /// Update the reflections, returns the indices of the reflectors that produced a reflection.
+    pub fn update_reflections(
+        &mut self,
+        radar_pose: &Mat4,
+        reflectors: &[(Mat4, f32)],
+    ) -> Vec<usize> {
         use crate::util::cgmath::prelude::*;
         self.reflections.clear();
-        for (pos, reflectivity) in reflectors.iter() {
+        let mut reflecting = vec![];
+        for (index, (pos, reflectivity)) in reflectors.iter().enumerate() {
             let pos_v = pos.to_translation();
             let radar_v = radar_pose.to_translation();
             let distance = radar_v.distance2(pos_v).sqrt();
@@ -97,12 +122,15 @@ impl Radar {
                     strength,
                     distance,
                 });
+                reflecting.push(index);
             }
         }
+        reflecting
     }
 }
 impl Component for Radar {}
 
+use crate::components::subsystem::subsystem_integrity;
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::radar::*;
 
@@ -123,40 +151,60 @@ impl UnitModule for RadarModule {
             let reflections = radar.reflections();
             registers.insert(
                 REG_RADAR_RANGE_MAX,
-                Register::new_f32("range_max", radar.range_max),
+                Register::new_f32("range_max", radar.range_max)
+                    .with_unit("m")
+                    .read_only(),
             );
             registers.insert(
                 REG_RADAR_DETECTION_ANGLE_YAW,
-                Register::new_f32("detection_angle_yaw", radar.detection_angle_yaw),
+                Register::new_f32("detection_angle_yaw", radar.detection_angle_yaw)
+                    .with_unit("rad")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_RADAR_DETECTION_ANGLE_PITCH,
-                Register::new_f32("detection_angle_pitch", radar.detection_angle_pitch),
+                Register::new_f32("detection_angle_pitch", radar.detection_angle_pitch)
+                    .with_unit("rad")
+                    .read_only(),
+            );
+
+            registers.insert(
+                REG_RADAR_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_RADAR_REFLECTION_COUNT,
-                Register::new_i32("reflections", reflections.len() as i32),
+                Register::new_i32("reflections", reflections.len() as i32).read_only(),
             );
 
             for (i, reflection) in reflections.iter().enumerate() {
                 let offset = i as u32 * REG_RADAR_REFLECTION_STRIDE + REG_RADAR_REFLECTION_START;
                 registers.insert(
                     offset + REG_RADAR_REFLECTION_OFFSET_YAW,
-                    Register::new_f32("yaw", reflection.yaw),
+                    Register::new_f32("yaw", reflection.yaw)
+                        .with_unit("rad")
+                        .read_only(),
                 );
                 registers.insert(
                     offset + REG_RADAR_REFLECTION_OFFSET_PITCH,
-                    Register::new_f32("pitch", reflection.pitch),
+                    Register::new_f32("pitch", reflection.pitch)
+                        .with_unit("rad")
+                        .read_only(),
                 );
                 registers.insert(
                     offset + REG_RADAR_REFLECTION_OFFSET_DISTANCE,
-                    Register::new_f32("distance", reflection.distance),
+                    Register::new_f32("distance", reflection.distance)
+                        .with_unit("m")
+                        .read_only(),
                 );
                 registers.insert(
                     offset + REG_RADAR_REFLECTION_OFFSET_STRENGTH,
-                    Register::new_f32("strength", reflection.strength),
+                    Register::new_f32("strength", reflection.strength).read_only(),
                 );
             }
         }
@@ -1,8 +1,11 @@
+use crate::components::team::TeamId;
 use engine::prelude::*;
 
 #[derive(Debug, Clone)]
 pub struct RadarReflector {
     pub reflectivity: f32,
+    /// If set, only radars of this team can see the reflector.
+    pub visible_to: Option<TeamId>,
 }
 impl Default for RadarReflector {
     fn default() -> Self {
@@ -12,10 +15,20 @@ impl Default for RadarReflector {
 
 impl RadarReflector {
     pub fn new() -> Self {
-        Self { reflectivity: 1.0 }
+        Self {
+            reflectivity: 1.0,
+            visible_to: None,
+        }
     }
     pub fn reflectivity(&self) -> f32 {
         self.reflectivity
     }
+    pub fn visible_to(&self) -> Option<TeamId> {
+        self.visible_to
+    }
+    /// Whether a radar of this team can see the reflector.
+    pub fn is_visible_to(&self, team: Option<TeamId>) -> bool {
+        self.visible_to.is_none() || self.visible_to == team
+    }
 }
 impl Component for RadarReflector {}
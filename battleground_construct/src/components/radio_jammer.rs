@@ -0,0 +1,135 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+fn default_jammer_power() -> f32 {
+    1.0
+}
+fn default_jammer_range() -> f32 {
+    20.0
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct RadioJammerConfig {
+    /// Power added to the noise floor, falls off with the square of the distance.
+    #[serde(default = "default_jammer_power")]
+    pub power: f32,
+    /// Range beyond which receivers are not affected.
+    #[serde(default = "default_jammer_range")]
+    pub range: f32,
+    /// Whether the jammer is active from the start.
+    #[serde(default)]
+    pub active: bool,
+}
+
+impl Default for RadioJammerConfig {
+    fn default() -> Self {
+        RadioJammerConfig {
+            power: default_jammer_power(),
+            range: default_jammer_range(),
+            active: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RadioJammer {
+    config: RadioJammerConfig,
+    active: bool,
+}
+
+impl RadioJammer {
+    pub fn new_with_config(config: RadioJammerConfig) -> Self {
+        RadioJammer {
+            config,
+            active: config.active,
+        }
+    }
+
+    pub fn config(&self) -> RadioJammerConfig {
+        self.config
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    /// The noise added at the provided distance from the jammer.
+    pub fn noise_at(&self, distance: f32) -> f32 {
+        if !self.active || distance >= self.config.range {
+            return 0.0;
+        }
+        self.config.power / distance.max(1.0).powi(2)
+    }
+}
+impl Component for RadioJammer {}
+
+use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
+use battleground_unit_control::modules::radio_jammer::*;
+pub struct RadioJammerModule {
+    entity: EntityId,
+}
+
+impl RadioJammerModule {
+    pub fn new(entity: EntityId) -> Self {
+        RadioJammerModule { entity }
+    }
+}
+
+impl UnitModule for RadioJammerModule {
+    fn get_registers(&self, world: &World, registers: &mut RegisterMap) {
+        registers.clear();
+        if let Some(jammer) = world.component::<RadioJammer>(self.entity) {
+            registers.insert(
+                REG_RADIO_JAMMER_POWER,
+                Register::new_f32("power", jammer.config.power).read_only(),
+            );
+            registers.insert(
+                REG_RADIO_JAMMER_RANGE,
+                Register::new_f32("range", jammer.config.range)
+                    .with_unit("m")
+                    .read_only(),
+            );
+            registers.insert(
+                REG_RADIO_JAMMER_ACTIVE,
+                Register::new_i32("active", jammer.active as i32)
+                    .with_range(0.0, 1.0)
+                    .with_description("set to enable the jammer"),
+            );
+        }
+    }
+
+    fn set_component(&self, world: &mut World, registers: &RegisterMap) {
+        if let Some(mut jammer) = world.component_mut::<RadioJammer>(self.entity) {
+            let active = registers
+                .get(&REG_RADIO_JAMMER_ACTIVE)
+                .expect("register doesnt exist")
+                .value_i32()
+                .expect("wrong value type");
+            jammer.set_active(active != 0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radio_jammer_noise() {
+        let mut jammer = RadioJammer::new_with_config(RadioJammerConfig {
+            power: 4.0,
+            range: 10.0,
+            active: false,
+        });
+        assert_eq!(jammer.noise_at(2.0), 0.0);
+        jammer.set_active(true);
+        assert_eq!(jammer.noise_at(2.0), 1.0);
+        // Close by the power is capped, beyond the range there is no effect.
+        assert_eq!(jammer.noise_at(0.0), 4.0);
+        assert_eq!(jammer.noise_at(10.0), 0.0);
+    }
+}
@@ -0,0 +1,121 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+fn default_snr_threshold() -> f32 {
+    1.0
+}
+
+/// Determines which radio messages are delivered. The defaults deliver every message within the
+/// transmitter's range.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct RadioPropagationConfig {
+    /// Seed for packet loss and bearing noise, such that matches can be reproduced.
+    #[serde(default)]
+    pub seed: u64,
+    /// Noise present at every receiver, in the same units as the received strength.
+    #[serde(default)]
+    pub noise_floor: f32,
+    /// Ratio of signal over noise at which half of the messages are delivered.
+    #[serde(default = "default_snr_threshold")]
+    pub snr_threshold: f32,
+    /// Fraction of the strength of other transmissions on the same channel in the same step that
+    /// is counted as noise.
+    #[serde(default)]
+    pub interference: f32,
+    /// Bytes each channel can carry per step, messages beyond this are lost. Unlimited if not
+    /// specified.
+    #[serde(default)]
+    pub channel_bandwidth: Option<usize>,
+}
+
+impl Default for RadioPropagationConfig {
+    fn default() -> Self {
+        RadioPropagationConfig {
+            seed: 0,
+            noise_floor: 0.0,
+            snr_threshold: default_snr_threshold(),
+            interference: 0.0,
+            channel_bandwidth: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RadioPropagation {
+    config: RadioPropagationConfig,
+}
+
+impl RadioPropagation {
+    pub fn new(config: RadioPropagationConfig) -> Self {
+        RadioPropagation { config }
+    }
+
+    pub fn config(&self) -> RadioPropagationConfig {
+        self.config
+    }
+
+    /// Probability that a message of this strength is delivered, given the noise at the receiver.
+    pub fn delivery_probability(&self, strength: f32, noise: f32) -> f32 {
+        if noise <= 0.0 {
+            return 1.0;
+        }
+        let snr = strength / noise;
+        let threshold = self.config.snr_threshold.max(f32::EPSILON);
+        snr.powi(2) / (snr.powi(2) + threshold.powi(2))
+    }
+
+    /// Deterministic draw whether a message with this delivery probability arrives, different for
+    /// each combination of receiver, time and channel.
+    pub fn is_delivered(
+        &self,
+        probability: f32,
+        receiver: EntityId,
+        time: f32,
+        channel: u64,
+    ) -> bool {
+        if probability >= 1.0 {
+            return true;
+        }
+        let draw = (self.noise(receiver, time, channel) + 1.0) / 2.0;
+        draw < probability
+    }
+
+    /// Deterministic noise in [-1.0, 1.0], different for each combination of entity, time and
+    /// channel.
+    pub fn noise(&self, entity: EntityId, time: f32, channel: u64) -> f32 {
+        crate::util::noise::noise(self.config.seed, usize::from(entity) as u64, time, channel)
+    }
+}
+impl Component for RadioPropagation {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radio_propagation_delivery() {
+        let mut world = World::new();
+        let receiver = world.add_entity();
+
+        // Without noise everything is delivered.
+        let propagation = RadioPropagation::new(Default::default());
+        assert_eq!(propagation.delivery_probability(0.01, 0.0), 1.0);
+        assert!(propagation.is_delivered(1.0, receiver, 0.0, 0));
+
+        let propagation = RadioPropagation::new(RadioPropagationConfig {
+            seed: 5,
+            snr_threshold: 2.0,
+            ..Default::default()
+        });
+        assert_eq!(propagation.delivery_probability(2.0, 1.0), 0.5);
+        assert!(propagation.delivery_probability(20.0, 1.0) > 0.99);
+        assert!(propagation.delivery_probability(0.2, 1.0) < 0.01);
+
+        // The fraction delivered approaches the probability.
+        let delivered = (0..1000)
+            .filter(|i| propagation.is_delivered(0.25, receiver, 0.0, *i))
+            .count();
+        assert!((200..300).contains(&delivered));
+        assert!(!propagation.is_delivered(0.0, receiver, 0.0, 0));
+    }
+}
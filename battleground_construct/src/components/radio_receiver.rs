@@ -3,6 +3,7 @@ use engine::prelude::*;
 #[derive(Debug, Clone)]
 struct ReceivedMessage {
     strength: f32,
+    bearing: Option<f32>,
     message: Vec<u8>,
 }
 
@@ -26,6 +27,12 @@ pub struct RadioReceiverConfig {
 
     /// The maximum number of incoming transmissions.
     pub message_count_limit: usize,
+
+    /// Whether the receiver reports the bearing towards the transmitter of each message.
+    pub direction_finding: bool,
+
+    /// Noise on the bearing, uniformly distributed within plus and minus this value, radians.
+    pub bearing_noise: f32,
 }
 
 impl Default for RadioReceiverConfig {
@@ -34,6 +41,8 @@ impl Default for RadioReceiverConfig {
             channel_min: 0,
             channel_max: 4,
             message_count_limit: 64,
+            direction_finding: false,
+            bearing_noise: 0.0,
         }
     }
 }
@@ -48,10 +57,15 @@ impl RadioReceiver {
         }
     }
 
-    pub fn add_message(&mut self, strength: f32, message: &[u8]) {
+    pub fn config(&self) -> RadioReceiverConfig {
+        self.config
+    }
+
+    pub fn add_message(&mut self, strength: f32, bearing: Option<f32>, message: &[u8]) {
         if self.messages.len() < self.config.message_count_limit {
             self.messages.push(ReceivedMessage {
                 strength,
+                bearing,
                 message: message.to_vec(),
             })
         } else {
@@ -59,6 +73,11 @@ impl RadioReceiver {
         }
     }
 
+    /// The number of messages currently held.
+    pub fn message_count(&self) -> usize {
+        self.messages.len()
+    }
+
     pub fn clear(&mut self) {
         self.messages.clear()
     }
@@ -98,11 +117,13 @@ impl UnitModule for RadioReceiverModule {
         if let Some(radio_receiver) = world.component::<RadioReceiver>(self.entity) {
             registers.insert(
                 REG_RADIO_RX_CHANNEL_MIN,
-                Register::new_i32("channel_min", radio_receiver.config.channel_min as i32),
+                Register::new_i32("channel_min", radio_receiver.config.channel_min as i32)
+                    .read_only(),
             );
             registers.insert(
                 REG_RADIO_RX_CHANNEL_MAX,
-                Register::new_i32("channel_max", radio_receiver.config.channel_max as i32),
+                Register::new_i32("channel_max", radio_receiver.config.channel_max as i32)
+                    .read_only(),
             );
 
             registers.insert(
@@ -110,12 +131,23 @@ impl UnitModule for RadioReceiverModule {
                 Register::new_i32(
                     "message_count_limit",
                     radio_receiver.config.message_count_limit as i32,
-                ),
+                )
+                .read_only(),
+            );
+
+            registers.insert(
+                REG_RADIO_RX_DIRECTION_FINDING,
+                Register::new_i32(
+                    "direction_finding",
+                    radio_receiver.config.direction_finding as i32,
+                )
+                .read_only(),
             );
 
             registers.insert(
                 REG_RADIO_RX_CHANNEL_SELECT,
-                Register::new_i32("channel_select", radio_receiver.channel() as i32),
+                Register::new_i32("channel_select", radio_receiver.channel() as i32)
+                    .with_description("channel to receive on"),
             );
 
             registers.insert(
@@ -134,7 +166,7 @@ impl UnitModule for RadioReceiverModule {
                     REG_RADIO_RX_MSG_START
                         + (REG_RADIO_RX_MSG_STRIDE * i as u32)
                         + REG_RADIO_RX_MSG_OFFSET_STRENGTH,
-                    Register::new_f32("message_strength", msg.strength),
+                    Register::new_f32("message_strength", msg.strength).read_only(),
                 );
                 let v = registers
                     .entry(
@@ -142,8 +174,14 @@ impl UnitModule for RadioReceiverModule {
                             + (REG_RADIO_RX_MSG_STRIDE * i as u32)
                             + REG_RADIO_RX_MSG_OFFSET_DATA,
                     )
-                    .or_insert_with(|| Register::new_bytes("message_data"));
+                    .or_insert_with(|| Register::new_bytes("message_data").read_only());
                 *v.value_bytes_mut().unwrap() = msg.message.clone();
+                if let Some(bearing) = msg.bearing {
+                    registers.insert(
+                        REG_RADIO_RX_BEARING_START + i as u32,
+                        Register::new_f32("message_bearing", bearing).read_only(),
+                    );
+                }
             }
         }
     }
@@ -177,3 +215,47 @@ impl UnitModule for RadioReceiverModule {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radio_receiver_direction_finding() {
+        let mut world = World::new();
+        let receiver = world.add_entity();
+        world.add_component(
+            receiver,
+            RadioReceiver::new_with_config(RadioReceiverConfig {
+                direction_finding: true,
+                ..Default::default()
+            }),
+        );
+        world
+            .component_mut::<RadioReceiver>(receiver)
+            .unwrap()
+            .add_message(0.5, Some(1.0), &[1, 2]);
+
+        let module = RadioReceiverModule::new(receiver);
+        let mut registers = RegisterMap::new();
+        module.get_registers(&world, &mut registers);
+        assert_eq!(
+            registers
+                .get(&REG_RADIO_RX_DIRECTION_FINDING)
+                .unwrap()
+                .value_i32(),
+            Some(1)
+        );
+        let bearing = REG_RADIO_RX_BEARING_START;
+        assert_eq!(registers.get(&bearing).unwrap().value_f32(), Some(1.0));
+
+        // Without direction finding, no bearing is provided.
+        world.add_component(receiver, RadioReceiver::new_with_config(Default::default()));
+        world
+            .component_mut::<RadioReceiver>(receiver)
+            .unwrap()
+            .add_message(0.5, None, &[1, 2]);
+        module.get_registers(&world, &mut registers);
+        assert!(!registers.contains_key(&bearing));
+    }
+}
@@ -122,42 +122,51 @@ impl UnitModule for RadioTransmitterModule {
                 Register::new_f32(
                     "transmit_range_max",
                     radio_transmitter.config.transmit_range_max,
-                ),
+                )
+                .with_unit("m")
+                .read_only(),
             );
             registers.insert(
                 REG_RADIO_TX_INTERVAL,
                 Register::new_f32(
                     "transmit_interval",
                     radio_transmitter.config.transmit_interval,
-                ),
+                )
+                .with_unit("s")
+                .read_only(),
             );
             registers.insert(
                 REG_RADIO_TX_MSG_SIZE_LIMIT,
                 Register::new_i32(
                     "message_size_limit",
                     radio_transmitter.config.message_size_limit as i32,
-                ),
+                )
+                .read_only(),
             );
             registers.insert(
                 REG_RADIO_TX_MSG_COUNT_LIMIT,
                 Register::new_i32(
                     "message_count_limit",
                     radio_transmitter.config.message_count_limit as i32,
-                ),
+                )
+                .read_only(),
             );
             registers.insert(
                 REG_RADIO_TX_CHANNEL_MIN,
-                Register::new_i32("channel_min", radio_transmitter.config.channel_min as i32),
+                Register::new_i32("channel_min", radio_transmitter.config.channel_min as i32)
+                    .read_only(),
             );
             registers.insert(
                 REG_RADIO_TX_CHANNEL_MAX,
-                Register::new_i32("channel_max", radio_transmitter.config.channel_max as i32),
+                Register::new_i32("channel_max", radio_transmitter.config.channel_max as i32)
+                    .read_only(),
             );
 
             // Writeable registers.
             registers.insert(
                 REG_RADIO_TX_CHANNEL_SELECT,
-                Register::new_i32("channel_select", radio_transmitter.channel() as i32),
+                Register::new_i32("channel_select", radio_transmitter.channel() as i32)
+                    .with_description("channel to transmit on"),
             );
 
             let messages = radio_transmitter.messages();
@@ -365,6 +365,8 @@ impl Record {
 
         // Projectile visualisation.
         self.register_type::<display::tank_bullet::TankBullet>("tank_bullet");
+        self.register_type::<display::missile::Missile>("missile");
+        self.register_type::<display::mine_model::MineModel>("mine_model");
 
         // Visualisation emitters that are not trivially recreated like meshes.
         self.register_type::<display::particle_emitter::ParticleEmitter>("particle_emitter");
@@ -383,6 +385,7 @@ impl Record {
         self.register_type::<crate::units::capturable_flag::UnitCapturableFlag>(
             "unit_capturable_flag",
         );
+        self.register_type::<crate::units::payload::UnitPayload>("unit_payload");
 
         // Team information, to color vehicles.
         self.register_type::<components::team::Team>("team");
@@ -391,14 +394,28 @@ impl Record {
         // Capturables
         self.register_type::<components::capturable::Capturable>("capturable");
         self.register_type::<components::capture_point::CapturePoint>("capture_point");
+        self.register_type::<components::carriable_flag::CarriableFlag>("carriable_flag");
+        self.register_type::<components::payload::Payload>("payload");
 
         // Match info.
         self.register_type::<components::match_finished::MatchFinished>("match_finished");
         self.register_type::<components::match_king_of_the_hill::MatchKingOfTheHill>(
             "match_king_of_the_hill",
         );
+        self.register_type::<components::match_attack_defend::MatchAttackDefend>(
+            "match_attack_defend",
+        );
+        self.register_type::<components::match_capture_the_flag::MatchCaptureTheFlag>(
+            "match_capture_the_flag",
+        );
+        self.register_type::<components::match_payload::MatchPayload>("match_payload");
+        self.register_type::<components::match_scoring::MatchScoring>("match_scoring");
+        self.register_type::<components::match_rules::MatchRules>("match_rules");
         self.register_type::<components::match_time_limit::MatchTimeLimit>("match_time_limit");
 
+        // Team visibility, allows viewing the match from a team's perspective.
+        self.register_type::<components::visibility::Visibility>("visibility");
+
         self.current_state.ensure_components(&self.component_map);
     }
 
@@ -0,0 +1,280 @@
+use crate::components::team::TeamId;
+use crate::components::unit::UnitType;
+use crate::units::common::RadioConfig;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Marker component added to the unit entity whenever a unit is destroyed, for the reinforcement
+/// logic to queue a replacement.
+#[derive(Debug, Clone, Copy)]
+pub struct ReinforcementJustDestroyed;
+impl Component for ReinforcementJustDestroyed {}
+
+/// Function to create a fresh controller for a reinforcement. This must be an Rc, as we need to be
+/// able to copy it out of the component to allow a mutable world while spawning.
+pub type ReinforcementController = std::rc::Rc<
+    dyn Fn() -> Result<Box<dyn battleground_unit_control::UnitControl>, Box<dyn std::error::Error>>,
+>;
+
+/// A location in which reinforcements appear.
+#[derive(Serialize, Deserialize, Debug, Copy, Default, Clone)]
+pub struct SpawnZone {
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub yaw: f32,
+    /// Units are placed on a circle of this radius around the zone's center, to avoid units that
+    /// spawn in the same wave being placed on top of each other.
+    #[serde(default)]
+    pub radius: f32,
+}
+
+/// Reinforcement rules for a single team.
+pub struct TeamReinforcements {
+    /// Number of reinforcements remaining, unlimited if None.
+    pub tickets: Option<usize>,
+    /// Zones in which reinforcements are spawned, these are used in round robin order.
+    pub spawn_zones: Vec<SpawnZone>,
+    /// Radio configuration for the reinforcements.
+    pub radio_config: Option<RadioConfig>,
+    /// Creates the controller for each reinforcement.
+    pub controller: ReinforcementController,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingReinforcement {
+    team: TeamId,
+    unit_type: UnitType,
+    available: f32,
+}
+
+/// A reinforcement that should be spawned now.
+#[derive(Debug, Clone, Copy)]
+pub struct Reinforcement {
+    pub team: TeamId,
+    pub unit_type: UnitType,
+    pub x: f32,
+    pub y: f32,
+    pub yaw: f32,
+}
+
+pub struct Reinforcements {
+    respawn_delay: f32,
+    wave_interval: Option<f32>,
+    next_wave: f32,
+    teams: std::collections::HashMap<TeamId, TeamReinforcements>,
+    pending: Vec<PendingReinforcement>,
+    /// Number of units spawned per team, used to cycle through the team's zones and positions.
+    spawn_count: std::collections::HashMap<TeamId, usize>,
+}
+
+impl Reinforcements {
+    pub fn new(respawn_delay: f32, wave_interval: Option<f32>) -> Self {
+        Reinforcements {
+            respawn_delay,
+            wave_interval,
+            next_wave: wave_interval.unwrap_or(0.0),
+            teams: Default::default(),
+            pending: vec![],
+            spawn_count: Default::default(),
+        }
+    }
+
+    pub fn add_team(&mut self, team: TeamId, reinforcements: TeamReinforcements) {
+        self.teams.insert(team, reinforcements);
+    }
+
+    /// The number of reinforcements the team has left, None if unlimited or the team does not get
+    /// reinforcements.
+    pub fn tickets(&self, team: TeamId) -> Option<usize> {
+        self.teams.get(&team).and_then(|t| t.tickets)
+    }
+
+    /// The number of reinforcements waiting to be spawned for this team.
+    pub fn pending(&self, team: TeamId) -> usize {
+        self.pending.iter().filter(|p| p.team == team).count()
+    }
+
+    pub fn controller(&self, team: TeamId) -> Option<ReinforcementController> {
+        self.teams.get(&team).map(|t| t.controller.clone())
+    }
+
+    pub fn radio_config(&self, team: TeamId) -> Option<RadioConfig> {
+        self.teams.get(&team).and_then(|t| t.radio_config)
+    }
+
+    /// Register that a unit of a team was destroyed, this queues a replacement if the team has
+    /// tickets remaining. Returns whether a replacement was queued.
+    pub fn unit_destroyed(&mut self, team: TeamId, unit_type: UnitType, time: f32) -> bool {
+        let team_reinforcements = if let Some(v) = self.teams.get_mut(&team) {
+            v
+        } else {
+            return false;
+        };
+        if team_reinforcements.spawn_zones.is_empty() {
+            return false;
+        }
+        if let Some(ref mut tickets) = team_reinforcements.tickets {
+            if *tickets == 0 {
+                return false;
+            }
+            *tickets -= 1;
+        }
+        self.pending.push(PendingReinforcement {
+            team,
+            unit_type,
+            available: time + self.respawn_delay,
+        });
+        true
+    }
+
+    /// Retrieve the reinforcements that are to be spawned at this time. If a wave interval is set
+    /// available reinforcements are held back until the next wave.
+    pub fn take_ready(&mut self, time: f32) -> Vec<Reinforcement> {
+        let release_time = if let Some(interval) = self.wave_interval {
+            if time < self.next_wave {
+                return vec![];
+            }
+            let release_time = self.next_wave;
+            while self.next_wave <= time {
+                self.next_wave += interval;
+            }
+            release_time
+        } else {
+            time
+        };
+
+        let (ready, pending): (Vec<_>, Vec<_>) = self
+            .pending
+            .iter()
+            .partition(|p| p.available <= release_time);
+        self.pending = pending;
+
+        ready
+            .iter()
+            .map(|p| {
+                let (x, y, yaw) = self.spawn_location(p.team);
+                Reinforcement {
+                    team: p.team,
+                    unit_type: p.unit_type,
+                    x,
+                    y,
+                    yaw,
+                }
+            })
+            .collect()
+    }
+
+    /// Determine the next spawn location for a team.
+    fn spawn_location(&mut self, team: TeamId) -> (f32, f32, f32) {
+        let zones = &self
+            .teams
+            .get(&team)
+            .expect("only teams with zones")
+            .spawn_zones;
+        let spawn_count = self.spawn_count.entry(team).or_default();
+        let zone = zones[*spawn_count % zones.len()];
+        // Spread out over eight positions on the zone's radius, this is deterministic.
+        let angle = ((*spawn_count / zones.len()) % 8) as f32 * std::f32::consts::PI / 4.0;
+        *spawn_count += 1;
+        (
+            zone.x + zone.radius * angle.cos(),
+            zone.y + zone.radius * angle.sin(),
+            zone.yaw,
+        )
+    }
+}
+impl Component for Reinforcements {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+
+    fn team_reinforcements(tickets: Option<usize>) -> TeamReinforcements {
+        TeamReinforcements {
+            tickets,
+            spawn_zones: vec![
+                SpawnZone {
+                    x: 1.0,
+                    y: 2.0,
+                    yaw: 0.5,
+                    radius: 0.0,
+                },
+                SpawnZone {
+                    x: -1.0,
+                    y: -2.0,
+                    yaw: 0.5,
+                    radius: 0.0,
+                },
+            ],
+            radio_config: None,
+            controller: std::rc::Rc::new(|| Ok(Box::new(unit_control_builtin::idle::Idle {}))),
+        }
+    }
+
+    #[test]
+    fn test_reinforcements_tickets() {
+        let team_a = make_team_id(1);
+        let team_b = make_team_id(2);
+        let mut reinforcements = Reinforcements::new(5.0, None);
+        reinforcements.add_team(team_a, team_reinforcements(Some(1)));
+
+        // Team b has no reinforcements.
+        assert!(!reinforcements.unit_destroyed(team_b, UnitType::Tank, 0.0));
+
+        assert!(reinforcements.unit_destroyed(team_a, UnitType::Tank, 0.0));
+        assert_eq!(reinforcements.tickets(team_a), Some(0));
+        assert!(!reinforcements.unit_destroyed(team_a, UnitType::Tank, 1.0));
+        assert_eq!(reinforcements.pending(team_a), 1);
+
+        assert!(reinforcements.take_ready(4.9).is_empty());
+        let ready = reinforcements.take_ready(5.0);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].team, team_a);
+        assert_eq!((ready[0].x, ready[0].y), (1.0, 2.0));
+        assert_eq!(reinforcements.pending(team_a), 0);
+    }
+
+    #[test]
+    fn test_reinforcements_waves() {
+        let team_a = make_team_id(1);
+        let mut reinforcements = Reinforcements::new(1.0, Some(10.0));
+        reinforcements.add_team(team_a, team_reinforcements(None));
+
+        assert!(reinforcements.unit_destroyed(team_a, UnitType::Tank, 0.0));
+        assert!(reinforcements.unit_destroyed(team_a, UnitType::Artillery, 9.5));
+        // Not yet at the first wave.
+        assert!(reinforcements.take_ready(5.0).is_empty());
+        // First wave, only the first unit is available.
+        let ready = reinforcements.take_ready(10.0);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].unit_type, UnitType::Tank);
+        assert!(reinforcements.take_ready(15.0).is_empty());
+        // Second wave, uses the second spawn zone.
+        let ready = reinforcements.take_ready(20.0);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].unit_type, UnitType::Artillery);
+        assert_eq!((ready[0].x, ready[0].y), (-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_reinforcements_zones_per_team() {
+        let team_a = make_team_id(1);
+        let team_b = make_team_id(2);
+        let mut reinforcements = Reinforcements::new(0.0, None);
+        reinforcements.add_team(team_a, team_reinforcements(None));
+        reinforcements.add_team(team_b, team_reinforcements(None));
+
+        // Each team starts at its first zone, regardless of the other team's spawns.
+        assert!(reinforcements.unit_destroyed(team_a, UnitType::Tank, 0.0));
+        assert!(reinforcements.unit_destroyed(team_a, UnitType::Tank, 0.0));
+        assert!(reinforcements.unit_destroyed(team_b, UnitType::Tank, 0.0));
+        let ready = reinforcements.take_ready(0.0);
+        let positions = ready.iter().map(|r| (r.team, r.x)).collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![(team_a, 1.0), (team_a, -1.0), (team_b, 1.0)]
+        );
+    }
+}
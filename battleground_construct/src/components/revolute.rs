@@ -125,6 +125,7 @@ impl Revolute {
 }
 impl Component for Revolute {}
 
+use crate::components::subsystem::subsystem_integrity;
 use crate::components::unit_interface::{Register, RegisterMap, UnitModule};
 use battleground_unit_control::modules::revolute::*;
 pub struct RevoluteModule {
@@ -143,36 +144,58 @@ impl UnitModule for RevoluteModule {
         if let Some(revolute) = world.component::<Revolute>(self.entity) {
             registers.insert(
                 REG_REVOLUTE_POSITION,
-                Register::new_f32("position", revolute.position()),
+                Register::new_f32("position", revolute.position())
+                    .with_unit("rad")
+                    .read_only(),
             );
             registers.insert(
                 REG_REVOLUTE_VELOCITY,
-                Register::new_f32("velocity", revolute.velocity()),
+                Register::new_f32("velocity", revolute.velocity())
+                    .with_unit("rad/s")
+                    .read_only(),
             );
 
             let (vel_min, vel_max) = revolute.velocity_bounds();
             registers.insert(
                 REG_REVOLUTE_VELOCITY_MIN,
-                Register::new_f32("velocity_min", vel_min),
+                Register::new_f32("velocity_min", vel_min)
+                    .with_unit("rad/s")
+                    .read_only(),
             );
             registers.insert(
                 REG_REVOLUTE_VELOCITY_MAX,
-                Register::new_f32("velocity_max", vel_max),
+                Register::new_f32("velocity_max", vel_max)
+                    .with_unit("rad/s")
+                    .read_only(),
             );
 
             registers.insert(
                 REG_REVOLUTE_VELOCITY_CMD,
-                Register::new_f32("velocity_cmd", revolute.velocity_cmd()),
+                Register::new_f32("velocity_cmd", revolute.velocity_cmd())
+                    .with_unit("rad/s")
+                    .with_description("commanded joint velocity"),
             );
 
             let accel_bounds = revolute.acceleration_bounds().unwrap_or((0.0, 0.0));
             registers.insert(
                 REG_REVOLUTE_ACCELERATION_LOWER,
-                Register::new_f32("acceleration_lower", accel_bounds.0),
+                Register::new_f32("acceleration_lower", accel_bounds.0)
+                    .with_unit("rad/s^2")
+                    .read_only(),
             );
             registers.insert(
                 REG_REVOLUTE_ACCELERATION_UPPER,
-                Register::new_f32("acceleration_upper", accel_bounds.1),
+                Register::new_f32("acceleration_upper", accel_bounds.1)
+                    .with_unit("rad/s^2")
+                    .read_only(),
+            );
+
+            registers.insert(
+                REG_REVOLUTE_INTEGRITY,
+                Register::new_f32("integrity", subsystem_integrity(world, self.entity))
+                    .with_range(0.0, 1.0)
+                    .with_description("remaining integrity of the subsystem")
+                    .read_only(),
             );
         }
     }
@@ -0,0 +1,152 @@
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/*
+    Subsystems allow hits on specific parts of a unit to degrade that part's capabilities.
+
+    The Subsystem component lives on the same entity as the component it degrades, such that the
+    unit modules can easily report the integrity. The SubsystemLink is placed on the entities that
+    can be hit (those with hit boxes or hit collections), it specifies which subsystems receive
+    damage when that entity is hit, and by how much the damage is scaled.
+*/
+
+/// The kind of subsystem, this determines which component gets degraded.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsystemKind {
+    /// Reduces the wheel velocity bounds of the differential drive base.
+    Tracks,
+    /// Reduces the velocity bounds of the revolute joint.
+    Revolute,
+    /// Reduces the maximum range of the radar.
+    Radar,
+    /// Increases the reload time of the cannon or gun battery.
+    Weapon,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct SubsystemConfig {
+    /// The kind of subsystem.
+    pub kind: SubsystemKind,
+    /// Effectiveness of the subsystem when its integrity is zero, a fully damaged subsystem still
+    /// works at this fraction of its original capability.
+    pub effectiveness_min: f32,
+}
+
+impl SubsystemConfig {
+    pub fn new(kind: SubsystemKind) -> Self {
+        SubsystemConfig {
+            kind,
+            effectiveness_min: 0.25,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct Subsystem {
+    config: SubsystemConfig,
+    integrity: f32,
+    /// The effectiveness that's currently applied to the degraded component.
+    applied: f32,
+}
+
+impl Subsystem {
+    pub fn new(kind: SubsystemKind) -> Self {
+        Self::from_config(SubsystemConfig::new(kind))
+    }
+
+    pub fn from_config(config: SubsystemConfig) -> Self {
+        Subsystem {
+            config,
+            integrity: 1.0,
+            applied: 1.0,
+        }
+    }
+
+    pub fn kind(&self) -> SubsystemKind {
+        self.config.kind
+    }
+
+    /// The integrity of this subsystem, 1.0 is undamaged, 0.0 is fully damaged.
+    pub fn integrity(&self) -> f32 {
+        self.integrity
+    }
+
+    pub fn subtract(&mut self, value: f32) -> f32 {
+        self.integrity = (self.integrity - value).clamp(0.0, 1.0);
+        self.integrity
+    }
+
+    /// The fraction of the original capability that remains, given the current integrity.
+    pub fn effectiveness(&self) -> f32 {
+        let min = self.config.effectiveness_min;
+        min + (1.0 - min) * self.integrity
+    }
+
+    /// Returns the ratio by which the degraded component's capability must be scaled to reach the
+    /// current effectiveness, and marks that effectiveness as applied. Returns None if nothing
+    /// changed since the last call.
+    pub fn take_effectiveness_ratio(&mut self) -> Option<f32> {
+        let effectiveness = self.effectiveness();
+        if effectiveness == self.applied {
+            return None;
+        }
+        let ratio = effectiveness / self.applied;
+        self.applied = effectiveness;
+        Some(ratio)
+    }
+}
+impl Component for Subsystem {}
+
+/// Retrieve the integrity of the subsystem on this entity, 1.0 if there is no subsystem.
+pub fn subsystem_integrity(world: &World, entity: EntityId) -> f32 {
+    world
+        .component::<Subsystem>(entity)
+        .map(|v| v.integrity())
+        .unwrap_or(1.0)
+}
+
+/// Specifies which subsystems are damaged when the entity holding this component is hit.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SubsystemLink {
+    /// The entities holding the Subsystem component and the ratio to scale the damage by.
+    subsystems: Vec<(EntityId, f32)>,
+}
+
+impl SubsystemLink {
+    pub fn new() -> Self {
+        SubsystemLink::default()
+    }
+
+    pub fn with_subsystem(mut self, subsystem_entity: EntityId, damage_ratio: f32) -> Self {
+        self.subsystems.push((subsystem_entity, damage_ratio));
+        self
+    }
+
+    pub fn subsystems(&self) -> &[(EntityId, f32)] {
+        &self.subsystems[..]
+    }
+}
+impl Component for SubsystemLink {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::test_util::approx_equal;
+    #[test]
+    fn test_subsystem_effectiveness() {
+        let mut subsystem = Subsystem::new(SubsystemKind::Tracks);
+        assert_eq!(subsystem.take_effectiveness_ratio(), None);
+        approx_equal!(subsystem.effectiveness(), 1.0, 0.0001);
+
+        subsystem.subtract(0.5);
+        approx_equal!(subsystem.effectiveness(), 0.625, 0.0001);
+        approx_equal!(subsystem.take_effectiveness_ratio().unwrap(), 0.625, 0.0001);
+        assert_eq!(subsystem.take_effectiveness_ratio(), None);
+
+        // Integrity can't go below zero, effectiveness bottoms out at the minimum.
+        subsystem.subtract(5.0);
+        approx_equal!(subsystem.integrity(), 0.0, 0.0001);
+        approx_equal!(subsystem.effectiveness(), 0.25, 0.0001);
+        approx_equal!(subsystem.take_effectiveness_ratio().unwrap(), 0.4, 0.0001);
+    }
+}
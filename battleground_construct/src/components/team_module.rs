@@ -24,7 +24,7 @@ impl UnitModule for TeamModule {
             .unwrap_or(battleground_unit_control::modules::team::TEAM_NO_TEAM);
         registers.insert(
             battleground_unit_control::modules::team::REG_TEAM_TEAMID,
-            Register::new_i32("team", value),
+            Register::new_i32("team", value).read_only(),
         );
     }
 }
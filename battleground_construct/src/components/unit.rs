@@ -83,7 +83,7 @@ impl UnitModule for UnitModuleComponent {
             .unwrap_or(battleground_unit_control::modules::unit::UNIT_NO_UNIT_ID);
         registers.insert(
             battleground_unit_control::modules::unit::REG_UNIT_UNIT_ID,
-            Register::new_i32("unit_id", value),
+            Register::new_i32("unit_id", value).read_only(),
         );
         let value = world
             .component::<Unit>(self.unit_entity)
@@ -91,7 +91,7 @@ impl UnitModule for UnitModuleComponent {
             .unwrap_or(battleground_unit_control::modules::unit::UNIT_NO_UNIT_TYPE);
         registers.insert(
             battleground_unit_control::modules::unit::REG_UNIT_UNIT_TYPE,
-            Register::new_i32("unit_type", value),
+            Register::new_i32("unit_type", value).read_only(),
         );
     }
 }
@@ -0,0 +1,133 @@
+use crate::components::team::TeamId;
+use crate::components::unit::UnitId;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+fn default_proximity_range() -> f32 {
+    5.0
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct VisibilityConfig {
+    /// If enabled, objective information is only provided to controllers of teams observing it.
+    #[serde(default)]
+    pub fog_of_war: bool,
+    /// Range of the proximity sensor every unit has, in meters.
+    #[serde(default = "default_proximity_range")]
+    pub proximity_range: f32,
+}
+impl Default for VisibilityConfig {
+    fn default() -> Self {
+        Self {
+            fog_of_war: false,
+            proximity_range: default_proximity_range(),
+        }
+    }
+}
+
+/// What a single team currently observes.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Observation {
+    /// Units that are observed, this includes the team's own units.
+    pub units: Vec<UnitId>,
+    /// Objective entities that are observed.
+    pub entities: Vec<EntityId>,
+}
+
+/// Holds per team what is currently observed, through radar returns or proximity. Observations
+/// are shared by all units of a team.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Visibility {
+    config: VisibilityConfig,
+    observations: Vec<(TeamId, Observation)>,
+}
+
+impl Visibility {
+    pub fn new(config: VisibilityConfig) -> Self {
+        Visibility {
+            config,
+            observations: vec![],
+        }
+    }
+
+    pub fn config(&self) -> VisibilityConfig {
+        self.config
+    }
+
+    pub fn is_fog_of_war(&self) -> bool {
+        self.config.fog_of_war
+    }
+
+    pub fn proximity_range(&self) -> f32 {
+        self.config.proximity_range
+    }
+
+    pub fn set_observation(&mut self, team: TeamId, observation: Observation) {
+        if let Some(entry) = self.observations.iter_mut().find(|(t, _)| *t == team) {
+            entry.1 = observation;
+        } else {
+            self.observations.push((team, observation));
+        }
+    }
+
+    pub fn observation(&self, team: TeamId) -> Option<&Observation> {
+        self.observations
+            .iter()
+            .find(|(t, _)| *t == team)
+            .map(|(_, o)| o)
+    }
+
+    /// Whether the team currently observes this unit.
+    pub fn can_see_unit(&self, team: TeamId, unit: UnitId) -> bool {
+        self.observation(team)
+            .map(|o| o.units.contains(&unit))
+            .unwrap_or(false)
+    }
+
+    /// Whether the team currently observes this objective entity.
+    pub fn can_see_entity(&self, team: TeamId, entity: EntityId) -> bool {
+        self.observation(team)
+            .map(|o| o.entities.contains(&entity))
+            .unwrap_or(false)
+    }
+}
+impl Component for Visibility {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::team::make_team_id;
+    use crate::components::unit::make_unit_id;
+
+    #[test]
+    fn test_visibility() {
+        let t1 = make_team_id(1);
+        let t2 = make_team_id(2);
+        let u1 = make_unit_id(1);
+        let u2 = make_unit_id(2);
+        let mut visibility = Visibility::new(VisibilityConfig::default());
+        assert!(!visibility.can_see_unit(t1, u1));
+
+        visibility.set_observation(
+            t1,
+            Observation {
+                units: vec![u1, u2],
+                entities: vec![],
+            },
+        );
+        visibility.set_observation(
+            t2,
+            Observation {
+                units: vec![u2],
+                entities: vec![],
+            },
+        );
+        assert!(visibility.can_see_unit(t1, u2));
+        assert!(!visibility.can_see_unit(t2, u1));
+
+        // Observations are replaced, not accumulated.
+        visibility.set_observation(t1, Observation::default());
+        assert!(!visibility.can_see_unit(t1, u2));
+        assert!(visibility.can_see_unit(t2, u2));
+    }
+}
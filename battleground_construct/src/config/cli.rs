@@ -86,6 +86,11 @@ struct Scenario {
     #[arg(short = 'w', long)]
     record: Option<String>,
 
+    /// Also record the register reads and writes of the controllers, these are written alongside
+    /// the recording with a '.control' suffix.
+    #[arg(long)]
+    record_control: bool,
+
     /// Outro duration, defaults to 4.55 seconds.
     #[arg(long)]
     outro_duration: Option<f32>,
@@ -117,7 +122,7 @@ pub fn parse_wrap_up_args() -> Result<WrapUpConfig, Box<dyn std::error::Error>>
     };
 
     let scenario = match setup {
-        Setup::Scenario(config) => Some(config),
+        Setup::Scenario(config) => Some(*config),
         _ => None,
     };
 
@@ -130,7 +135,7 @@ pub fn parse_wrap_up_args() -> Result<WrapUpConfig, Box<dyn std::error::Error>>
 }
 
 pub enum Setup {
-    Scenario(ScenarioConfig),
+    Scenario(Box<ScenarioConfig>),
     Play(String),
 }
 
@@ -166,6 +171,7 @@ pub fn parse_setup_args() -> Result<Setup, Box<dyn std::error::Error>> {
 
             if scenario.record.is_some() {
                 specification.recording = true;
+                specification.record_control = scenario.record_control;
             }
 
             #[cfg(not(feature = "unit_control_wasm"))]
@@ -208,7 +214,8 @@ pub fn parse_setup_args() -> Result<Setup, Box<dyn std::error::Error>> {
                 .chain(extra_config.iter())
                 .map(|v| v.as_str())
                 .collect();
-            apply_config(&config_strs, specification).map(Setup::Scenario)
+            apply_config(&config_strs, specification)
+                .map(|config| Setup::Scenario(Box::new(config)))
         }
         Commands::Play(play) => Ok(Setup::Play(play.file)),
         Commands::Recording(subcommand) => {
@@ -513,12 +520,14 @@ mod test {
                         name: "red".to_owned(),
                         color: (255, 0, 0),
                         controller: None,
+                        commander: None,
                         comment: None,
                     },
                     Team {
                         name: "blue".to_owned(),
                         color: (0, 255, 0),
                         controller: None,
+                        commander: None,
                         comment: None,
                     },
                 ],
@@ -32,6 +32,15 @@ pub fn add_systems(systems: &mut Systems) {
     systems.add_system(Box::new(
         systems::match_logic_team_deathmatch::MatchLogicTeamDeathmatch {},
     ));
+    systems.add_system(Box::new(
+        systems::match_logic_capture_the_flag::MatchLogicCaptureTheFlag {},
+    ));
+    systems.add_system(Box::new(
+        systems::match_logic_attack_defend::MatchLogicAttackDefend {},
+    ));
+    systems.add_system(Box::new(systems::match_logic_payload::MatchLogicPayload {}));
+    // Scoring combines the objectives, so it must go after them.
+    systems.add_system(Box::new(systems::match_logic_scoring::MatchLogicScoring {}));
     systems.add_system(Box::new(
         systems::match_logic_domination::MatchLogicDomination {},
     ));
@@ -46,6 +55,8 @@ pub fn add_systems(systems: &mut Systems) {
     systems.add_system(Box::new(
         systems::kinematics_differential_drive::KinematicsDifferentialDrive {},
     ));
+    systems.add_system(Box::new(systems::missile_guidance::MissileGuidance {}));
+    systems.add_system(Box::new(systems::atmosphere_drag::AtmosphereDrag {}));
     systems.add_system(Box::new(
         systems::acceleration_velocity::AccelerationVelocity {},
     ));
@@ -58,9 +69,14 @@ pub fn add_systems(systems: &mut Systems) {
 
     // Projectile system handling, hit calculation, impact processing
     systems.add_system(Box::new(systems::projectile_hit::ProjectileHit {}));
+    systems.add_system(Box::new(systems::mine_trigger::MineTrigger {}));
     systems.add_system(Box::new(systems::process_impact::ProcessImpact {}));
     // ProcessHitBy MUST go after the hit calculation.
     systems.add_system(Box::new(systems::process_hit_by::ProcessHitBy {}));
+    // Degrade any subsystems that were damaged by the hits.
+    systems.add_system(Box::new(
+        systems::subsystem_degradation::SubsystemDegradation {},
+    ));
 
     // Next, determine the health of any unit, mark them as destroyed if applicable.
     systems.add_system(Box::new(systems::health_check::HealthCheck {}));
@@ -68,6 +84,11 @@ pub fn add_systems(systems: &mut Systems) {
     // Destroy anything marked as destroyed by the health check.
     systems.add_system(Box::new(systems::destroy::Destroy {}));
 
+    // Queue replacements for destroyed units and spawn reinforcements that are ready.
+    systems.add_system(Box::new(
+        systems::reinforcement_spawn::ReinforcementSpawn {},
+    ));
+
     // Coloring / display systems, they don't really matter when they go.
     // systems.add_system(Box::new(systems::health_tank_body::HealthTankBody {}));
     systems.add_system(Box::new(systems::team_color_body::TeamColorBody {}));
@@ -88,6 +109,14 @@ pub fn add_systems(systems: &mut Systems) {
 
     // Calculate the radar hits
     systems.add_system(Box::new(systems::radar_scan::RadarScan {}));
+    // Calculate the lidar ranges
+    systems.add_system(Box::new(systems::lidar_scan::LidarScan {}));
+    // Update the gps, odometry and imu measurements.
+    systems.add_system(Box::new(
+        systems::localization_update::LocalizationUpdate {},
+    ));
+    // Combine the radar hits and proximity into what each team observes.
+    systems.add_system(Box::new(systems::visibility_update::VisibilityUpdate {}));
     // Run the unit controllers
     systems.add_system(Box::new(systems::unit_control::UnitControl {}));
 
@@ -102,4 +131,8 @@ pub fn add_systems(systems: &mut Systems) {
     // Shoot any cannons
     systems.add_system(Box::new(systems::cannon_trigger::CannonTrigger {}));
     systems.add_system(Box::new(systems::gun_battery_trigger::GunBatteryTrigger {}));
+    systems.add_system(Box::new(
+        systems::missile_launcher_trigger::MissileLauncherTrigger {},
+    ));
+    systems.add_system(Box::new(systems::mine_layer_deploy::MineLayerDeploy {}));
 }
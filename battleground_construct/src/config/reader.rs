@@ -88,3 +88,130 @@ mod test {
         }
     }
 }
+
+#[cfg(test)]
+mod test_scenarios {
+    use super::super::specification::{AtmosphereConfig, ScenarioConfig};
+    use super::*;
+    #[test]
+    fn test_scenario_files_setup() {
+        let scenarios: &[(&str, &str)] = &[
+            ("atmosphere", include_str!("scenario/test_atmosphere.yaml")),
+            (
+                "attack_defend",
+                include_str!("scenario/test_attack_defend.yaml"),
+            ),
+            (
+                "capture_the_flag",
+                include_str!("scenario/test_capture_the_flag.yaml"),
+            ),
+            ("composite", include_str!("scenario/test_composite.yaml")),
+            ("fog_of_war", include_str!("scenario/test_fog_of_war.yaml")),
+            (
+                "localization",
+                include_str!("scenario/test_localization.yaml"),
+            ),
+            ("payload", include_str!("scenario/test_payload.yaml")),
+            (
+                "radio_propagation",
+                include_str!("scenario/test_radio_propagation.yaml"),
+            ),
+        ];
+        for &(name, content) in scenarios {
+            let config = load_yaml_config(content);
+            assert!(config.is_ok(), "Failed to read {name}; {config:?}");
+            let construct = super::super::setup::setup_scenario(config.unwrap());
+            assert!(
+                construct.is_ok(),
+                "Failed to setup {name}; {:?}",
+                construct.err()
+            );
+        }
+    }
+
+    /// Apply each modification to a freshly loaded scenario and assert that setup rejects it.
+    fn assert_setup_rejects<T>(
+        content: &str,
+        get: impl Fn(&mut ScenarioConfig) -> &mut T,
+        modifications: &[&dyn Fn(&mut T)],
+    ) {
+        for (i, modify) in modifications.iter().enumerate() {
+            let mut config = load_yaml_config(content).unwrap();
+            modify(get(&mut config));
+            assert!(
+                super::super::setup::setup_scenario(config).is_err(),
+                "modification {i} was accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scenario_invalid_payload() {
+        use super::super::specification::{MatchType, Waypoint};
+        let content = include_str!("scenario/test_payload.yaml");
+        assert_setup_rejects(
+            content,
+            |config| &mut config.match_config.mode,
+            &[
+                &|mode| {
+                    if let MatchType::Payload { path, .. } = mode {
+                        *path = vec![Waypoint { x: 1.0, y: 2.0 }];
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload { path, .. } = mode {
+                        *path = vec![Waypoint { x: 1.0, y: 2.0 }, Waypoint { x: 1.0, y: 2.0 }];
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload {
+                        attacker, defender, ..
+                    } = mode
+                    {
+                        *defender = *attacker;
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload { radius, .. } = mode {
+                        *radius = 0.0;
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload { radius, .. } = mode {
+                        *radius = f32::NAN;
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload { speed, .. } = mode {
+                        *speed = -1.0;
+                    }
+                },
+                &|mode| {
+                    if let MatchType::Payload { speed, .. } = mode {
+                        *speed = f32::INFINITY;
+                    }
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn test_scenario_invalid_atmosphere() {
+        let content = include_str!("scenario/test_atmosphere.yaml");
+        assert_setup_rejects(
+            content,
+            |config| &mut config.atmosphere,
+            &[
+                &|atmosphere: &mut AtmosphereConfig| atmosphere.drag_coefficient = -0.1,
+                &|atmosphere| atmosphere.drag_coefficient = f32::NAN,
+                &|atmosphere| atmosphere.wind.x = f32::INFINITY,
+                &|atmosphere| atmosphere.wind.period = -1.0,
+            ],
+        );
+
+        // Negative wind velocities are just the opposite direction.
+        let mut config = load_yaml_config(content).unwrap();
+        config.atmosphere.wind.x = -3.0;
+        assert!(super::super::setup::setup_scenario(config).is_ok());
+    }
+}
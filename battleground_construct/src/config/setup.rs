@@ -25,7 +25,7 @@ impl std::fmt::Display for SetupError {
 
 pub fn setup(config: Setup) -> Result<Construct, Box<dyn std::error::Error>> {
     match config {
-        Setup::Scenario(scenario) => setup_scenario(scenario),
+        Setup::Scenario(scenario) => setup_scenario(*scenario),
         Setup::Play(path) => setup_playback_path(&path),
     }
 }
@@ -69,6 +69,97 @@ fn setup_playback_common(
     Ok(construct)
 }
 
+/// Create a controller from the controller type specification.
+fn controller_type_to_control(
+    controller_type: &specification::ControllerType,
+    control_config: &std::collections::HashMap<String, specification::ControllerType>,
+    team_config: &std::collections::HashMap<String, specification::Team>,
+) -> Result<Box<dyn UnitControl>, Box<dyn std::error::Error>> {
+    Ok(match controller_type {
+        specification::ControllerType::SwivelShoot => {
+            Box::new(unit_control_builtin::tank_swivel_shoot::TankSwivelShoot::new())
+        }
+        specification::ControllerType::Idle => {
+            Box::new(unit_control_builtin::idle::Idle {})
+        }
+        specification::ControllerType::RadioPosition => {
+            Box::new(unit_control_builtin::radio_position::RadioPosition {})
+        }
+        specification::ControllerType::InterfacePrinter => {
+            Box::new(unit_control_builtin::interface_printer::InterfacePrinter {})
+        }
+        specification::ControllerType::NaiveShoot => {
+            Box::new(unit_control_builtin::naive_shoot::NaiveShoot::new())
+        }
+        specification::ControllerType::DiffDriveForwardsBackwards{velocities, duration} => {
+            Box::new(unit_control_builtin::diff_drive_forwards_backwards::DiffDriveForwardsBackwardsControl::new(*velocities, *duration))
+        }
+        specification::ControllerType::DiffDriveCapturable => {
+            Box::new(unit_control_builtin::diff_drive_capturable::DiffDriveCapturable {})
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        specification::ControllerType::LibraryLoad { name } => {
+            unit_control_builtin::dynamic_load_control::DynamicLoadControl::new(name)?
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        specification::ControllerType::Process { command, args, timeout } => {
+            use unit_control_builtin::process_control::*;
+            let config = ProcessControlConfig {
+                command: command.clone(),
+                args: args.clone(),
+                timeout: timeout.unwrap_or(PROCESS_CONTROL_DEFAULT_TIMEOUT),
+            };
+            ProcessControl::new(config)?
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        specification::ControllerType::Socket {
+            address,
+            lockstep,
+            timeout,
+        } => {
+            use unit_control_builtin::socket_control::*;
+            let config = SocketControlConfig {
+                address: address.clone(),
+                lockstep: *lockstep,
+                timeout: timeout.unwrap_or(SOCKET_CONTROL_DEFAULT_TIMEOUT),
+            };
+            SocketControl::new(config)?
+        }
+        #[cfg(feature = "unit_control_wasm")]
+        specification::ControllerType::Wasm(wasmconfig) => {
+            let config = unit_control_wasm::UnitControlWasmConfig {
+                wasm_path: wasmconfig.path.clone().into(),
+                fuel_per_update: wasmconfig.fuel_per_update,
+                reload: wasmconfig.reload,
+                fuel_for_setup: wasmconfig.fuel_for_setup,
+            };
+            Box::new(unit_control_wasm::UnitControlWasm::new_with_config(config)?)
+        }
+        specification::ControllerType::SequenceControl { controllers } => {
+            let mut v = vec![];
+            for t in controllers.iter() {
+                v.push(controller_type_to_control(t, control_config, team_config)?);
+            }
+            Box::new(unit_control_builtin::sequence_control::SequenceControl::new(v))
+        }
+        specification::ControllerType::Function ( f ) => {
+            f()
+        }
+        specification::ControllerType::FromControlConfig{ name } => {
+            let subcontrol = control_config.get(name).ok_or_else(|| {
+                SetupError::new(&format!("requested controller {} not found", name))})?;
+            controller_type_to_control(subcontrol, control_config, team_config)?
+        }
+        specification::ControllerType::TeamController{ name } => {
+            let subcontrol = team_config.get(name).ok_or_else(|| {
+                SetupError::new(&format!("controlller for requested team {} not found", name))})?;
+            let subcontrol = subcontrol.controller.as_ref().ok_or_else(||{
+                SetupError::new(&format!("team {} doesn't have a controller but is necessary", name))})?;
+            controller_type_to_control(subcontrol, control_config, team_config)?
+        }
+    })
+}
+
 pub fn setup_scenario(
     config: super::specification::ScenarioConfig,
 ) -> Result<Construct, Box<dyn std::error::Error>> {
@@ -90,6 +181,13 @@ pub fn setup_scenario(
             .add_system(Box::new(systems::record::Record {}));
     }
 
+    if config.record_control {
+        construct.world.add_component(
+            recorder_entity,
+            components::control_recording::ControlRecording::new(),
+        );
+    }
+
     match config.pre_setup.as_str() {
         "" => {}
         "playground" => {
@@ -102,9 +200,56 @@ pub fn setup_scenario(
 
     let world = &mut construct.world;
 
+    // Add the atmosphere, affecting projectiles.
+    let atmosphere = config.atmosphere;
+    let wind = atmosphere.wind;
+    if !(atmosphere.drag_coefficient.is_finite() && atmosphere.drag_coefficient >= 0.0) {
+        return Err(Box::new(SetupError::new(
+            "atmosphere drag coefficient must not be negative",
+        )));
+    }
+    if !([wind.x, wind.y, wind.variation_x, wind.variation_y]
+        .iter()
+        .all(|v| v.is_finite())
+        && wind.period.is_finite()
+        && wind.period >= 0.0)
+    {
+        return Err(Box::new(SetupError::new(
+            "wind must be finite and its period must not be negative",
+        )));
+    }
+    let atmosphere_entity = world.add_entity();
+    world.add_component(
+        atmosphere_entity,
+        components::atmosphere::Atmosphere::new(config.atmosphere),
+    );
+
+    // Add the localization, determining the sensor noise.
+    let localization_entity = world.add_entity();
+    world.add_component(
+        localization_entity,
+        components::localization::Localization::new(config.localization),
+    );
+
+    // Add the radio propagation, determining which messages are delivered.
+    let radio_propagation_entity = world.add_entity();
+    world.add_component(
+        radio_propagation_entity,
+        components::radio_propagation::RadioPropagation::new(config.radio),
+    );
+
+    // Add the visibility, tracking what each team observes.
+    let visibility_entity = world.add_entity();
+    world.add_component(
+        visibility_entity,
+        components::visibility::Visibility::new(config.visibility),
+    );
+
     // Add teams
     let mut team_set = std::collections::HashMap::<String, specification::Team>::new();
     let mut teams = vec![];
+    let mut team_names = vec![];
+    let mut commanders = vec![];
     for team in config.spawn_config.teams {
         let team_id = components::id_generator::generate_id(world);
         let team_entity = world.add_entity();
@@ -119,7 +264,11 @@ pub fn setup_scenario(
             )));
         }
         team_set.insert(team.name.to_owned(), team.clone());
+        if let Some(commander) = team.commander {
+            commanders.push((team_component.id(), commander));
+        }
         teams.push(team_component.id());
+        team_names.push(team.name.clone());
         world.add_component(team_entity, team_component);
     }
 
@@ -134,72 +283,6 @@ pub fn setup_scenario(
             None
         };
 
-        fn controller_type_to_control(
-            controller_type: &specification::ControllerType,
-            control_config: &std::collections::HashMap<String, specification::ControllerType>,
-            team_config: &std::collections::HashMap<String, specification::Team>,
-        ) -> Result<Box<dyn UnitControl>, Box<dyn std::error::Error>> {
-            Ok(match controller_type {
-                specification::ControllerType::SwivelShoot => {
-                    Box::new(unit_control_builtin::tank_swivel_shoot::TankSwivelShoot::new())
-                }
-                specification::ControllerType::Idle => {
-                    Box::new(unit_control_builtin::idle::Idle {})
-                }
-                specification::ControllerType::RadioPosition => {
-                    Box::new(unit_control_builtin::radio_position::RadioPosition {})
-                }
-                specification::ControllerType::InterfacePrinter => {
-                    Box::new(unit_control_builtin::interface_printer::InterfacePrinter {})
-                }
-                specification::ControllerType::NaiveShoot => {
-                    Box::new(unit_control_builtin::naive_shoot::NaiveShoot::new())
-                }
-                specification::ControllerType::DiffDriveForwardsBackwards{velocities, duration} => {
-                    Box::new(unit_control_builtin::diff_drive_forwards_backwards::DiffDriveForwardsBackwardsControl::new(*velocities, *duration))
-                }
-                specification::ControllerType::DiffDriveCapturable => {
-                    Box::new(unit_control_builtin::diff_drive_capturable::DiffDriveCapturable {})
-                }
-                #[cfg(not(target_arch = "wasm32"))]
-                specification::ControllerType::LibraryLoad { name } => {
-                    unit_control_builtin::dynamic_load_control::DynamicLoadControl::new(name)?
-                }
-                #[cfg(feature = "unit_control_wasm")]
-                specification::ControllerType::Wasm(wasmconfig) => {
-                    let config = unit_control_wasm::UnitControlWasmConfig {
-                        wasm_path: wasmconfig.path.clone().into(),
-                        fuel_per_update: wasmconfig.fuel_per_update,
-                        reload: wasmconfig.reload,
-                        fuel_for_setup: wasmconfig.fuel_for_setup,
-                    };
-                    Box::new(unit_control_wasm::UnitControlWasm::new_with_config(config)?)
-                }
-                specification::ControllerType::SequenceControl { controllers } => {
-                    let mut v = vec![];
-                    for t in controllers.iter() {
-                        v.push(controller_type_to_control(t, control_config, team_config)?);
-                    }
-                    Box::new(unit_control_builtin::sequence_control::SequenceControl::new(v))
-                }
-                specification::ControllerType::Function ( f ) => {
-                    f()
-                }
-                specification::ControllerType::FromControlConfig{ name } => {
-                    let subcontrol = control_config.get(name).ok_or_else(|| {
-                        SetupError::new(&format!("requested controller {} not found", name))})?;
-                    controller_type_to_control(subcontrol, control_config, team_config)?
-                }
-                specification::ControllerType::TeamController{ name } => {
-                    let subcontrol = team_config.get(name).ok_or_else(|| {
-                        SetupError::new(&format!("controlller for requested team {} not found", name))})?;
-                    let subcontrol = subcontrol.controller.as_ref().ok_or_else(||{
-                        SetupError::new(&format!("team {} doesn't have a controller but is necessary", name))})?;
-                    controller_type_to_control(subcontrol, control_config, team_config)?
-                }
-            })
-        }
-
         let controller: Box<dyn UnitControl> = controller_type_to_control(
             &spawn.controller,
             &config.spawn_config.control_config,
@@ -231,6 +314,106 @@ pub fn setup_scenario(
         }
     }
 
+    // Spawn the team commanders.
+    for (team, controller_type) in commanders {
+        let controller = controller_type_to_control(
+            &controller_type,
+            &config.spawn_config.control_config,
+            &team_set,
+        )?;
+        units::commander::spawn_commander(
+            world,
+            units::commander::CommanderSpawnConfig { team, controller },
+        );
+    }
+
+    // Setup reinforcements
+    if let Some(reinforcement_config) = config.spawn_config.reinforcements {
+        if reinforcement_config
+            .wave_interval
+            .map(|v| !(v.is_finite() && v > 0.0))
+            .unwrap_or(false)
+        {
+            return Err(Box::new(SetupError::new(
+                "reinforcement wave interval must be positive",
+            )));
+        }
+        let respawn_delay = reinforcement_config.respawn_delay;
+        if !(respawn_delay.is_finite() && respawn_delay >= 0.0) {
+            return Err(Box::new(SetupError::new(
+                "reinforcement respawn delay must not be negative",
+            )));
+        }
+        let mut reinforcements = components::reinforcements::Reinforcements::new(
+            reinforcement_config.respawn_delay,
+            reinforcement_config.wave_interval,
+        );
+        for team_reinforcements in reinforcement_config.teams {
+            let team_index = team_reinforcements.team;
+            let team_id = *teams
+                .get(team_index)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            let team_name = &team_names[team_index];
+            let controller_type = team_reinforcements
+                .controller
+                .or_else(|| team_set[team_name].controller.clone())
+                .ok_or_else(|| {
+                    SetupError::new(&format!(
+                        "reinforcements for team {} need a controller",
+                        team_name
+                    ))
+                })?;
+            let control_config = config.spawn_config.control_config.clone();
+            let team_config = team_set.clone();
+            let controller: components::reinforcements::ReinforcementController =
+                std::rc::Rc::new(move || {
+                    controller_type_to_control(&controller_type, &control_config, &team_config)
+                });
+            reinforcements.add_team(
+                team_id,
+                components::reinforcements::TeamReinforcements {
+                    tickets: team_reinforcements.tickets,
+                    spawn_zones: team_reinforcements.spawn_zones,
+                    radio_config: Some(team_reinforcements.radio),
+                    controller,
+                },
+            );
+        }
+        let entity = world.add_entity();
+        world.add_component(entity, reinforcements);
+    }
+
+    // Setup match.
+    setup_match_mode(world, config.match_config.mode, &teams)?;
+
+    // Configure the rules deciding the outcome.
+    let entity = world.add_entity();
+    world.add_component(
+        entity,
+        components::match_rules::MatchRules::new(
+            &config.match_config.tie_breakers,
+            config.match_config.no_contest_on_controller_failure,
+        ),
+    );
+
+    // Configure time limit
+    if let Some(time_limit) = config.match_config.time_limit {
+        let entity = world.add_entity();
+        world.add_component(
+            entity,
+            components::match_time_limit::MatchTimeLimit::new(time_limit),
+        );
+    }
+
+    Ok(construct)
+}
+
+/// Setup the components for the match mode, composite modes setup each of their objectives.
+fn setup_match_mode(
+    world: &mut engine::World,
+    mode: specification::MatchType,
+    teams: &[components::team::TeamId],
+) -> Result<(), Box<dyn std::error::Error>> {
     let setup_king_of_the_hill = |world: &mut engine::World,
                                   capture_points: &[specification::CapturePoint],
                                   point_limit: Option<f32>|
@@ -264,8 +447,7 @@ pub fn setup_scenario(
         Ok(())
     };
 
-    // Setup match.
-    match config.match_config.mode {
+    match mode {
         specification::MatchType::None => {}
         specification::MatchType::Domination {
             team_deathmatch_min,
@@ -298,16 +480,183 @@ pub fn setup_scenario(
         } => {
             setup_king_of_the_hill(world, &capture_points, point_limit)?;
         }
+        specification::MatchType::AttackDefend {
+            attacker,
+            defender,
+            capture_points,
+        } => {
+            let attacker = *teams
+                .get(attacker)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            let defender = *teams
+                .get(defender)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            if attacker == defender {
+                return Err(Box::new(SetupError::new(
+                    "attacker and defender must be different teams",
+                )));
+            }
+            let mut points = vec![];
+            for (i, point) in capture_points.iter().enumerate() {
+                let initial_owner = if let Some(team_index) = point.team {
+                    *teams
+                        .get(team_index)
+                        .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?
+                } else {
+                    defender
+                };
+                let config = crate::units::capturable_flag::CapturableFlagConfig {
+                    x: point.x,
+                    y: point.y,
+                    yaw: point.yaw,
+                    radius: point.radius,
+                    capture_speed: point.capture_speed,
+                    initial_owner: Some(initial_owner),
+                    ..Default::default()
+                };
+                let entity = crate::units::capturable_flag::spawn_capturable_flag(world, config);
+                // Only the first point can be captured at the start.
+                if i != 0 {
+                    let mut capturable = world
+                        .component_mut::<components::capturable::Capturable>(entity)
+                        .unwrap();
+                    capturable.set_locked(true);
+                }
+                points.push(entity);
+            }
+            // Spawn the attack defend component.
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_attack_defend::MatchAttackDefend::new(
+                    attacker, defender, &points,
+                ),
+            );
+        }
+        specification::MatchType::Payload {
+            attacker,
+            defender,
+            path,
+            radius,
+            speed,
+        } => {
+            let attacker = *teams
+                .get(attacker)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            let defender = *teams
+                .get(defender)
+                .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+            if attacker == defender {
+                return Err(Box::new(SetupError::new(
+                    "attacker and defender must be different teams",
+                )));
+            }
+            if !(radius.is_finite() && radius > 0.0 && speed.is_finite() && speed > 0.0) {
+                return Err(Box::new(SetupError::new(
+                    "payload radius and speed must be positive",
+                )));
+            }
+            if path.len() < 2 {
+                return Err(Box::new(SetupError::new(
+                    "payload path needs at least two waypoints",
+                )));
+            }
+            let path = path.iter().map(|w| (w.x, w.y)).collect::<Vec<_>>();
+            let path_length = components::payload::Payload::new(&path, radius, speed).path_length();
+            if !(path_length.is_finite() && path_length > 0.0) {
+                return Err(Box::new(SetupError::new("payload path has no length")));
+            }
+            let config = crate::units::payload::PayloadConfig {
+                path,
+                radius,
+                speed,
+            };
+            crate::units::payload::spawn_payload(world, config);
+            // Spawn the payload match component.
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_payload::MatchPayload::new(attacker, defender, path_length),
+            );
+        }
+        specification::MatchType::Composite {
+            objectives,
+            scoring,
+            tie_breakers,
+        } => {
+            for objective in objectives {
+                if let specification::MatchType::Composite { .. } = objective {
+                    return Err(Box::new(SetupError::new(
+                        "composite objectives can't be nested",
+                    )));
+                }
+                setup_match_mode(world, objective, teams)?;
+            }
+            // The match logic only handles a single instance of each objective.
+            let duplicated = world
+                .component_iter::<components::match_king_of_the_hill::MatchKingOfTheHill>()
+                .count()
+                > 1
+                || world
+                    .component_iter::<components::match_team_deathmatch::MatchTeamDeathmatch>()
+                    .count()
+                    > 1
+                || world
+                    .component_iter::<components::match_domination::MatchDomination>()
+                    .count()
+                    > 1
+                || world
+                    .component_iter::<components::match_capture_the_flag::MatchCaptureTheFlag>()
+                    .count()
+                    > 1
+                || world
+                    .component_iter::<components::match_attack_defend::MatchAttackDefend>()
+                    .count()
+                    > 1
+                || world
+                    .component_iter::<components::match_payload::MatchPayload>()
+                    .count()
+                    > 1;
+            if duplicated {
+                return Err(Box::new(SetupError::new(
+                    "composite objectives contain the same objective twice",
+                )));
+            }
+            // Spawn the scoring component.
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_scoring::MatchScoring::new(&scoring, &tie_breakers),
+            );
+        }
+        specification::MatchType::CaptureTheFlag {
+            flags,
+            point_limit,
+            return_time,
+        } => {
+            for flag in flags.iter() {
+                let team = teams
+                    .get(flag.team)
+                    .ok_or_else(|| Box::new(SetupError::new("team index out of range")))?;
+                let config = crate::units::capturable_flag::CapturableFlagConfig {
+                    x: flag.x,
+                    y: flag.y,
+                    radius: flag.radius,
+                    carriable: Some(*team),
+                    ..Default::default()
+                };
+                crate::units::capturable_flag::spawn_capturable_flag(world, config);
+            }
+            // Spawn the capture the flag component.
+            let entity = world.add_entity();
+            world.add_component(
+                entity,
+                components::match_capture_the_flag::MatchCaptureTheFlag::new(
+                    point_limit,
+                    return_time,
+                ),
+            );
+        }
     }
-
-    // Configure time limit
-    if let Some(time_limit) = config.match_config.time_limit {
-        let entity = world.add_entity();
-        world.add_component(
-            entity,
-            components::match_time_limit::MatchTimeLimit::new(time_limit),
-        );
-    }
-
-    Ok(construct)
+    Ok(())
 }
@@ -16,6 +16,31 @@ pub struct CapturePoint {
     pub team: Option<usize>,
 }
 
+fn default_flag_radius() -> f32 {
+    1.0
+}
+#[derive(Serialize, Deserialize, Debug, Copy, Default, Clone)]
+pub struct FlagBase {
+    /// Index of the team this flag belongs to.
+    pub team: usize,
+    pub x: f32,
+    pub y: f32,
+    #[serde(default = "default_flag_radius")]
+    pub radius: f32,
+}
+
+fn default_payload_radius() -> f32 {
+    3.0
+}
+fn default_payload_speed() -> f32 {
+    0.5
+}
+#[derive(Serialize, Deserialize, Debug, Copy, Default, Clone)]
+pub struct Waypoint {
+    pub x: f32,
+    pub y: f32,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(tag = "type")]
 pub enum MatchType {
@@ -33,6 +58,47 @@ pub enum MatchType {
         capture_points: Vec<CapturePoint>,
         point_limit: Option<f32>,
     },
+    AttackDefend {
+        /// Index of the attacking team, it must capture the points in order.
+        attacker: usize,
+        /// Index of the defending team, it wins if the time limit is reached.
+        defender: usize,
+        /// Capture points, in the order in which they must be captured. Owned by the defender
+        /// unless their team is specified.
+        capture_points: Vec<CapturePoint>,
+    },
+    Payload {
+        /// Index of the attacking team, it must escort the payload to the end of the path.
+        attacker: usize,
+        /// Index of the defending team, it wins if the time limit is reached.
+        defender: usize,
+        /// Path the payload follows, starting at the first waypoint.
+        path: Vec<Waypoint>,
+        /// Radius within which units influence the payload.
+        #[serde(default = "default_payload_radius")]
+        radius: f32,
+        /// Speed at which the payload moves.
+        #[serde(default = "default_payload_speed")]
+        speed: f32,
+    },
+    /// Multiple objectives played at the same time, each ends the match when its own limit is
+    /// reached. Otherwise, the winner is the team with the highest weighted score.
+    Composite {
+        objectives: Vec<MatchType>,
+        /// Weights used to combine the objectives into a single score.
+        #[serde(default)]
+        scoring: Vec<ScoreWeight>,
+        /// Sources compared in order if teams have an equal score.
+        #[serde(default)]
+        tie_breakers: Vec<ScoreSource>,
+    },
+    CaptureTheFlag {
+        flags: Vec<FlagBase>,
+        point_limit: Option<i64>,
+        /// Duration after which a dropped flag returns to its base by itself.
+        #[serde(default)]
+        return_time: Option<f32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -40,6 +106,12 @@ pub struct MatchConfig {
     #[serde(default)]
     pub mode: MatchType,
     pub time_limit: Option<f32>,
+    /// Values compared in order to rank teams that are tied on the objectives.
+    #[serde(default)]
+    pub tie_breakers: Vec<ScoreSource>,
+    /// End the match as no contest if a unit's controller fails.
+    #[serde(default)]
+    pub no_contest_on_controller_failure: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -55,6 +127,11 @@ pub struct Team {
 
     /// The controller to use for this team.
     pub controller: Option<ControllerType>,
+
+    /// Controller for the team commander, which coordinates the units of this team. Teams don't
+    /// have a commander unless specified.
+    #[serde(default)]
+    pub commander: Option<ControllerType>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -89,6 +166,31 @@ pub enum ControllerType {
     LibraryLoad {
         name: String,
     },
+    /// Run an external process, communicating over stdin and stdout, see
+    /// [`unit_control_builtin::process_control`].
+    #[cfg(not(target_arch = "wasm32"))]
+    Process {
+        command: String,
+        #[serde(default)]
+        args: Vec<String>,
+        /// Duration within which the process must complete an update, seconds.
+        #[serde(default)]
+        timeout: Option<f32>,
+    },
+    /// Connect to a remote controller over a socket, see
+    /// [`unit_control_builtin::socket_control`].
+    #[cfg(not(target_arch = "wasm32"))]
+    Socket {
+        /// The endpoint, `tcp:<host>:<port>` or `unix:<path>`.
+        address: String,
+        /// Wait for the remote controller to complete each update.
+        #[serde(default)]
+        lockstep: bool,
+        /// Duration after which the simulation proceeds if not in lockstep, also bounds connection
+        /// attempts, seconds.
+        #[serde(default)]
+        timeout: Option<f32>,
+    },
     DiffDriveForwardsBackwards {
         velocities: (f32, f32),
         duration: f32,
@@ -132,12 +234,54 @@ pub struct Spawn {
     pub radio: crate::units::common::RadioConfig,
 }
 
+pub use crate::components::atmosphere::{AtmosphereConfig, WindConfig};
+pub use crate::components::localization::{
+    GpsDenial, GpsDeniedZone, GpsNoiseConfig, ImuNoiseConfig, LocalizationConfig,
+    OdometryNoiseConfig,
+};
+pub use crate::components::match_scoring::{ScoreSource, ScoreWeight};
+pub use crate::components::radio_jammer::RadioJammerConfig;
+pub use crate::components::radio_propagation::RadioPropagationConfig;
+pub use crate::components::reinforcements::SpawnZone;
+pub use crate::components::visibility::VisibilityConfig;
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct TeamReinforcements {
+    /// The team index these reinforcements are for.
+    pub team: usize,
+    /// The number of reinforcements available to this team, unlimited if not specified.
+    #[serde(default)]
+    pub tickets: Option<usize>,
+    /// Zones in which the reinforcements appear, used in round robin order.
+    pub spawn_zones: Vec<SpawnZone>,
+    /// Controller for the reinforcements, the team's controller is used if not specified.
+    #[serde(default)]
+    pub controller: Option<ControllerType>,
+    #[serde(default)]
+    pub radio: crate::units::common::RadioConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ReinforcementConfig {
+    /// Duration between a unit being destroyed and its replacement being available.
+    #[serde(default)]
+    pub respawn_delay: f32,
+    /// If specified, available reinforcements are only spawned at multiples of this interval.
+    #[serde(default)]
+    pub wave_interval: Option<f32>,
+    /// Reinforcement rules per team, teams not in this list are not reinforced.
+    pub teams: Vec<TeamReinforcements>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SpawnConfig {
     #[serde(default)]
     pub control_config: std::collections::HashMap<String, ControllerType>,
     pub teams: Vec<Team>,
     pub spawns: Vec<Spawn>,
+    /// Replacements for destroyed units during the match.
+    #[serde(default)]
+    pub reinforcements: Option<ReinforcementConfig>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -149,6 +293,10 @@ pub struct ScenarioConfig {
     #[serde(default)]
     pub recording: bool,
 
+    /// Record the register reads and writes of each unit controller.
+    #[serde(default)]
+    pub record_control: bool,
+
     /// Denotes the match specification.
     #[serde(default)]
     pub match_config: MatchConfig,
@@ -156,6 +304,22 @@ pub struct ScenarioConfig {
     /// Spawn of vehicles.
     #[serde(default)]
     pub spawn_config: SpawnConfig,
+
+    /// Air drag and wind applied to projectiles.
+    #[serde(default)]
+    pub atmosphere: AtmosphereConfig,
+
+    /// Noise on the localization sensors and gps denied zones.
+    #[serde(default)]
+    pub localization: LocalizationConfig,
+
+    /// Packet loss, interference and bandwidth of the radios.
+    #[serde(default)]
+    pub radio: RadioPropagationConfig,
+
+    /// What teams can observe, and whether objective information is hidden from them.
+    #[serde(default)]
+    pub visibility: VisibilityConfig,
 }
 
 /// This struct specifies the steps to be done after a scenario wraps up.
@@ -37,6 +37,7 @@ pub fn create_wrap_up_report(world: &World) -> WrapUpReport {
                     name: team.name().to_owned(),
                     color: (team_color.r, team_color.g, team_color.b),
                     controller: None,
+                    commander: None,
                     comment: team.comment().map(|x| x.to_owned()),
                 },
             );
@@ -96,6 +97,14 @@ pub fn wrap_up_scenario(
         {
             recording.1.write_file(&path)?;
         }
+        if let Some(control_recording) = construct
+            .world()
+            .component_iter::<components::control_recording::ControlRecording>()
+            .next()
+        {
+            let path = components::control_recording::ControlRecording::path_for_recording(&path);
+            control_recording.1.write_file(&path)?;
+        }
     }
 
     Ok(full_report)
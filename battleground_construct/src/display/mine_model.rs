@@ -0,0 +1,44 @@
+use super::primitives::*;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Copy, Debug, Clone)]
+pub struct MineModel {
+    pub radius: f32,
+}
+impl Default for MineModel {
+    fn default() -> Self {
+        MineModel::new()
+    }
+}
+
+impl MineModel {
+    pub fn new() -> Self {
+        MineModel { radius: 0.15 }
+    }
+}
+impl Component for MineModel {}
+
+impl Drawable for MineModel {
+    fn drawables(&self) -> Vec<Element> {
+        vec![
+            // A flat disc on the floor.
+            Element {
+                transform: Mat4::from_angle_y(cgmath::Deg(-90.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius,
+                    height: 0.05,
+                }),
+                material: Color::rgb(60, 60, 40).into(),
+            },
+            // With a trigger on top.
+            Element {
+                transform: Mat4::from_translation(Vec3::new(0.0, 0.0, 0.05)),
+                primitive: Primitive::Sphere(Sphere {
+                    radius: self.radius * 0.25,
+                }),
+                material: Color::RED.into(),
+            },
+        ]
+    }
+}
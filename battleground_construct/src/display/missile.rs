@@ -0,0 +1,55 @@
+use super::primitives::*;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Copy, Debug, Clone)]
+pub struct Missile {
+    pub length: f32,
+    pub radius: f32,
+}
+impl Default for Missile {
+    fn default() -> Self {
+        Missile::new()
+    }
+}
+
+impl Missile {
+    pub fn new() -> Self {
+        Missile {
+            length: 0.3,
+            radius: 0.03,
+        }
+    }
+}
+impl Component for Missile {}
+
+impl Drawable for Missile {
+    fn drawables(&self) -> Vec<Element> {
+        let body_length = self.length * 0.75;
+        let material = Color::rgb(220, 220, 220).into();
+        vec![
+            // Body, centered on the missile's position.
+            Element {
+                transform: Mat4::from_translation(Vec3::new(-self.length / 2.0, 0.0, 0.0)),
+                primitive: Primitive::Cylinder(Cylinder {
+                    radius: self.radius,
+                    height: body_length,
+                }),
+                material,
+            },
+            // Nose cone.
+            Element {
+                transform: Mat4::from_translation(Vec3::new(
+                    -self.length / 2.0 + body_length,
+                    0.0,
+                    0.0,
+                )),
+                primitive: Primitive::Cone(Cone {
+                    radius: self.radius,
+                    height: self.length - body_length,
+                }),
+                material: Color::RED.into(),
+            },
+        ]
+    }
+}
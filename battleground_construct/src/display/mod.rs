@@ -12,6 +12,8 @@ pub mod draw_module;
 pub mod fireworks;
 pub mod flag;
 pub mod health_bar;
+pub mod mine_model;
+pub mod missile;
 pub mod particle_emitter;
 pub mod primitives;
 pub mod radar_model;
@@ -0,0 +1,40 @@
+use super::components::acceleration::Acceleration;
+use super::components::atmosphere::Atmosphere;
+use super::components::point_projectile::PointProjectile;
+use super::components::velocity::Velocity;
+use super::Clock;
+use engine::prelude::*;
+
+/// Applies air drag, relative to the wind, to ballistic projectiles. Projectiles are those with a
+/// PointProjectile and an Acceleration, their velocity is expressed in the world frame.
+pub struct AtmosphereDrag {}
+impl System for AtmosphereDrag {
+    fn update(&mut self, world: &mut World) {
+        let atmosphere =
+            if let Some((_entity, atmosphere)) = world.component_iter::<Atmosphere>().next() {
+                *atmosphere
+            } else {
+                return;
+            };
+        if atmosphere.drag_coefficient() == 0.0 {
+            return;
+        }
+
+        let (_entity, clock) = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock");
+        let dt = clock.step_as_f32();
+        let t = clock.elapsed_as_f32();
+
+        for entity in world.component_entities::<PointProjectile>() {
+            if world.component::<Acceleration>(entity).is_none() {
+                continue;
+            }
+            if let Some(mut vel) = world.component_mut::<Velocity>(entity) {
+                let drag = atmosphere.drag_acceleration(vel.v, t);
+                vel.v += drag * dt;
+            }
+        }
+    }
+}
@@ -24,18 +24,11 @@ impl System for Capture {
         for (capturable_entity, mut capturable) in world.component_iter_mut::<Capturable>() {
             let mut influence = std::collections::HashMap::new();
             if let Some(capture_point) = world.component::<CapturePoint>(capturable_entity) {
-                let point_pose = components::pose::world_pose(world, capturable_entity);
-                for (marker_entity, _marker) in world.component_iter::<CaptureMarker>() {
-                    if let Some(team_membership) = world.component::<TeamMember>(marker_entity) {
-                        let marker_pose = components::pose::world_pose(world, marker_entity);
-                        if (point_pose.to_translation() - marker_pose.to_translation())
-                            .euclid_norm()
-                            < capture_point.radius()
-                        {
-                            *influence.entry(team_membership.team()).or_insert(0.0) +=
-                                1.0 * dt * capture_point.capture_speed();
-                        }
-                    }
+                for (team, count) in
+                    markers_in_radius(world, capturable_entity, capture_point.radius())
+                {
+                    *influence.entry(team).or_insert(0.0) +=
+                        count as f32 * dt * capture_point.capture_speed();
                 }
             }
             let influence_vec: Vec<(TeamId, f32)> =
@@ -45,3 +38,22 @@ impl System for Capture {
         }
     }
 }
+
+/// Count the capture markers per team that are within the radius of the entity.
+pub fn markers_in_radius(
+    world: &World,
+    entity: EntityId,
+    radius: f32,
+) -> std::collections::HashMap<TeamId, usize> {
+    let mut counts = std::collections::HashMap::new();
+    let pose = components::pose::world_pose(world, entity);
+    for (marker_entity, _marker) in world.component_iter::<CaptureMarker>() {
+        if let Some(team_membership) = world.component::<TeamMember>(marker_entity) {
+            let marker_pose = components::pose::world_pose(world, marker_entity);
+            if (pose.to_translation() - marker_pose.to_translation()).euclid_norm() < radius {
+                *counts.entry(team_membership.team()).or_insert(0) += 1;
+            }
+        }
+    }
+    counts
+}
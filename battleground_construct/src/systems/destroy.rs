@@ -76,6 +76,14 @@ impl System for Destroy {
             all_to_be_removed.append(&mut elements_here);
         }
 
+        // Mark the units themselves, such that they can be reinforced.
+        for (_orig_entity, root_entity) in destroyed_entity_and_root.iter() {
+            world.add_component(
+                *root_entity,
+                components::reinforcements::ReinforcementJustDestroyed,
+            );
+        }
+
         // Remove the destroyed markers.
         world.remove_components::<components::destroyed::Destroyed>(&destroyed);
         for entity in destroyed {
@@ -2,9 +2,14 @@ use super::components;
 use super::display;
 use crate::components::team::{get_team_entity, Team};
 use components::capturable::Capturable;
+use components::carriable_flag::CarriableFlag;
+use components::match_payload::MatchPayload;
+use components::payload::Payload;
 use display::display_control_point::DisplayControlPoint;
 use display::flag::Flag;
 
+use crate::units::capturable_flag::UnitCapturableFlag;
+
 use engine::prelude::*;
 
 pub struct DisplayCaptureFlag {}
@@ -34,5 +39,44 @@ impl System for DisplayCaptureFlag {
                 }
             }
         }
+
+        // Carriable flags and their base always have the color of the team they belong to.
+        let carriable_flags = world
+            .component_iter::<UnitCapturableFlag>()
+            .filter_map(|(flag_entity, unit)| Some((flag_entity, unit.base_entity?)));
+        for (flag_entity, base_entity) in carriable_flags {
+            let color = world
+                .component::<CarriableFlag>(flag_entity)
+                .and_then(|flag| get_team_entity(world, flag.team()))
+                .and_then(|team_entity| world.component::<Team>(team_entity))
+                .map_or(display::Color::rgb(30, 30, 30), |team| *team.color());
+            if let Some(mut flag) = world.component_mut::<Flag>(flag_entity) {
+                flag.set_color(color);
+                flag.set_flag_position(1.0);
+            }
+            if let Some(mut area) = world.component_mut::<DisplayControlPoint>(base_entity) {
+                area.set_color(color);
+                if let Some(flag) = world.component::<CarriableFlag>(flag_entity) {
+                    area.set_radius(flag.radius());
+                }
+            }
+        }
+
+        // The payload's area takes the attacker's color while it is moving.
+        let attacker = world
+            .component_iter::<MatchPayload>()
+            .next()
+            .map(|(_e, match_payload)| match_payload.attacker());
+        for (payload_entity, payload) in world.component_iter::<Payload>() {
+            let color = attacker
+                .filter(|_| payload.is_moving())
+                .and_then(|team| get_team_entity(world, team))
+                .and_then(|team_entity| world.component::<Team>(team_entity))
+                .map_or(display::Color::rgb(30, 30, 30), |team| *team.color());
+            if let Some(mut area) = world.component_mut::<DisplayControlPoint>(payload_entity) {
+                area.set_color(color);
+                area.set_radius(payload.radius());
+            }
+        }
     }
 }
@@ -0,0 +1,60 @@
+use super::components::group::Group;
+use super::components::hit_box::HitBox;
+use super::components::hit_collection::HitCollection;
+use super::components::hit_plane::HitPlane;
+use super::components::hit_sphere::HitSphere;
+use super::components::lidar::{Lidar, LidarObstacle};
+use super::components::pose::world_pose;
+use crate::display::primitives::Mat4;
+use crate::util::cgmath::prelude::*;
+use engine::prelude::*;
+
+pub struct LidarScan {}
+impl System for LidarScan {
+    fn update(&mut self, world: &mut World) {
+        if world.component_iter::<Lidar>().next().is_none() {
+            return;
+        }
+
+        // Obstacles hold the inverse of their pose, to express the rays in their local frame.
+        let mut obstacles: Vec<(Mat4, LidarObstacle, Option<Group>)> = vec![];
+        let mut add_obstacle = |entity: EntityId, local: Mat4, obstacle: LidarObstacle| {
+            obstacles.push((
+                (world_pose(world, entity).transform() * local).to_inv_h(),
+                obstacle,
+                world.component::<Group>(entity).map(|v| v.clone()),
+            ));
+        };
+        use cgmath::SquareMatrix;
+        let identity = Mat4::identity();
+        for (entity, hitbox) in world.component_iter::<HitBox>() {
+            add_obstacle(entity, identity, LidarObstacle::HitBox(*hitbox));
+        }
+        for (entity, sphere) in world.component_iter::<HitSphere>() {
+            add_obstacle(entity, identity, LidarObstacle::HitSphere(*sphere));
+        }
+        for (entity, plane) in world.component_iter::<HitPlane>() {
+            add_obstacle(entity, identity, LidarObstacle::HitPlane(*plane));
+        }
+        for (entity, collection) in world.component_iter::<HitCollection>() {
+            for (local, hitbox) in collection.hit_boxes() {
+                add_obstacle(entity, *local, LidarObstacle::HitBox(*hitbox));
+            }
+        }
+
+        for (entity, mut lidar) in world.component_iter_mut::<Lidar>() {
+            let lidar_pose = world_pose(world, entity);
+            // Ignore the hit volumes of the unit the lidar is part of.
+            let obstacles = obstacles
+                .iter()
+                .filter(|v| {
+                    v.2.as_ref()
+                        .map(|g| !g.entities().contains(&entity))
+                        .unwrap_or(true)
+                })
+                .map(|v| (v.0, v.1))
+                .collect::<Vec<_>>();
+            lidar.update_ranges(lidar_pose.transform(), &obstacles);
+        }
+    }
+}
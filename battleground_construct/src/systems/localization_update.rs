@@ -0,0 +1,134 @@
+use super::components::clock::Clock;
+use super::components::gps::{Gps, GpsFix};
+use super::components::imu::Imu;
+use super::components::localization::{GpsDenial, Localization};
+use super::components::odometry::Odometry;
+use super::components::pose::world_pose;
+use super::components::velocity::Velocity;
+use crate::display::primitives::Vec3;
+use engine::prelude::*;
+
+// Noise channels, each measured quantity gets its own noise.
+const CHANNEL_GPS_X: u64 = 0;
+const CHANNEL_GPS_Y: u64 = 1;
+const CHANNEL_GPS_Z: u64 = 2;
+const CHANNEL_GPS_ROLL: u64 = 3;
+const CHANNEL_GPS_PITCH: u64 = 4;
+const CHANNEL_GPS_YAW: u64 = 5;
+const CHANNEL_ODOMETRY_VELOCITY: u64 = 6;
+const CHANNEL_ODOMETRY_YAW_RATE: u64 = 7;
+const CHANNEL_IMU_ANGULAR_RATE: u64 = 8; // 8, 9, 10
+const CHANNEL_IMU_ACCELERATION: u64 = 11; // 11, 12, 13
+
+/// Updates the gps, odometry and imu measurements, applying the noise of the localization config.
+pub struct LocalizationUpdate {}
+impl System for LocalizationUpdate {
+    fn update(&mut self, world: &mut World) {
+        let localization =
+            if let Some((_e, localization)) = world.component_iter::<Localization>().next() {
+                localization.clone()
+            } else {
+                return;
+            };
+        let config = localization.config();
+
+        let (dt, time) = {
+            let (_e, clock) = world
+                .component_iter::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            (clock.step_as_f32(), clock.elapsed_as_f32())
+        };
+
+        // Gps, apply denial, update rate, noise and bias.
+        let gps_config = config.gps;
+        for entity in world.component_entities::<Gps>() {
+            use crate::util::cgmath::ToRollPitchYaw;
+            use crate::util::cgmath::ToTranslation;
+            let pose = world_pose(world, entity);
+            let translation = pose.to_translation();
+            let rpy = pose.to_rpy();
+            let mut gps = world.component_mut::<Gps>(entity).unwrap();
+
+            match localization.denial(translation.x, translation.y) {
+                Some(GpsDenial::Stale) => {
+                    gps.set_stale();
+                    continue;
+                }
+                Some(GpsDenial::Invalid) => {
+                    gps.set_invalid();
+                    continue;
+                }
+                None => {}
+            }
+
+            let due = match gps.fix() {
+                Some(fix) if gps.is_valid() && gps_config.update_rate > 0.0 => {
+                    // Allow half a step of slack to avoid skipping fixes on rounding.
+                    time - fix.time >= 1.0 / gps_config.update_rate - dt / 2.0
+                }
+                _ => true,
+            };
+            if !due {
+                continue;
+            }
+
+            let noise = |channel: u64, amplitude: f32| {
+                localization.noise(entity, time, channel) * amplitude
+            };
+            let position_noise = gps_config.position_noise;
+            let orientation_noise = gps_config.orientation_noise;
+            gps.set_fix(GpsFix {
+                time,
+                x: translation.x + gps_config.bias_x + noise(CHANNEL_GPS_X, position_noise),
+                y: translation.y + gps_config.bias_y + noise(CHANNEL_GPS_Y, position_noise),
+                z: translation.z + noise(CHANNEL_GPS_Z, position_noise),
+                roll: rpy.x + noise(CHANNEL_GPS_ROLL, orientation_noise),
+                pitch: rpy.y + noise(CHANNEL_GPS_PITCH, orientation_noise),
+                yaw: rpy.z + gps_config.bias_yaw + noise(CHANNEL_GPS_YAW, orientation_noise),
+            });
+        }
+
+        // Odometry, integrate the noisy forward velocity and yaw rate.
+        let odometry_config = config.odometry;
+        for entity in world.component_entities::<Odometry>() {
+            let velocity = if let Some(velocity) = world.component::<Velocity>(entity) {
+                *velocity
+            } else {
+                continue;
+            };
+            let v = velocity.v.x
+                + localization.noise(entity, time, CHANNEL_ODOMETRY_VELOCITY)
+                    * odometry_config.velocity_noise;
+            let w = velocity.w.z
+                + localization.noise(entity, time, CHANNEL_ODOMETRY_YAW_RATE)
+                    * odometry_config.yaw_rate_noise;
+            let mut odometry = world.component_mut::<Odometry>(entity).unwrap();
+            odometry.integrate(v, w, dt);
+        }
+
+        // Imu, angular rates and accelerations with noise.
+        let imu_config = config.imu;
+        for entity in world.component_entities::<Imu>() {
+            let velocity = if let Some(velocity) = world.component::<Velocity>(entity) {
+                *velocity
+            } else {
+                continue;
+            };
+            let noise = |channel: u64, amplitude: f32| {
+                Vec3::new(
+                    localization.noise(entity, time, channel),
+                    localization.noise(entity, time, channel + 1),
+                    localization.noise(entity, time, channel + 2),
+                ) * amplitude
+            };
+            let mut imu = world.component_mut::<Imu>(entity).unwrap();
+            let (w, a) = imu.update(velocity.v, velocity.w, dt);
+            let w = w
+                + noise(CHANNEL_IMU_ANGULAR_RATE, imu_config.angular_rate_noise)
+                + Vec3::new(0.0, 0.0, imu_config.angular_rate_bias);
+            let a = a + noise(CHANNEL_IMU_ACCELERATION, imu_config.acceleration_noise);
+            imu.set_measurement(w, a);
+        }
+    }
+}
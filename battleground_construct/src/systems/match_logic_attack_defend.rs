@@ -0,0 +1,60 @@
+use crate::components;
+use components::capturable::Capturable;
+use components::match_attack_defend::MatchAttackDefend;
+
+use engine::prelude::*;
+
+pub struct MatchLogicAttackDefend {}
+impl System for MatchLogicAttackDefend {
+    fn update(&mut self, world: &mut World) {
+        let (attacker, points, mut captured) =
+            if let Some((_e, attack_defend)) = world.component_iter::<MatchAttackDefend>().next() {
+                (
+                    attack_defend.attacker(),
+                    attack_defend.points().to_vec(),
+                    attack_defend.captured(),
+                )
+            } else {
+                return;
+            };
+
+        // A point is captured once the attacker fully owns it, which unlocks the next point.
+        let mut newly_captured = 0;
+        let mut active_progress = 0.0;
+        while let Some(point) = points.get(captured) {
+            if let Some(mut capturable) = world.component_mut::<Capturable>(*point) {
+                let attacker_owns = capturable.owner() == Some(attacker);
+                if attacker_owns && capturable.strength() >= 1.0 {
+                    // Captured points stay with the attacker.
+                    capturable.set_locked(true);
+                    captured += 1;
+                    newly_captured += 1;
+                    continue;
+                }
+                capturable.set_locked(false);
+                active_progress = if attacker_owns {
+                    capturable.strength()
+                } else {
+                    0.0
+                };
+            }
+            break;
+        }
+
+        // Points that are not yet reachable stay locked.
+        for point in points.iter().skip(captured + 1) {
+            if let Some(mut capturable) = world.component_mut::<Capturable>(*point) {
+                capturable.set_locked(true);
+            }
+        }
+
+        if let Some((_e, mut attack_defend)) =
+            world.component_iter_mut::<MatchAttackDefend>().next()
+        {
+            for _ in 0..newly_captured {
+                attack_defend.capture_active();
+            }
+            attack_defend.set_active_progress(active_progress);
+        }
+    }
+}
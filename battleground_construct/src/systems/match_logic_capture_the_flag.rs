@@ -0,0 +1,157 @@
+use crate::components;
+use components::capture_marker::CaptureMarker;
+use components::carriable_flag::{CarriableFlag, FlagState};
+use components::clock::Clock;
+use components::match_capture_the_flag::MatchCaptureTheFlag;
+use components::pose::{world_pose, Pose};
+use components::team::TeamId;
+use components::unit::UnitId;
+
+use engine::prelude::*;
+
+/// Height above the carrier's body at which a carried flag is displayed.
+const CARRIED_FLAG_HEIGHT: f32 = 0.3;
+
+pub struct MatchLogicCaptureTheFlag {}
+impl System for MatchLogicCaptureTheFlag {
+    fn update(&mut self, world: &mut World) {
+        let return_time =
+            if let Some((_e, ctf)) = world.component_iter::<MatchCaptureTheFlag>().next() {
+                ctf.return_time()
+            } else {
+                return;
+            };
+        let t = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        // Unit bodies carry the capture marker, collect their position, unit and team.
+        let mut bodies: Vec<(EntityId, (f32, f32), UnitId, TeamId)> = vec![];
+        for entity in world.component_entities::<CaptureMarker>() {
+            let unit = world
+                .component::<components::unit_member::UnitMember>(entity)
+                .map(|v| v.unit());
+            let team = world
+                .component::<components::team_member::TeamMember>(entity)
+                .map(|v| v.team());
+            if let (Some(unit), Some(team)) = (unit, team) {
+                let pose = world_pose(world, entity);
+                bodies.push((entity, (pose.x(), pose.y()), unit, team));
+            }
+        }
+        let within = |a: (f32, f32), b: (f32, f32), radius: f32| {
+            let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+            (dx * dx + dy * dy).sqrt() < radius
+        };
+
+        let flag_entities = world.component_entities::<CarriableFlag>();
+
+        // Drop flags whose carrier was destroyed.
+        for flag_entity in flag_entities.iter() {
+            let state = world
+                .component::<CarriableFlag>(*flag_entity)
+                .unwrap()
+                .state();
+            if let FlagState::Carried { unit, body_entity } = state {
+                let alive = components::unit::get_unit_entity(world, unit)
+                    .map(|e| world.component::<components::health::Health>(e).is_some())
+                    .unwrap_or(false)
+                    && world.component::<Pose>(body_entity).is_some();
+                if !alive {
+                    world
+                        .component_mut::<CarriableFlag>(*flag_entity)
+                        .unwrap()
+                        .drop(t);
+                    if let Some(mut pose) = world.component_mut::<Pose>(*flag_entity) {
+                        pose.transform_mut().w.z = 0.0;
+                    }
+                }
+            }
+        }
+
+        // Pick up, return and capture flags.
+        let mut captures = vec![];
+        for flag_entity in flag_entities.iter() {
+            let mut flag = *world.component::<CarriableFlag>(*flag_entity).unwrap();
+            let flag_pose = world_pose(world, *flag_entity);
+            let flag_position = (flag_pose.x(), flag_pose.y());
+
+            match flag.state() {
+                FlagState::Carried { unit, body_entity } => {
+                    let carrier = bodies.iter().find(|b| b.0 == body_entity && b.2 == unit);
+                    if let Some((_, position, _, team)) = carrier {
+                        // The flag is captured if the carrier reaches its own base while its own
+                        // flag is at that base.
+                        let captured = flag_entities.iter().any(|e| {
+                            let own = world.component::<CarriableFlag>(*e).unwrap();
+                            own.team() == *team
+                                && own.is_at_base()
+                                && within(*position, own.base(), own.radius())
+                        });
+                        if captured {
+                            captures.push(*team);
+                            flag.return_to_base();
+                        }
+                    }
+                }
+                FlagState::AtBase | FlagState::Dropped { .. } => {
+                    let is_dropped = !flag.is_at_base();
+                    for (body_entity, position, unit, team) in bodies.iter() {
+                        if !within(*position, flag_position, flag.radius()) {
+                            continue;
+                        }
+                        if *team == flag.team() {
+                            if is_dropped {
+                                // Touching your own dropped flag returns it.
+                                flag.return_to_base();
+                                break;
+                            }
+                        } else {
+                            // Units can carry only a single flag.
+                            let is_carrying = flag_entities.iter().any(|e| {
+                                world.component::<CarriableFlag>(*e).unwrap().carrier()
+                                    == Some(*unit)
+                            });
+                            if !is_carrying {
+                                flag.pick_up(*unit, *body_entity);
+                                break;
+                            }
+                        }
+                    }
+                    if let (FlagState::Dropped { time }, Some(return_time)) =
+                        (flag.state(), return_time)
+                    {
+                        if t - time >= return_time {
+                            flag.return_to_base();
+                        }
+                    }
+                }
+            }
+
+            // Update the flag's position.
+            let new_position = match flag.state() {
+                FlagState::AtBase => Some((flag.base().0, flag.base().1, 0.0)),
+                FlagState::Carried { body_entity, .. } => {
+                    let body = world_pose(world, body_entity);
+                    Some((body.x(), body.y(), body.z() + CARRIED_FLAG_HEIGHT))
+                }
+                FlagState::Dropped { .. } => None,
+            };
+            if let Some((x, y, z)) = new_position {
+                if let Some(mut pose) = world.component_mut::<Pose>(*flag_entity) {
+                    *pose = Pose::from_xyz(x, y, z);
+                }
+            }
+            *world.component_mut::<CarriableFlag>(*flag_entity).unwrap() = flag;
+        }
+
+        if let Some((_e, mut ctf)) = world.component_iter_mut::<MatchCaptureTheFlag>().next() {
+            for team in captures {
+                ctf.add_capture(team);
+            }
+        }
+    }
+}
@@ -1,10 +1,18 @@
 use crate::components;
 use crate::components::team::TeamId;
+use components::match_attack_defend::MatchAttackDefend;
+use components::match_capture_the_flag::MatchCaptureTheFlag;
 use components::match_domination::MatchDomination;
-use components::match_finished::{MatchConclusion, MatchFinished, MatchReport, ObjectiveReport};
+use components::match_finished::{
+    rank_teams, MatchConclusion, MatchFinished, MatchOutcome, MatchReport, ObjectiveReport,
+};
 use components::match_king_of_the_hill::MatchKingOfTheHill;
+use components::match_payload::MatchPayload;
+use components::match_rules::MatchRules;
+use components::match_scoring::{source_values, MatchScoring};
 use components::match_team_deathmatch::MatchTeamDeathmatch;
 use components::match_time_limit::MatchTimeLimit;
+use components::team::Team;
 
 use engine::prelude::*;
 
@@ -43,6 +51,39 @@ impl System for MatchLogicFinished {
             }
         }
 
+        for (_e, match_capture_the_flag) in world.component_iter::<MatchCaptureTheFlag>() {
+            if match_capture_the_flag.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
+        for (_e, match_attack_defend) in world.component_iter::<MatchAttackDefend>() {
+            if match_attack_defend.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
+        for (_e, match_payload) in world.component_iter::<MatchPayload>() {
+            if match_payload.is_finished() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::Objective);
+                break;
+            }
+        }
+
+        // A failing controller may end the match as no contest.
+        for (_e, match_rules) in world.component_iter::<MatchRules>() {
+            if match_rules.is_no_contest() {
+                is_finished = true;
+                conclusion = Some(MatchConclusion::ControllerFailure);
+                break;
+            }
+        }
+
         // Check time limit criteria.
         for (_e, match_time_limit) in world.component_iter::<MatchTimeLimit>() {
             if match_time_limit.is_finished() {
@@ -60,73 +101,153 @@ impl System for MatchLogicFinished {
                 .1
                 .elapsed_as_f32();
 
-            // collect the reports.
+            // collect the reports, teams that met an objective are winners.
             let mut reports = vec![];
             let mut winners: std::collections::HashSet<TeamId> = Default::default();
-            let mut leaders: std::collections::HashSet<TeamId> = Default::default();
             {
                 for (_e, match_koth) in world.component_iter::<MatchKingOfTheHill>() {
                     let report = match_koth.clone();
-                    if let Some(leader) = report.get_leader() {
-                        if report.is_finished() {
-                            winners.insert(leader);
-                        } else {
-                            leaders.insert(leader);
-                        }
+                    if report.is_finished() {
+                        winners.extend(report.get_leader());
                     }
                     reports.push(ObjectiveReport::MatchKingOfTheHill(report));
                 }
                 for (_e, match_team_deathmatch) in world.component_iter::<MatchTeamDeathmatch>() {
                     let report = match_team_deathmatch.clone();
-                    if let Some(leader) = report.get_leader() {
-                        if report.is_finished() {
-                            winners.insert(leader.0);
-                        } else {
-                            leaders.insert(leader.0);
-                        }
+                    if report.is_finished() {
+                        winners.extend(report.get_leader().map(|v| v.0));
                     }
                     reports.push(ObjectiveReport::MatchTeamDeathmatch(report));
                 }
                 for (_e, match_domination) in world.component_iter::<MatchDomination>() {
                     let report = match_domination.clone();
-                    if let Some(leader) = report.get_leader() {
-                        if report.is_finished() {
-                            winners.insert(leader);
-                        } else {
-                            leaders.insert(leader);
-                        }
+                    if report.is_finished() {
+                        winners.extend(report.get_leader());
                     }
                     reports.push(ObjectiveReport::MatchDomination(report));
                 }
+                for (_e, match_capture_the_flag) in world.component_iter::<MatchCaptureTheFlag>() {
+                    let report = match_capture_the_flag.clone();
+                    if report.is_finished() {
+                        winners.extend(report.get_leader().map(|v| v.0));
+                    }
+                    reports.push(ObjectiveReport::MatchCaptureTheFlag(report));
+                }
+                for (_e, match_attack_defend) in world.component_iter::<MatchAttackDefend>() {
+                    let report = match_attack_defend.clone();
+                    if report.is_finished() {
+                        winners.insert(report.get_leader());
+                    }
+                    reports.push(ObjectiveReport::MatchAttackDefend(report));
+                }
+                for (_e, match_payload) in world.component_iter::<MatchPayload>() {
+                    let report = match_payload.clone();
+                    if report.is_finished() {
+                        winners.insert(report.get_leader());
+                    }
+                    reports.push(ObjectiveReport::MatchPayload(report));
+                }
+                for (_e, match_scoring) in world.component_iter::<MatchScoring>() {
+                    reports.push(ObjectiveReport::MatchScoring(match_scoring.clone()));
+                }
             }
 
-            // We are actually finished... lets collect the information for the match report.
-            if winners.len() > 1 {
-                println!("Got multiple winners: {winners:?}, logic error or draw??");
-            }
+            // Rank all teams, the keys are compared in order; meeting an objective comes first,
+            // then the standing in the objectives, then the configured tie breakers.
+            let mut keys: Vec<(TeamId, Vec<f32>)> = world
+                .component_iter::<Team>()
+                .map(|(_e, team)| (team.id(), vec![]))
+                .collect();
+            let mut add_key = |value: &dyn Fn(TeamId) -> f32| {
+                for (team, key) in keys.iter_mut() {
+                    key.push(value(*team));
+                }
+            };
+            add_key(&|team| winners.contains(&team) as i32 as f32);
 
-            // Lets... get the winner.
-            let mut final_winner: Option<TeamId> = None;
+            if let Some((_e, scoring)) = world.component_iter::<MatchScoring>().next() {
+                // Composite matches are decided by the weighted score.
+                add_key(&|team| scoring.score(team));
+                for source in scoring.tie_breakers() {
+                    add_key(&|team| scoring.value(*source, team));
+                }
+            } else {
+                for (_e, m) in world.component_iter::<MatchAttackDefend>() {
+                    add_key(&|team| (m.get_leader() == team) as i32 as f32);
+                }
+                for (_e, m) in world.component_iter::<MatchPayload>() {
+                    add_key(&|team| (m.get_leader() == team) as i32 as f32);
+                }
+                for (_e, m) in world.component_iter::<MatchKingOfTheHill>() {
+                    let points = m.points();
+                    add_key(&|team| {
+                        points
+                            .iter()
+                            .find(|v| v.0 == team)
+                            .map(|v| v.1)
+                            .unwrap_or(0.0)
+                    });
+                }
+                for (_e, m) in world.component_iter::<MatchTeamDeathmatch>() {
+                    let points = m.points();
+                    add_key(&|team| {
+                        points
+                            .iter()
+                            .find(|v| v.0 == team)
+                            .map(|v| v.1)
+                            .unwrap_or(0) as f32
+                    });
+                }
+                for (_e, m) in world.component_iter::<MatchCaptureTheFlag>() {
+                    let points = m.points();
+                    add_key(&|team| {
+                        points
+                            .iter()
+                            .find(|v| v.0 == team)
+                            .map(|v| v.1)
+                            .unwrap_or(0) as f32
+                    });
+                }
+            }
 
-            // If there is a true winner...
-            if !winners.is_empty() {
-                final_winner = winners.iter().copied().collect::<Vec<_>>().first().copied();
+            let rules = world
+                .component_iter::<MatchRules>()
+                .next()
+                .map(|(_e, rules)| rules.clone())
+                .unwrap_or_default();
+            for source in rules.tie_breakers() {
+                let values = source_values(world, *source);
+                add_key(&|team| {
+                    values
+                        .iter()
+                        .find(|v| v.0 == team)
+                        .map(|v| v.1)
+                        .unwrap_or(0.0)
+                });
             }
+            let ranking = rank_teams(&keys);
 
-            // Check if we don't have a winner, if not, it is decided by time limit, use the runner
-            // up.
-            if final_winner.is_none() {
-                if leaders.len() > 1 {
-                    println!(
-                        "Got multiple runner ups: {leaders:?}... logic error? Draw? Taking first."
-                    );
+            // Determine the outcome.
+            let outcome = if rules.is_no_contest() {
+                MatchOutcome::NoContest(rules.failed_teams().to_vec())
+            } else {
+                match ranking.first().map(|v| &v[..]) {
+                    Some([winner]) => MatchOutcome::Win(*winner),
+                    Some(tied) => MatchOutcome::Draw(tied.to_vec()),
+                    None => MatchOutcome::Draw(vec![]),
                 }
-                final_winner = leaders.iter().copied().collect::<Vec<_>>().first().copied();
-            }
+            };
+            let winner = if let MatchOutcome::Win(winner) = outcome {
+                Some(winner)
+            } else {
+                None
+            };
 
             // Now, we can create the match report.
             let report = MatchReport {
-                winner: final_winner,
+                winner,
+                outcome,
+                ranking,
                 conclusion: conclusion.unwrap(),
                 reports,
                 duration,
@@ -0,0 +1,58 @@
+use crate::components;
+use components::clock::Clock;
+use components::match_payload::MatchPayload;
+use components::payload::Payload;
+use components::pose::Pose;
+
+use engine::prelude::*;
+
+pub struct MatchLogicPayload {}
+impl System for MatchLogicPayload {
+    fn update(&mut self, world: &mut World) {
+        let (attacker, defender) =
+            if let Some((_e, match_payload)) = world.component_iter::<MatchPayload>().next() {
+                (match_payload.attacker(), match_payload.defender())
+            } else {
+                return;
+            };
+        let dt = world
+            .component_iter_mut::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .step_as_f32();
+
+        let mut distance = None;
+        for payload_entity in world.component_entities::<Payload>() {
+            let radius = world.component::<Payload>(payload_entity).unwrap().radius();
+
+            // Count the attackers and defenders near the payload, like capture points do.
+            let counts = super::capture::markers_in_radius(world, payload_entity, radius);
+            let attackers = counts.get(&attacker).copied().unwrap_or(0);
+            let defenders = counts.get(&defender).copied().unwrap_or(0);
+
+            // The payload only moves if attackers are near and it isn't contested by defenders.
+            let mut payload = world.component_mut::<Payload>(payload_entity).unwrap();
+            if attackers > 0 && defenders == 0 {
+                let speed = payload.speed();
+                payload.advance(speed * dt);
+            } else {
+                payload.halt();
+            }
+            let (x, y, yaw) = payload.position();
+            distance = Some(payload.distance());
+            drop(payload);
+
+            if let Some(mut pose) = world.component_mut::<Pose>(payload_entity) {
+                *pose = Pose::from_se2(x, y, yaw);
+            }
+        }
+
+        if let Some(distance) = distance {
+            if let Some((_e, mut match_payload)) = world.component_iter_mut::<MatchPayload>().next()
+            {
+                match_payload.set_distance(distance);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::components;
+use components::match_scoring::{source_values, MatchScoring, ScoreSource};
+use components::team::{Team, TeamId};
+
+use engine::prelude::*;
+
+pub struct MatchLogicScoring {}
+impl System for MatchLogicScoring {
+    fn update(&mut self, world: &mut World) {
+        let sources = if let Some((_e, scoring)) = world.component_iter::<MatchScoring>().next() {
+            scoring.sources()
+        } else {
+            return;
+        };
+
+        let teams: Vec<TeamId> = world
+            .component_iter::<Team>()
+            .map(|(_e, team)| team.id())
+            .collect();
+        let values: Vec<(ScoreSource, Vec<(TeamId, f32)>)> = sources
+            .iter()
+            .map(|source| (*source, source_values(world, *source)))
+            .collect();
+
+        if let Some((_e, mut scoring)) = world.component_iter_mut::<MatchScoring>().next() {
+            scoring.set_teams(&teams);
+            for (source, source_values) in values.iter() {
+                scoring.set_values(*source, source_values);
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use super::components;
+use super::Clock;
+use components::mine::{Mine, MineLayer};
+use engine::prelude::*;
+
+/// Deploys mines from triggered mine layers, mines are placed on the floor below the layer.
+pub struct MineLayerDeploy {}
+impl System for MineLayerDeploy {
+    fn update(&mut self, world: &mut World) {
+        let current = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        for layer_entity in world.component_entities::<MineLayer>() {
+            let config = {
+                let mut layer = world.component_mut::<MineLayer>(layer_entity).unwrap();
+                if !(layer.is_triggered() && layer.is_ready(current)) {
+                    continue;
+                }
+                layer.deployed(current);
+                layer.config()
+            };
+
+            let layer_pose = components::pose::world_pose(world, layer_entity);
+            let team = world
+                .component::<components::team_member::TeamMember>(layer_entity)
+                .map(|v| v.team());
+            let unit_id = world
+                .component::<components::unit_member::UnitMember>(layer_entity)
+                .map(|v| v.unit());
+
+            let mine_entity = world.add_entity();
+            world.add_component(
+                mine_entity,
+                components::pose::Pose::from_xyz(layer_pose.w[0], layer_pose.w[1], 0.0),
+            );
+            world.add_component(
+                mine_entity,
+                Mine::new(team, current + config.arming_delay, config.trigger_radius),
+            );
+            world.add_component(
+                mine_entity,
+                components::damage_splash::DamageSplash::new(config.damage, config.splash_radius),
+            );
+            if let Some(unit_id) = unit_id {
+                world.add_component(
+                    mine_entity,
+                    components::unit_source::UnitSource::new(unit_id),
+                );
+            }
+            if let Some(team) = team {
+                world.add_component(
+                    mine_entity,
+                    components::radar_reflector::RadarReflector {
+                        reflectivity: 0.5,
+                        visible_to: Some(team),
+                    },
+                );
+            }
+            world.add_component(mine_entity, crate::display::mine_model::MineModel::new());
+        }
+    }
+}
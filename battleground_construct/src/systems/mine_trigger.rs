@@ -0,0 +1,86 @@
+use super::components;
+use super::Clock;
+use components::capture_marker::CaptureMarker;
+use components::mine::Mine;
+use components::pose::world_pose;
+use components::team_member::TeamMember;
+use engine::prelude::*;
+
+/// Detonates armed mines when the body of a unit of another team is within the trigger radius.
+/// Detonation adds an Impact to the mine, such that its DamageSplash is processed by the impact
+/// processing.
+pub struct MineTrigger {}
+impl System for MineTrigger {
+    fn update(&mut self, world: &mut World) {
+        let current = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        let mines = world.component_entities::<Mine>();
+        if mines.is_empty() {
+            return;
+        }
+
+        // Unit bodies carry the capture marker.
+        let bodies = world
+            .component_entities::<CaptureMarker>()
+            .iter()
+            .map(|entity| {
+                let pose = world_pose(world, *entity);
+                let team = world.component::<TeamMember>(*entity).map(|v| v.team());
+                (pose.w.truncate(), team)
+            })
+            .collect::<Vec<_>>();
+
+        for mine_entity in mines {
+            let mine = *world.component::<Mine>(mine_entity).unwrap();
+            if !mine.is_armed(current) {
+                continue;
+            }
+            let mine_pose = world_pose(world, mine_entity);
+            let triggered = bodies.iter().any(|(position, team)| {
+                let dx = position.x - mine_pose.w[0];
+                let dy = position.y - mine_pose.w[1];
+                mine.is_triggered_by(*team) && (dx * dx + dy * dy).sqrt() < mine.trigger_radius()
+            });
+            if !triggered {
+                continue;
+            }
+
+            // Explosion effect.
+            let effect_entity = world.add_entity();
+            let effect_id = components::id_generator::generate_id(world);
+            let splash_radius = world
+                .component::<components::damage_splash::DamageSplash>(mine_entity)
+                .map(|v| v.radius())
+                .unwrap_or(1.0);
+            world.add_component(
+                effect_entity,
+                crate::display::particle_emitter::ParticleEmitter::explosion(
+                    effect_id,
+                    splash_radius,
+                ),
+            );
+            world.add_component(effect_entity, mine_pose);
+            world.add_component(
+                effect_entity,
+                crate::components::expiry::Expiry::lifetime(10.0),
+            );
+
+            world.remove_component::<Mine>(mine_entity);
+            world.remove_component::<crate::display::mine_model::MineModel>(mine_entity);
+            world.remove_component::<components::radar_reflector::RadarReflector>(mine_entity);
+
+            let source = world
+                .component::<components::unit_source::UnitSource>(mine_entity)
+                .map(|v| v.source());
+            world.add_component(
+                mine_entity,
+                components::impact::Impact::new(None, *mine_pose.transform(), source),
+            );
+        }
+    }
+}
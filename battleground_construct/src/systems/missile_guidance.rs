@@ -0,0 +1,88 @@
+use super::components::guided_missile::GuidedMissile;
+use super::components::pose::Pose;
+use super::components::radar_reflector::RadarReflector;
+use super::components::unit::UnitId;
+use super::components::unit_member::UnitMember;
+use super::components::unit_source::UnitSource;
+use super::components::velocity::Velocity;
+use super::Clock;
+use crate::components::pose::world_pose;
+use crate::display::particle_emitter::ParticleEmitter;
+use crate::display::primitives::{Mat4, Vec3};
+use crate::util::cgmath::prelude::*;
+use cgmath::InnerSpace;
+use engine::prelude::*;
+
+/// Steers guided missiles towards their target while they have fuel, after that they fall under
+/// gravity. The missile's velocity is expressed in its own frame, which is aligned with the
+/// direction of flight.
+pub struct MissileGuidance {}
+impl System for MissileGuidance {
+    fn update(&mut self, world: &mut World) {
+        let dt = world
+            .component_iter::<Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .step_as_f32();
+
+        let missiles = world.component_entities::<GuidedMissile>();
+        if missiles.is_empty() {
+            return;
+        }
+
+        let mut reflectors: Vec<(Mat4, f32, Option<UnitId>)> = vec![];
+        for (entity, reflector) in world.component_iter::<RadarReflector>() {
+            // Missiles don't belong to a team, so they only see reflectors visible to everyone.
+            if reflector.visible_to().is_some() {
+                continue;
+            }
+            let pose = world_pose(world, entity);
+            reflectors.push((
+                *pose.transform(),
+                reflector.reflectivity(),
+                world.component::<UnitMember>(entity).map(|v| v.unit()),
+            ));
+        }
+
+        let gravity = Vec3::new(0.0, 0.0, -9.81);
+
+        for entity in missiles {
+            let pose = *world_pose(world, entity).transform();
+            let source = world.component::<UnitSource>(entity).map(|v| v.source());
+            let (aligned_pose, speed, burnt_out) = {
+                let mut missile = world.component_mut::<GuidedMissile>(entity).unwrap();
+                let had_fuel = missile.has_fuel();
+                if had_fuel {
+                    // The missile shouldn't home in on the unit that launched it.
+                    let reflectors = reflectors
+                        .iter()
+                        .filter(|v| v.2.is_none() || v.2 != source)
+                        .map(|v| (v.0, v.1))
+                        .collect::<Vec<_>>();
+                    let desired = missile.seek(&pose, &reflectors);
+                    missile.steer(desired, dt);
+                } else {
+                    missile.fall(gravity, dt);
+                }
+                (
+                    missile.aligned_pose(pose.to_translation()),
+                    missile.velocity().magnitude(),
+                    had_fuel && !missile.has_fuel(),
+                )
+            };
+
+            if let Some(mut pose) = world.component_mut::<Pose>(entity) {
+                *pose = aligned_pose.into();
+            }
+            if let Some(mut velocity) = world.component_mut::<Velocity>(entity) {
+                *velocity = Velocity::from_linear(Vec3::new(speed, 0.0, 0.0));
+            }
+            if burnt_out {
+                if let Some(mut emitter) = world.component_mut::<ParticleEmitter>(entity) {
+                    emitter.emitting = false;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::components::missile_launcher::MissileLauncher;
+
+use super::Clock;
+use engine::prelude::*;
+
+pub struct MissileLauncherTrigger {}
+impl System for MissileLauncherTrigger {
+    fn update(&mut self, world: &mut World) {
+        let current = {
+            let (_entity, clock) = world
+                .component_iter_mut::<Clock>()
+                .next()
+                .expect("Should have one clock");
+            clock.elapsed_as_f32()
+        };
+
+        for launcher_entity in world.component_entities::<MissileLauncher>() {
+            let fired = {
+                let mut launcher = world
+                    .component_mut::<MissileLauncher>(launcher_entity)
+                    .unwrap();
+                launcher.update(current);
+                if launcher.is_triggered() && launcher.is_ready() {
+                    launcher.fired(current);
+                    true
+                } else {
+                    false
+                }
+            };
+
+            if !fired {
+                continue;
+            }
+
+            let launch_effect = {
+                world
+                    .component::<MissileLauncher>(launcher_entity)
+                    .unwrap()
+                    .effect()
+            };
+
+            launch_effect(world, launcher_entity);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod acceleration_velocity;
+pub mod atmosphere_drag;
 pub mod cannon_trigger;
 pub mod capture;
 pub mod clock;
@@ -12,11 +13,21 @@ pub mod health_bar_update;
 pub mod health_check;
 pub mod health_tank_body;
 pub mod kinematics_differential_drive;
+pub mod lidar_scan;
+pub mod localization_update;
+pub mod match_logic_attack_defend;
+pub mod match_logic_capture_the_flag;
 pub mod match_logic_domination;
 pub mod match_logic_finished;
 pub mod match_logic_king_of_the_hill;
+pub mod match_logic_payload;
+pub mod match_logic_scoring;
 pub mod match_logic_team_deathmatch;
 pub mod match_logic_time_limit;
+pub mod mine_layer_deploy;
+pub mod mine_trigger;
+pub mod missile_guidance;
+pub mod missile_launcher_trigger;
 pub mod playback;
 pub mod playback_finished;
 pub mod playback_units;
@@ -26,15 +37,18 @@ pub mod projectile_hit;
 pub mod radar_scan;
 pub mod radio_transmission;
 pub mod record;
+pub mod reinforcement_spawn;
 pub mod revolute_pose;
 pub mod revolute_update;
 pub mod revolute_velocity;
+pub mod subsystem_degradation;
 pub mod team_color_body;
 pub mod timed_function;
 pub mod unit_control;
 pub mod unit_controller_error_check;
 pub mod velocity_pose;
 pub mod victory_effect;
+pub mod visibility_update;
 
 use super::components;
 use super::components::clock::Clock;
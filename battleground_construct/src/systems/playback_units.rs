@@ -82,5 +82,19 @@ impl System for PlaybackUnits {
                 world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
             }
         }
+
+        for entity in world.component_entities::<units::payload::UnitPayload>() {
+            let needs_spawn = world
+                .component::<components::recording::PlaybackUnitCreatedMarker>(entity)
+                .is_none();
+
+            if needs_spawn {
+                let unit = *world
+                    .component::<units::payload::UnitPayload>(entity)
+                    .unwrap();
+                units::payload::add_payload_passives(world, &unit);
+                world.add_component(entity, components::recording::PlaybackUnitCreatedMarker);
+            }
+        }
     }
 }
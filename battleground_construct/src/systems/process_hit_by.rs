@@ -36,10 +36,27 @@ impl System for ProcessHitBy {
 
         // Next, we can process the HitBy
         for (ids, hit_by) in hit_entity_and_root.iter().zip(hit_by.iter()) {
-            // let hit_entity = ids.0;
+            let hit_entity = ids.0;
             let root_entity = ids.1;
             let hit_by = hit_by.as_ref().expect("all hits should have hitby now.");
 
+            // Damage any subsystems that are linked to the entity that was hit, splash damage is
+            // delivered to the root, which links to all the unit's subsystems.
+            let subsystem_link = world
+                .component::<components::subsystem::SubsystemLink>(hit_entity)
+                .map(|v| v.clone());
+            if let Some(subsystem_link) = subsystem_link {
+                for (subsystem_entity, damage_ratio) in subsystem_link.subsystems() {
+                    if let Some(mut subsystem) =
+                        world.component_mut::<components::subsystem::Subsystem>(*subsystem_entity)
+                    {
+                        for (damage, _impact) in hit_by.hits() {
+                            subsystem.subtract(damage * damage_ratio);
+                        }
+                    }
+                }
+            }
+
             // Modify the health.
             if let Some(ref mut health) = world.component_mut::<Health>(root_entity) {
                 for (damage, _impact) in hit_by.hits() {
@@ -56,3 +73,34 @@ impl System for ProcessHitBy {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::impact::Impact;
+    use crate::components::subsystem::Subsystem;
+    use crate::units::tank::{spawn_tank, TankSpawnConfig};
+    use cgmath::SquareMatrix;
+
+    #[test]
+    fn test_splash_degrades_subsystems() {
+        let mut world = World::new();
+        crate::config::default::add_components(&mut world);
+        let unit_entity = spawn_tank(&mut world, TankSpawnConfig::default());
+
+        // Splash damage is added to the unit entity by the impact processing.
+        let mut hit_by = HitBy::new();
+        let impact = Impact::new(None, cgmath::Matrix4::identity(), None);
+        hit_by.add_hit(0.2, impact, None, 0.0);
+        world.add_component(unit_entity, hit_by);
+        ProcessHitBy {}.update(&mut world);
+
+        let subsystems = world.component_iter::<Subsystem>().collect::<Vec<_>>();
+        assert_eq!(subsystems.len(), 4);
+        for (_entity, subsystem) in subsystems {
+            assert!(subsystem.integrity() < 1.0);
+        }
+        let health = world.component::<Health>(unit_entity).unwrap().health();
+        assert!(health < 1.0);
+    }
+}
@@ -2,30 +2,41 @@ use super::components::group::Group;
 use super::components::pose::world_pose;
 use super::components::radar::Radar;
 use super::components::radar_reflector::RadarReflector;
+use super::components::team_member::TeamMember;
 use crate::display::primitives::Mat4;
 use engine::prelude::*;
 
 pub struct RadarScan {}
 impl System for RadarScan {
     fn update(&mut self, world: &mut World) {
-        let mut reflectors: Vec<(Mat4, f32, Group)> = vec![];
+        let mut reflectors: Vec<(Mat4, f32, Option<Group>, RadarReflector, EntityId)> = vec![];
         for (entity, reflector) in world.component_iter::<RadarReflector>() {
             let pose = world_pose(world, entity);
             reflectors.push((
                 *pose.transform(),
                 reflector.reflectivity(),
-                world.component::<Group>(entity).unwrap().clone(),
+                world.component::<Group>(entity).map(|v| v.clone()),
+                reflector.clone(),
+                entity,
             ));
         }
 
         for (entity, mut radar) in world.component_iter_mut::<Radar>() {
             let radar_pose = world_pose(world, entity);
-            let reflectors = reflectors
+            let radar_team = world.component::<TeamMember>(entity).map(|v| v.team());
+            let candidates = reflectors
                 .iter()
-                .filter(|v| !v.2.entities().contains(&entity))
-                .map(|v| (v.0, v.1))
+                .filter(|v| {
+                    v.2.as_ref()
+                        .map(|g| !g.entities().contains(&entity))
+                        .unwrap_or(true)
+                })
+                .filter(|v| v.3.is_visible_to(radar_team))
                 .collect::<Vec<_>>();
-            radar.update_reflections(&radar_pose, &reflectors);
+            let poses = candidates.iter().map(|v| (v.0, v.1)).collect::<Vec<_>>();
+            let reflecting = radar.update_reflections(&radar_pose, &poses);
+            // Track which entities were seen, this is used for the team's visibility.
+            radar.set_detected(reflecting.iter().map(|i| candidates[*i].4).collect());
         }
     }
 }
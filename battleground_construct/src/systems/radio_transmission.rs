@@ -1,11 +1,18 @@
 use super::components::clock::Clock;
 use super::components::pose::world_pose;
+use super::components::radio_jammer::RadioJammer;
+use super::components::radio_propagation::RadioPropagation;
 use super::components::radio_receiver::RadioReceiver;
 use super::components::radio_transmitter::RadioTransmitter;
 use crate::util::cgmath::EuclideanNorm;
+use crate::util::cgmath::InvertHomogeneous;
 use crate::util::cgmath::ToTranslation;
 use engine::prelude::*;
 
+// Noise channels, the bearing and the delivery of each message get their own noise.
+const CHANNEL_BEARING: u64 = 1 << 48;
+const CHANNEL_DELIVERY: u64 = 2 << 48;
+
 pub struct RadioTransmission {}
 impl System for RadioTransmission {
     fn update(&mut self, world: &mut World) {
@@ -16,6 +23,13 @@ impl System for RadioTransmission {
             .1
             .elapsed_as_f32();
 
+        let propagation = world
+            .component_iter::<RadioPropagation>()
+            .next()
+            .map(|(_e, v)| v.clone())
+            .unwrap_or_default();
+        let propagation_config = propagation.config();
+
         #[derive(Debug)]
         struct Transmission {
             entity: EntityId,
@@ -44,23 +58,94 @@ impl System for RadioTransmission {
                     });
             }
         }
+
+        // Each channel can only carry so many bytes per step, the remainder is lost.
+        if let Some(bandwidth) = propagation_config.channel_bandwidth {
+            for pending in pending_transmissions.values_mut() {
+                let mut remaining = bandwidth;
+                for transmission in pending.iter_mut() {
+                    transmission.msgs.retain(|msg| {
+                        if msg.len() <= remaining {
+                            remaining -= msg.len();
+                            true
+                        } else {
+                            remaining = 0;
+                            false
+                        }
+                    });
+                }
+            }
+        }
         // println!("Delivering {pending_transmissions:?}");
 
+        let jammers = world
+            .component_iter::<RadioJammer>()
+            .filter(|(_e, jammer)| jammer.is_active())
+            .map(|(entity, jammer)| (world_pose(world, entity).to_translation(), jammer.clone()))
+            .collect::<Vec<_>>();
+
         for (entity, mut receiver) in world.component_iter_mut::<RadioReceiver>() {
-            let receiver_pose = world_pose(world, entity).to_translation();
+            let receiver_transform = *world_pose(world, entity).transform();
+            let receiver_pose = receiver_transform.to_translation();
+            let config = receiver.config();
             if let Some(pending) = pending_transmissions.get(&receiver.channel()) {
-                for transmission in pending.iter() {
+                // The strength of each transmission at this receiver, zero if out of range.
+                let strengths = pending
+                    .iter()
+                    .map(|transmission| {
+                        let distance = (transmission.pos - receiver_pose).euclid_norm();
+                        if transmission.entity == entity
+                            || distance >= transmission.transmit_max_range
+                        {
+                            0.0
+                        } else {
+                            transmission.strength / distance.powi(2)
+                        }
+                    })
+                    .collect::<Vec<f32>>();
+                let total_strength = strengths.iter().sum::<f32>();
+
+                // Noise floor, raised by any jammers nearby.
+                let noise_floor = propagation_config.noise_floor
+                    + jammers
+                        .iter()
+                        .map(|(pos, jammer)| jammer.noise_at((pos - receiver_pose).euclid_norm()))
+                        .sum::<f32>();
+
+                for (transmission, strength) in pending.iter().zip(strengths.iter()) {
                     if transmission.entity == entity {
                         continue; // a receiver attached to this transmitter, lets not deliver echoes.
                     }
                     let distance = (transmission.pos - receiver_pose).euclid_norm();
                     if distance < transmission.transmit_max_range {
-                        // calculate the strength.
-                        let ratio_towards = 1.0 / distance.powi(2);
-                        let total_strength = transmission.strength * ratio_towards;
-                        for payload in transmission.msgs.iter() {
+                        // Concurrent transmissions on this channel interfere with this one.
+                        let interference =
+                            propagation_config.interference * (total_strength - strength);
+                        let probability =
+                            propagation.delivery_probability(*strength, noise_floor + interference);
+                        // Direction finding receivers obtain the yaw towards the transmitter.
+                        let local = (receiver_transform.to_inv_h() * transmission.pos.extend(1.0))
+                            .truncate();
+                        let bearing = local.y.atan2(local.x);
+                        for (i, payload) in transmission.msgs.iter().enumerate() {
+                            let delivery_channel = CHANNEL_DELIVERY
+                                | ((usize::from(transmission.entity) as u64) << 16)
+                                | i as u64;
+                            if !propagation.is_delivered(probability, entity, t, delivery_channel) {
+                                continue;
+                            }
                             // println!("Delivering {transmission:?} to {entity:?}");
-                            receiver.add_message(total_strength, &payload[..]);
+                            let bearing = if config.direction_finding {
+                                let noise = propagation.noise(
+                                    entity,
+                                    t,
+                                    CHANNEL_BEARING | receiver.message_count() as u64,
+                                ) * config.bearing_noise;
+                                Some(wrap_angle(bearing + noise))
+                            } else {
+                                None
+                            };
+                            receiver.add_message(*strength, bearing, &payload[..]);
                         }
                     }
                 }
@@ -68,3 +153,9 @@ impl System for RadioTransmission {
         }
     }
 }
+
+/// Wrap an angle to [-pi, pi].
+fn wrap_angle(angle: f32) -> f32 {
+    let tau = std::f32::consts::PI * 2.0;
+    (angle + std::f32::consts::PI).rem_euclid(tau) - std::f32::consts::PI
+}
@@ -0,0 +1,107 @@
+use super::components;
+use crate::units;
+use components::reinforcements::{ReinforcementJustDestroyed, Reinforcements};
+use components::unit::UnitType;
+use engine::prelude::*;
+
+/// Queues replacements for destroyed units and spawns the reinforcements that are ready.
+pub struct ReinforcementSpawn {}
+impl System for ReinforcementSpawn {
+    fn update(&mut self, world: &mut World) {
+        let t = world
+            .component_iter::<components::clock::Clock>()
+            .next()
+            .expect("Should have one clock")
+            .1
+            .elapsed_as_f32();
+
+        let just_destroyed = world.component_entities::<ReinforcementJustDestroyed>();
+        world.remove_components::<ReinforcementJustDestroyed>(&just_destroyed);
+
+        let reinforcements_entity =
+            if let Some((entity, _)) = world.component_iter::<Reinforcements>().next() {
+                entity
+            } else {
+                return;
+            };
+
+        // Queue a replacement for every destroyed unit that belongs to a team.
+        for entity in just_destroyed {
+            let team = world
+                .component::<components::team_member::TeamMember>(entity)
+                .map(|v| v.team());
+            let unit_type = world
+                .component::<components::unit::Unit>(entity)
+                .map(|v| v.unit_type());
+            if let (Some(team), Some(unit_type)) = (team, unit_type) {
+                world
+                    .component_mut::<Reinforcements>(reinforcements_entity)
+                    .unwrap()
+                    .unit_destroyed(team, unit_type, t);
+            }
+        }
+
+        let ready = world
+            .component_mut::<Reinforcements>(reinforcements_entity)
+            .unwrap()
+            .take_ready(t);
+
+        for reinforcement in ready {
+            let (create_controller, radio_config) = {
+                let reinforcements = world
+                    .component::<Reinforcements>(reinforcements_entity)
+                    .unwrap();
+                (
+                    reinforcements
+                        .controller(reinforcement.team)
+                        .expect("reinforcements only for known teams"),
+                    reinforcements.radio_config(reinforcement.team),
+                )
+            };
+            // A controller that can't be created is a failure of the team's controller, just like
+            // a controller that returns an error during the match.
+            let controller = match create_controller() {
+                Ok(v) => v,
+                Err(e) => {
+                    println!(
+                        "Failed to create controller for reinforcement of {:?}, error: {e:?}",
+                        reinforcement.team
+                    );
+                    if let Some((_e, mut rules)) = world
+                        .component_iter_mut::<components::match_rules::MatchRules>()
+                        .next()
+                    {
+                        rules.add_controller_failure(reinforcement.team);
+                    }
+                    continue;
+                }
+            };
+            let team_member = Some(components::team_member::TeamMember::new(reinforcement.team));
+
+            match reinforcement.unit_type {
+                UnitType::Tank => {
+                    let unit_config = units::tank::TankSpawnConfig {
+                        x: reinforcement.x,
+                        y: reinforcement.y,
+                        yaw: reinforcement.yaw,
+                        controller,
+                        team_member,
+                        radio_config,
+                    };
+                    units::tank::spawn_tank(world, unit_config);
+                }
+                UnitType::Artillery => {
+                    let unit_config = units::artillery::ArtillerySpawnConfig {
+                        x: reinforcement.x,
+                        y: reinforcement.y,
+                        yaw: reinforcement.yaw,
+                        controller,
+                        team_member,
+                        radio_config,
+                    };
+                    units::artillery::spawn_artillery(world, unit_config);
+                }
+            }
+        }
+    }
+}
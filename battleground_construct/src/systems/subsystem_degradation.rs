@@ -0,0 +1,57 @@
+use super::components::cannon::Cannon;
+use super::components::differential_drive_base::DifferentialDriveBase;
+use super::components::gun_battery::GunBattery;
+use super::components::radar::Radar;
+use super::components::revolute::Revolute;
+use super::components::subsystem::{Subsystem, SubsystemKind};
+use engine::prelude::*;
+
+/// Applies the effectiveness of damaged subsystems to the components they degrade.
+pub struct SubsystemDegradation {}
+impl System for SubsystemDegradation {
+    fn update(&mut self, world: &mut World) {
+        for (entity, mut subsystem) in world.component_iter_mut::<Subsystem>() {
+            let ratio = if let Some(ratio) = subsystem.take_effectiveness_ratio() {
+                ratio
+            } else {
+                continue;
+            };
+
+            match subsystem.kind() {
+                SubsystemKind::Tracks => {
+                    if let Some(mut base) = world.component_mut::<DifferentialDriveBase>(entity) {
+                        let (min, max) = base.wheel_velocity_bounds();
+                        base.set_wheel_velocity_bounds(min * ratio, max * ratio);
+                    }
+                }
+                SubsystemKind::Revolute => {
+                    if let Some(mut revolute) = world.component_mut::<Revolute>(entity) {
+                        let (min, max) = revolute.velocity_bounds();
+                        revolute.set_velocity_bounds(min * ratio, max * ratio);
+                        // Ensure the current command respects the reduced bounds.
+                        let velocity_cmd = revolute.velocity_cmd();
+                        revolute.set_velocity_cmd(velocity_cmd);
+                    }
+                }
+                SubsystemKind::Radar => {
+                    if let Some(mut radar) = world.component_mut::<Radar>(entity) {
+                        let range_max = radar.range_max();
+                        radar.set_range_max(range_max * ratio);
+                    }
+                }
+                SubsystemKind::Weapon => {
+                    // Reduced effectiveness means a longer reload.
+                    if let Some(mut cannon) = world.component_mut::<Cannon>(entity) {
+                        let reload_time = cannon.reload_time();
+                        cannon.set_reload_time(reload_time / ratio);
+                    }
+                    if let Some(mut gun_battery) = world.component_mut::<GunBattery>(entity) {
+                        let gun_reload = gun_battery.gun_reload();
+                        let battery_reload = gun_battery.battery_reload();
+                        gun_battery.set_reload(gun_reload / ratio, battery_reload / ratio);
+                    }
+                }
+            }
+        }
+    }
+}
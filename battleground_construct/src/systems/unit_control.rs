@@ -1,3 +1,4 @@
+use super::components::control_recording::ControlRecording;
 use super::components::unit_controller::UnitController;
 use super::Clock;
 use engine::prelude::*;
@@ -38,11 +39,25 @@ impl System for UnitControl {
             interface.get_mut().get_registers(world);
         }
 
+        // If enabled, the register accesses of the controllers are recorded.
+        let logs = world
+            .component_iter::<ControlRecording>()
+            .next()
+            .map(|v| v.1.logs());
+
         // Run all vehicle controls, these ONLY work on interfaces.
         for (entity, mut controller) in world.component_iter_mut::<UnitController>() {
             if let Some(interface) = interface_map.get_mut(&entity) {
                 let control = controller.vehicle_control();
-                match control.update(&mut *interface.get_mut()) {
+                let result = if let Some(logs) = logs.as_ref() {
+                    logs.borrow_mut()
+                        .entry(usize::from(entity))
+                        .or_default()
+                        .record(time, &mut *interface.get_mut(), control)
+                } else {
+                    control.update(&mut *interface.get_mut())
+                };
+                match result {
                     Ok(_) => {}
                     Err(v) => {
                         controller.set_error(v);
@@ -21,6 +21,21 @@ impl System for UnitControllerErrorCheck {
                     controller.error().unwrap()
                 );
             }
+            // The commander entity is never removed, so drop its controller to report the
+            // failure once.
+            world.remove_component::<components::unit_controller::UnitController>(entity);
+            // Record the failure for the match outcome.
+            let team = world
+                .component::<components::team_member::TeamMember>(entity)
+                .map(|v| v.team());
+            if let Some(team) = team {
+                if let Some((_e, mut rules)) = world
+                    .component_iter_mut::<components::match_rules::MatchRules>()
+                    .next()
+                {
+                    rules.add_controller_failure(team);
+                }
+            }
             // Finally, apply the destroy marker.
             world.add_component(entity, components::destroyed::Destroyed::new());
         }
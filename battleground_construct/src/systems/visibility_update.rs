@@ -0,0 +1,172 @@
+use crate::components;
+use crate::display::primitives::Vec3;
+use components::capturable::Capturable;
+use components::capture_marker::CaptureMarker;
+use components::carriable_flag::CarriableFlag;
+use components::payload::Payload;
+use components::pose::world_pose;
+use components::radar::Radar;
+use components::team::{Team, TeamId};
+use components::team_member::TeamMember;
+use components::unit::UnitId;
+use components::unit_member::UnitMember;
+use components::visibility::{Observation, Visibility};
+
+use crate::util::cgmath::prelude::*;
+use crate::util::cgmath::ToTranslation;
+use engine::prelude::*;
+
+pub struct VisibilityUpdate {}
+impl System for VisibilityUpdate {
+    fn update(&mut self, world: &mut World) {
+        let proximity_range =
+            if let Some((_e, visibility)) = world.component_iter::<Visibility>().next() {
+                visibility.proximity_range()
+            } else {
+                return;
+            };
+
+        // The bodies carry the proximity sensor, and are what other teams can observe.
+        let mut bodies: Vec<(Vec3, UnitId, TeamId)> = vec![];
+        for (entity, _marker) in world.component_iter::<CaptureMarker>() {
+            let unit = world.component::<UnitMember>(entity).map(|v| v.unit());
+            let team = world.component::<TeamMember>(entity).map(|v| v.team());
+            if let (Some(unit), Some(team)) = (unit, team) {
+                bodies.push((world_pose(world, entity).to_translation(), unit, team));
+            }
+        }
+
+        // Objectives, with the team that always knows about them.
+        let mut objectives: Vec<(EntityId, Vec3, Option<TeamId>)> = vec![];
+        for (entity, capturable) in world.component_iter::<Capturable>() {
+            let pos = world_pose(world, entity).to_translation();
+            objectives.push((entity, pos, capturable.owner()));
+        }
+        for (entity, flag) in world.component_iter::<CarriableFlag>() {
+            let pos = world_pose(world, entity).to_translation();
+            objectives.push((entity, pos, Some(flag.team())));
+        }
+        for (entity, _payload) in world.component_iter::<Payload>() {
+            let pos = world_pose(world, entity).to_translation();
+            objectives.push((entity, pos, None));
+        }
+
+        let teams: Vec<TeamId> = world
+            .component_iter::<Team>()
+            .map(|(_e, team)| team.id())
+            .collect();
+
+        let within_proximity = |team: TeamId, pos: Vec3| {
+            bodies
+                .iter()
+                .filter(|(_, _, t)| *t == team)
+                .any(|(p, _, _)| (p - pos).euclid_norm() <= proximity_range)
+        };
+
+        let mut observations = vec![];
+        for team in teams {
+            let mut observation = Observation::default();
+
+            // Units of the own team, or within proximity of an own body.
+            for (pos, unit, unit_team) in bodies.iter() {
+                if *unit_team == team || within_proximity(team, *pos) {
+                    observation.units.push(*unit);
+                }
+            }
+
+            // Anything the radars of this team detect.
+            for (entity, radar) in world.component_iter::<Radar>() {
+                if world.component::<TeamMember>(entity).map(|v| v.team()) != Some(team) {
+                    continue;
+                }
+                for detected in radar.detected() {
+                    if let Some(unit) = world.component::<UnitMember>(*detected) {
+                        observation.units.push(unit.unit());
+                    }
+                    if objectives.iter().any(|v| v.0 == *detected) {
+                        observation.entities.push(*detected);
+                    }
+                }
+            }
+
+            for (entity, pos, known_by) in objectives.iter() {
+                if *known_by == Some(team) || within_proximity(team, *pos) {
+                    observation.entities.push(*entity);
+                }
+            }
+
+            observation.units.sort();
+            observation.units.dedup();
+            observation.entities.sort();
+            observation.entities.dedup();
+            observations.push((team, observation));
+        }
+
+        if let Some((_e, mut visibility)) = world.component_iter_mut::<Visibility>().next() {
+            for (team, observation) in observations {
+                visibility.set_observation(team, observation);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::capturable::CaptureType;
+    use crate::components::pose::Pose;
+    use crate::components::team::make_team_id;
+    use crate::components::unit::make_unit_id;
+    use crate::components::visibility::VisibilityConfig;
+    use crate::display::Color;
+
+    #[test]
+    fn test_visibility_update() {
+        let mut world = World::new();
+        let t1 = make_team_id(1);
+        let t2 = make_team_id(2);
+        let u1 = make_unit_id(1);
+        let u2 = make_unit_id(2);
+        for (id, name) in [(1, "red"), (2, "blue")] {
+            let e = world.add_entity();
+            world.add_component(e, Team::new(id, name, Color::rgb(0, 0, 0)));
+        }
+        let mut body = |unit, team, x| {
+            let e = world.add_entity();
+            world.add_component(e, CaptureMarker::new());
+            world.add_component(e, UnitMember::new(unit));
+            world.add_component(e, TeamMember::new(team));
+            world.add_component(e, Pose::from_xyz(x, 0.0, 0.0));
+            e
+        };
+        body(u1, t1, 0.0);
+        let body_2 = body(u2, t2, 20.0);
+        let point = world.add_entity();
+        world.add_component(
+            point,
+            Capturable::new(Some(t1), 1.0, CaptureType::Domination),
+        );
+        world.add_component(point, Pose::from_xyz(0.0, 0.0, 0.0));
+        let e = world.add_entity();
+        world.add_component(e, Visibility::new(VisibilityConfig::default()));
+
+        // Far apart, each team only sees its own unit and the owned capturable.
+        VisibilityUpdate {}.update(&mut world);
+        {
+            let (_e, visibility) = world.component_iter::<Visibility>().next().unwrap();
+            assert!(visibility.can_see_unit(t1, u1));
+            assert!(!visibility.can_see_unit(t1, u2));
+            assert!(!visibility.can_see_unit(t2, u1));
+            assert!(visibility.can_see_entity(t1, point));
+            assert!(!visibility.can_see_entity(t2, point));
+        }
+
+        // Within proximity range, everything is observed by both.
+        world.add_component(body_2, Pose::from_xyz(3.0, 0.0, 0.0));
+        VisibilityUpdate {}.update(&mut world);
+        let (_e, visibility) = world.component_iter::<Visibility>().next().unwrap();
+        assert!(visibility.can_see_unit(t1, u2));
+        assert!(visibility.can_see_unit(t2, u1));
+        assert!(visibility.can_see_entity(t2, point));
+    }
+}
@@ -91,6 +91,7 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
                 - RadarReflector
                 - CaptureMarker
                 - Radio's
+                - MineLayer
             -> Flag entity
             -> Health bar entity
             -> Turret Entity
@@ -142,7 +143,7 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
     let register_interface = components::unit_interface::RegisterInterfaceContainer::new(
         components::unit_interface::RegisterInterface::new(),
     );
-    super::common::add_common_global(&register_interface);
+    super::common::add_common_global(&register_interface, unit_entity);
 
     let unit_id = super::common::add_common_unit(
         world,
@@ -187,6 +188,8 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
         config.radio_config,
     );
     super::common::add_common_body(world, &register_interface, body_entity);
+    super::common::add_damage(&register_interface, unit_entity, body_entity);
+    super::common::add_mine_layer(world, &register_interface, body_entity, Default::default());
 
     // -----   Turret
     let revolute_config = components::revolute::RevoluteConfig {
@@ -305,6 +308,49 @@ pub fn spawn_artillery(world: &mut World, config: ArtillerySpawnConfig) -> Entit
         radar_config,
     );
 
+    // -----   Lidar
+    // The lidar is fixed to the turret, looking forward.
+    super::common::add_lidar(
+        world,
+        &register_interface,
+        turret_entity,
+        "lidar",
+        MODULE_ARTILLERY_LIDAR,
+        components::lidar::LidarConfig::default(),
+    );
+
+    // -----   Subsystems
+    // The turret has no hit volume, hits on the barrel damage both the gun battery and the turret.
+    // The radar sits on top of the body and takes part of the body's damage.
+    use super::common::{SUBSYSTEM_DAMAGE_RATIO, SUBSYSTEM_SPLASH_DAMAGE_RATIO};
+    use components::subsystem::{Subsystem, SubsystemKind, SubsystemLink};
+    world.add_component(base_entity, Subsystem::new(SubsystemKind::Tracks));
+    world.add_component(turret_entity, Subsystem::new(SubsystemKind::Revolute));
+    world.add_component(radar_entity, Subsystem::new(SubsystemKind::Radar));
+    world.add_component(muzzle_entity, Subsystem::new(SubsystemKind::Weapon));
+    for track_entity in [front_track_entity, rear_track_entity] {
+        world.add_component(
+            track_entity,
+            SubsystemLink::new().with_subsystem(base_entity, SUBSYSTEM_DAMAGE_RATIO),
+        );
+    }
+    world.add_component(
+        barrel_entity,
+        SubsystemLink::new()
+            .with_subsystem(muzzle_entity, SUBSYSTEM_DAMAGE_RATIO)
+            .with_subsystem(turret_entity, SUBSYSTEM_DAMAGE_RATIO * 0.5),
+    );
+    world.add_component(
+        body_entity,
+        SubsystemLink::new().with_subsystem(radar_entity, SUBSYSTEM_DAMAGE_RATIO * 0.5),
+    );
+    // Splash damage is delivered to the unit entity, it degrades all subsystems.
+    let mut splash_link = SubsystemLink::new();
+    for subsystem_entity in [base_entity, turret_entity, radar_entity, muzzle_entity] {
+        splash_link = splash_link.with_subsystem(subsystem_entity, SUBSYSTEM_SPLASH_DAMAGE_RATIO);
+    }
+    world.add_component(unit_entity, splash_link);
+
     // -----   Control
     world.add_component(control_entity, display::draw_module::DrawComponent::new());
     register_interface.get_mut().add_module(
@@ -14,6 +14,9 @@ pub struct CapturableFlagConfig {
     pub initial_owner: Option<components::team::TeamId>,
     pub capture_strength: f32,
     pub capture_type: components::capturable::CaptureType,
+    /// If set, the flag belongs to this team and is carried off by units of other teams instead
+    /// of being captured in place, the radius is then the pick up and capture radius.
+    pub carriable: Option<components::team::TeamId>,
 }
 
 impl Default for CapturableFlagConfig {
@@ -27,6 +30,7 @@ impl Default for CapturableFlagConfig {
             capture_strength: 0.5,
             initial_owner: None,
             capture_type: components::capturable::CaptureType::Exclusive,
+            carriable: None,
         }
     }
 }
@@ -34,6 +38,8 @@ impl Default for CapturableFlagConfig {
 #[derive(Deserialize, Serialize, Debug, Clone, Copy)]
 pub struct UnitCapturableFlag {
     pub capturable_entity: EntityId,
+    /// The base a carriable flag is returned to, it stays behind when the flag is carried off.
+    pub base_entity: Option<EntityId>,
 }
 impl Component for UnitCapturableFlag {}
 
@@ -45,10 +51,27 @@ pub fn spawn_capturable_flag(world: &mut World, config: CapturableFlagConfig) ->
         Pose::from_se2(config.x, config.y, config.yaw),
     );
 
-    let unit_capturable = UnitCapturableFlag { capturable_entity };
+    let base_entity = config.carriable.map(|_| {
+        let base_entity = world.add_entity();
+        world.add_component(base_entity, Pose::from_xyz(config.x, config.y, 0.0));
+        base_entity
+    });
+
+    let unit_capturable = UnitCapturableFlag {
+        capturable_entity,
+        base_entity,
+    };
     add_capturable_passives(world, &unit_capturable);
     world.add_component(capturable_entity, unit_capturable);
 
+    if let Some(team) = config.carriable {
+        world.add_component(
+            capturable_entity,
+            components::carriable_flag::CarriableFlag::new(team, config.x, config.y, config.radius),
+        );
+        return capturable_entity;
+    }
+
     world.add_component(
         capturable_entity,
         components::capture_point::CapturePoint::new(config.radius, config.capture_speed),
@@ -66,11 +89,17 @@ pub fn spawn_capturable_flag(world: &mut World, config: CapturableFlagConfig) ->
 
 pub fn add_capturable_passives(world: &mut World, capturable: &UnitCapturableFlag) {
     let mut flag = display::flag::Flag::new();
-    flag.set_pole_height(2.0);
+    flag.set_pole_height(if capturable.base_entity.is_some() {
+        1.5
+    } else {
+        2.0
+    });
     world.add_component(capturable.capturable_entity, flag);
 
     world.add_component(
-        capturable.capturable_entity,
+        capturable
+            .base_entity
+            .unwrap_or(capturable.capturable_entity),
         display::display_control_point::DisplayControlPoint::new(),
     );
 }
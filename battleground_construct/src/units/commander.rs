@@ -0,0 +1,56 @@
+use crate::components;
+use components::team::TeamId;
+use engine::prelude::*;
+
+pub struct CommanderSpawnConfig {
+    pub team: TeamId,
+    pub controller: Box<dyn battleground_unit_control::UnitControl>,
+}
+
+/// Spawn a team commander, returning its entity.
+pub fn spawn_commander(world: &mut World, config: CommanderSpawnConfig) -> EntityId {
+    /*
+        The commander is a single entity, it is not a unit and doesn't exist in the world:
+            - Commander
+            - TeamMember
+            - RegisterInterfaceContainer
+            - UnitController
+    */
+    let commander_entity = world.add_entity();
+    world.add_component(
+        commander_entity,
+        components::commander::Commander::new(config.team),
+    );
+    world.add_component(
+        commander_entity,
+        components::team_member::TeamMember::new(config.team),
+    );
+
+    let register_interface = components::unit_interface::RegisterInterfaceContainer::new(
+        components::unit_interface::RegisterInterface::new(),
+    );
+    register_interface.get_mut().add_module(
+        "clock",
+        battleground_unit_control::units::common::MODULE_CLOCK,
+        components::clock::ClockModule::new(),
+    );
+    register_interface.get_mut().add_module(
+        "team",
+        battleground_unit_control::units::common::MODULE_TEAM,
+        components::team_module::TeamModule::new(commander_entity),
+    );
+    register_interface.get_mut().add_module(
+        "commander",
+        battleground_unit_control::units::commander::MODULE_COMMANDER,
+        components::commander::CommanderModule::new(commander_entity),
+    );
+    world.add_component(commander_entity, register_interface);
+
+    let rc = components::unit_controller::UnitControlStorage::new(config.controller);
+    world.add_component(
+        commander_entity,
+        components::unit_controller::UnitController::new(rc),
+    );
+
+    commander_entity
+}
@@ -6,14 +6,30 @@ use components::unit_interface::RegisterInterfaceContainer;
 use engine::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Ratio by which a hit's damage is scaled before it is applied to the subsystem that was hit.
+pub const SUBSYSTEM_DAMAGE_RATIO: f32 = 1.5;
+
+/// Ratio by which splash damage is scaled before it is applied to each of the unit's subsystems,
+/// splash is delivered to the unit entity instead of the part that was hit.
+pub const SUBSYSTEM_SPLASH_DAMAGE_RATIO: f32 = 0.5;
+
 /// Radio config, for both transmitter and receiver.
 #[derive(Serialize, Deserialize, Debug, Default, Clone, Copy)]
 pub struct RadioConfig {
     pub channel_min: usize,
     pub channel_max: usize,
+    /// Whether the receiver reports the bearing towards the transmitter of each message.
+    #[serde(default)]
+    pub direction_finding: bool,
+    /// Noise on the bearing, radians.
+    #[serde(default)]
+    pub bearing_noise: f32,
+    /// Jammer carried by the unit, none if not specified.
+    #[serde(default)]
+    pub jammer: Option<components::radio_jammer::RadioJammerConfig>,
 }
 
-pub fn add_common_global(register_interface: &RegisterInterfaceContainer) {
+pub fn add_common_global(register_interface: &RegisterInterfaceContainer, unit_entity: EntityId) {
     // -----    Global modules
     register_interface.get_mut().add_module(
         "clock",
@@ -23,7 +39,12 @@ pub fn add_common_global(register_interface: &RegisterInterfaceContainer) {
     register_interface.get_mut().add_module(
         "objectives",
         common::MODULE_OBJECTIVES,
-        components::objectives_module::ObjectivesModule::new(),
+        components::objectives_module::ObjectivesModule::new(unit_entity),
+    );
+    register_interface.get_mut().add_module(
+        "wind",
+        common::MODULE_WIND,
+        components::atmosphere::WindModule::new(),
     );
 }
 
@@ -53,6 +74,13 @@ pub fn add_common_unit(
         components::unit::UnitModuleComponent::new(unit_entity),
     );
 
+    world.add_component(unit_entity, components::orders::Orders::new());
+    register_interface.get_mut().add_module(
+        "orders",
+        common::MODULE_ORDERS,
+        components::orders::OrdersModule::new(unit_entity),
+    );
+
     unit_id
 }
 
@@ -73,6 +101,20 @@ pub fn add_common_diff_drive(
         module_id,
         components::differential_drive_base::DifferentialDriveBaseModule::new(base_entity),
     );
+
+    // Odometry and imu measure the velocity of the base.
+    world.add_component(base_entity, components::odometry::Odometry::new());
+    register_interface.get_mut().add_module(
+        "odometry",
+        common::MODULE_ODOMETRY,
+        components::odometry::OdometryModule::new(base_entity),
+    );
+    world.add_component(base_entity, components::imu::Imu::new());
+    register_interface.get_mut().add_module(
+        "imu",
+        common::MODULE_IMU,
+        components::imu::ImuModule::new(base_entity),
+    );
 }
 
 pub fn add_common_body(
@@ -92,6 +134,7 @@ pub fn add_common_body(
     );
 
     // Lets place drawing and gps in the base as well.
+    world.add_component(body_entity, components::gps::Gps::new());
     register_interface.get_mut().add_module(
         "localization",
         common::MODULE_GPS,
@@ -99,6 +142,32 @@ pub fn add_common_body(
     );
 }
 
+pub fn add_damage(
+    register_interface: &RegisterInterfaceContainer,
+    unit_entity: EntityId,
+    body_entity: EntityId,
+) {
+    register_interface.get_mut().add_module(
+        "damage",
+        common::MODULE_DAMAGE,
+        components::hit_by::DamageModule::new(unit_entity, body_entity),
+    );
+}
+
+pub fn add_mine_layer(
+    world: &mut World,
+    register_interface: &RegisterInterfaceContainer,
+    body_entity: EntityId,
+    config: components::mine::MineLayerConfig,
+) {
+    world.add_component(body_entity, components::mine::MineLayer::new(config));
+    register_interface.get_mut().add_module(
+        "mine_layer",
+        common::MODULE_MINE_LAYER,
+        components::mine::MineLayerModule::new(body_entity),
+    );
+}
+
 pub fn add_radio_receiver_transmitter(
     world: &mut World,
     register_interface: &RegisterInterfaceContainer,
@@ -127,6 +196,8 @@ pub fn add_radio_receiver_transmitter(
         .map(|v| components::radio_receiver::RadioReceiverConfig {
             channel_min: v.channel_min,
             channel_max: v.channel_max,
+            direction_finding: v.direction_finding,
+            bearing_noise: v.bearing_noise,
             ..Default::default()
         })
         .unwrap_or_default();
@@ -139,6 +210,18 @@ pub fn add_radio_receiver_transmitter(
         common::MODULE_RADIO_RECEIVER,
         components::radio_receiver::RadioReceiverModule::new(body_entity),
     );
+
+    if let Some(jammer_config) = radio_config.and_then(|v| v.jammer) {
+        world.add_component(
+            body_entity,
+            components::radio_jammer::RadioJammer::new_with_config(jammer_config),
+        );
+        register_interface.get_mut().add_module(
+            "radio_jammer",
+            common::MODULE_RADIO_JAMMER,
+            components::radio_jammer::RadioJammerModule::new(body_entity),
+        );
+    }
 }
 
 pub fn add_revolute(
@@ -178,3 +261,22 @@ pub fn add_radar(
         components::radar::Radar::new_with_config(radar_config),
     );
 }
+
+pub fn add_lidar(
+    world: &mut World,
+    register_interface: &RegisterInterfaceContainer,
+    lidar_entity: EntityId,
+    name: &str,
+    module_id: u32,
+    lidar_config: components::lidar::LidarConfig,
+) {
+    register_interface.get_mut().add_module(
+        name,
+        module_id,
+        components::lidar::LidarModule::new(lidar_entity),
+    );
+    world.add_component(
+        lidar_entity,
+        components::lidar::Lidar::new_with_config(lidar_config),
+    );
+}
@@ -1,6 +1,8 @@
 pub mod artillery;
 pub mod capturable_flag;
+pub mod commander;
 pub mod common;
+pub mod payload;
 pub mod tank;
 
 use engine::prelude::*;
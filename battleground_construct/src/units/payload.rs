@@ -0,0 +1,45 @@
+use crate::components;
+use crate::display;
+use components::pose::Pose;
+use engine::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug)]
+pub struct PayloadConfig {
+    pub path: Vec<(f32, f32)>,
+    pub radius: f32,
+    pub speed: f32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct UnitPayload {
+    pub payload_entity: EntityId,
+}
+impl Component for UnitPayload {}
+
+/// Spawn a payload at the start of its path, returning the payload entity.
+pub fn spawn_payload(world: &mut World, config: PayloadConfig) -> EntityId {
+    let payload_entity = world.add_entity();
+
+    let payload = components::payload::Payload::new(&config.path, config.radius, config.speed);
+    let (x, y, yaw) = payload.position();
+    world.add_component(payload_entity, Pose::from_se2(x, y, yaw));
+
+    let unit_payload = UnitPayload { payload_entity };
+    add_payload_passives(world, &unit_payload);
+    world.add_component(payload_entity, unit_payload);
+    world.add_component(payload_entity, payload);
+
+    payload_entity
+}
+
+pub fn add_payload_passives(world: &mut World, payload: &UnitPayload) {
+    let mut body = display::artillery_body::ArtilleryBody::new();
+    body.set_color(display::Color::rgb(128, 128, 128));
+    world.add_component(payload.payload_entity, body);
+
+    world.add_component(
+        payload.payload_entity,
+        display::display_control_point::DisplayControlPoint::new(),
+    );
+}
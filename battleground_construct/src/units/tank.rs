@@ -44,6 +44,7 @@ pub struct UnitTank {
     pub flag_entity: EntityId,
     pub barrel_entity: EntityId,
     pub muzzle_entity: EntityId,
+    pub missile_launcher_entity: EntityId,
 }
 impl Component for UnitTank {}
 
@@ -59,6 +60,7 @@ impl Unit for UnitTank {
             self.flag_entity,
             self.barrel_entity,
             self.muzzle_entity,
+            self.missile_launcher_entity,
         ]
     }
 }
@@ -82,6 +84,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
                 - RadarReflector
                 - CaptureMarker
                 - Radio's
+                - MineLayer
             -> Flag entity
             -> Health Bar entity
             -> Turret Entity
@@ -89,6 +92,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
                 -> Barrel Entity
                     -> Nozzle Entity
                 -> Radar entity
+                -> Missile launcher entity
 
         The Unit and Control entities are 'free'.
         Base to Barrel forms a chain of Parent, all entities are part of the group.
@@ -104,6 +108,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
     let health_bar_entity = world.add_entity();
     let barrel_entity = world.add_entity();
     let muzzle_entity = world.add_entity();
+    let missile_launcher_entity = world.add_entity();
 
     let unit_tank = UnitTank {
         unit_entity,
@@ -116,6 +121,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         health_bar_entity,
         barrel_entity,
         muzzle_entity,
+        missile_launcher_entity,
     };
     // Unit must be first in the group!
     let mut tank_group_entities: Vec<EntityId> = vec![unit_entity];
@@ -125,7 +131,7 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
     let register_interface = components::unit_interface::RegisterInterfaceContainer::new(
         components::unit_interface::RegisterInterface::new(),
     );
-    super::common::add_common_global(&register_interface);
+    super::common::add_common_global(&register_interface, unit_entity);
 
     world.add_component(unit_entity, unit_tank);
 
@@ -167,6 +173,8 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         config.radio_config,
     );
     super::common::add_common_body(world, &register_interface, body_entity);
+    super::common::add_damage(&register_interface, unit_entity, body_entity);
+    super::common::add_mine_layer(world, &register_interface, body_entity, Default::default());
 
     // -----   Turret
     let revolute_config = components::revolute::RevoluteConfig {
@@ -233,6 +241,35 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         components::cannon::CannonModule::new(muzzle_entity),
     );
 
+    // -----   Missile launcher
+    world.add_component(missile_launcher_entity, Parent::new(turret_entity));
+    world.add_component(
+        missile_launcher_entity,
+        PreTransform::from_mat4(
+            cgmath::Matrix4::<f32>::from_translation(Vec3::new(
+                0.0,
+                TANK_DIM_TURRET_TO_MISSILE_LAUNCHER_Y,
+                0.0,
+            )) * cgmath::Matrix4::<f32>::from_angle_y(cgmath::Rad(
+                -TANK_DIM_MISSILE_LAUNCHER_PITCH,
+            )),
+        ),
+    );
+    let missile_launcher_config = components::missile_launcher::MissileLauncherConfig {
+        reload_time: 10.0,
+        missile: Default::default(),
+        launch_effect: std::rc::Rc::new(missile_function),
+    };
+    world.add_component(
+        missile_launcher_entity,
+        components::missile_launcher::MissileLauncher::new(missile_launcher_config),
+    );
+    register_interface.get_mut().add_module(
+        "missile_launcher",
+        MODULE_TANK_MISSILE_LAUNCHER,
+        components::missile_launcher::MissileLauncherModule::new(missile_launcher_entity),
+    );
+
     // -----   Radar
     world.add_component(radar_entity, Parent::new(turret_entity));
 
@@ -274,6 +311,47 @@ pub fn spawn_tank(world: &mut World, config: TankSpawnConfig) -> EntityId {
         radar_config,
     );
 
+    // -----   Lidar
+    // The lidar is fixed to the turret, looking forward.
+    super::common::add_lidar(
+        world,
+        &register_interface,
+        turret_entity,
+        "lidar",
+        MODULE_TANK_LIDAR,
+        components::lidar::LidarConfig::default(),
+    );
+
+    // -----   Subsystems
+    // Hits on the tracks, turret or barrel degrade the respective subsystem, the radar sits on the
+    // turret and takes part of the turret's damage.
+    use super::common::{SUBSYSTEM_DAMAGE_RATIO, SUBSYSTEM_SPLASH_DAMAGE_RATIO};
+    use components::subsystem::{Subsystem, SubsystemKind, SubsystemLink};
+    world.add_component(base_entity, Subsystem::new(SubsystemKind::Tracks));
+    world.add_component(turret_entity, Subsystem::new(SubsystemKind::Revolute));
+    world.add_component(radar_entity, Subsystem::new(SubsystemKind::Radar));
+    world.add_component(muzzle_entity, Subsystem::new(SubsystemKind::Weapon));
+    world.add_component(
+        base_entity,
+        SubsystemLink::new().with_subsystem(base_entity, SUBSYSTEM_DAMAGE_RATIO),
+    );
+    world.add_component(
+        turret_entity,
+        SubsystemLink::new()
+            .with_subsystem(turret_entity, SUBSYSTEM_DAMAGE_RATIO)
+            .with_subsystem(radar_entity, SUBSYSTEM_DAMAGE_RATIO * 0.5),
+    );
+    world.add_component(
+        barrel_entity,
+        SubsystemLink::new().with_subsystem(muzzle_entity, SUBSYSTEM_DAMAGE_RATIO),
+    );
+    // Splash damage is delivered to the unit entity, it degrades all subsystems.
+    let mut splash_link = SubsystemLink::new();
+    for subsystem_entity in [base_entity, turret_entity, radar_entity, muzzle_entity] {
+        splash_link = splash_link.with_subsystem(subsystem_entity, SUBSYSTEM_SPLASH_DAMAGE_RATIO);
+    }
+    world.add_component(unit_entity, splash_link);
+
     // -----   Control
     world.add_component(control_entity, display::draw_module::DrawComponent::new());
     register_interface.get_mut().add_module(
@@ -516,3 +594,99 @@ fn cannon_hit_effect(
         crate::components::expiry::Expiry::lifetime(5.0),
     );
 }
+
+pub fn missile_function(world: &mut World, launcher_entity: EntityId) {
+    use crate::components::guided_missile::GuidedMissile;
+    use crate::components::point_projectile::PointProjectile;
+    use crate::components::unit_source::UnitSource;
+    use crate::components::velocity::Velocity;
+
+    let launcher_pose = components::pose::world_pose(world, launcher_entity);
+    let (missile_config, seeker) = {
+        let launcher = world
+            .component::<components::missile_launcher::MissileLauncher>(launcher_entity)
+            .unwrap();
+        (launcher.missile_config(), launcher.seeker())
+    };
+
+    // The missile starts in the direction of the launcher, the guidance system takes care of the
+    // orientation from here on.
+    let mut direction = *launcher_pose.transform();
+    direction.w = cgmath::Vector4::<f32>::new(0.0, 0.0, 0.0, 1.0);
+    let direction = (direction * cgmath::Vector4::<f32>::new(1.0, 0.0, 0.0, 0.0)).truncate();
+    let missile = GuidedMissile::new(missile_config, seeker, direction);
+
+    let projectile_entity = world.add_entity();
+    world.add_component::<PointProjectile>(projectile_entity, PointProjectile::new());
+    let unit_id = world
+        .component::<components::unit_member::UnitMember>(launcher_entity)
+        .map(|v| v.unit());
+    if let Some(unit_member) = unit_id {
+        world.add_component(projectile_entity, UnitSource::new(unit_member));
+    }
+    world.add_component::<Pose>(
+        projectile_entity,
+        missile.aligned_pose(launcher_pose.w.truncate()).into(),
+    );
+    world.add_component::<Velocity>(
+        projectile_entity,
+        Velocity::from_linear(Vec3::new(missile_config.speed, 0.0, 0.0)),
+    );
+    world.add_component(projectile_entity, missile);
+    world.add_component(projectile_entity, crate::display::missile::Missile::new());
+
+    world.add_component(
+        projectile_entity,
+        components::damage_hit::DamageHit::new(0.4),
+    );
+
+    world.add_component(
+        projectile_entity,
+        components::hit_effect::HitEffect::new(std::rc::Rc::new(missile_hit_effect)),
+    );
+
+    let effect_id = components::id_generator::generate_id(world);
+    world.add_component(
+        projectile_entity,
+        crate::display::particle_emitter::ParticleEmitter::bullet_trail(
+            effect_id,
+            0.04,
+            crate::display::Color::rgb(255, 160, 60),
+        ),
+    );
+}
+
+fn missile_hit_effect(
+    world: &mut World,
+    projectile: EntityId,
+    _impact: &components::impact::Impact,
+) {
+    let projectile_destructor = world.add_entity();
+    let effect_id = components::id_generator::generate_id(world);
+    let world_pose = crate::components::pose::world_pose(world, projectile);
+    world.add_component(
+        projectile_destructor,
+        crate::display::particle_emitter::ParticleEmitter::explosion(effect_id, 0.3),
+    );
+    world.add_component(projectile_destructor, world_pose);
+    world.add_component(
+        projectile_destructor,
+        crate::components::expiry::Expiry::lifetime(10.0),
+    );
+    // Now, we can remove the displayable mesh.
+    world.remove_component::<display::missile::Missile>(projectile);
+
+    // Copy the trail to a new entity, such that it can fade out.
+    let emitter_entity = world.add_entity();
+    let emitter =
+        world.remove_component::<crate::display::particle_emitter::ParticleEmitter>(projectile);
+    if let Some(mut emitter) = emitter {
+        emitter.emitting = false;
+        world.add_component_boxed(emitter_entity, emitter);
+    }
+
+    world.add_component(
+        emitter_entity,
+        crate::components::expiry::Expiry::lifetime(5.0),
+    );
+}
@@ -1,5 +1,6 @@
 pub mod box_collision;
 pub mod cgmath;
+pub mod noise;
 
 #[cfg(test)]
 pub mod test_util;
@@ -0,0 +1,20 @@
+//! Deterministic noise, the construct must be reproducible so no actual randomness is used.
+
+/// Noise in [-1.0, 1.0], the same inputs always produce the same value. The key can be used to
+/// distinguish entities, the channel to distinguish quantities.
+pub fn noise(seed: u64, key: u64, time: f32, channel: u64) -> f32 {
+    let mut v = splitmix64(seed);
+    v = splitmix64(v ^ key);
+    v = splitmix64(v ^ time.to_bits() as u64);
+    v = splitmix64(v ^ channel);
+    // Use the upper 24 bits, these fit exactly in the mantissa.
+    let unit = (v >> 40) as f32 / (1u64 << 24) as f32;
+    unit * 2.0 - 1.0
+}
+
+fn splitmix64(v: u64) -> u64 {
+    let mut z = v.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
@@ -78,3 +78,57 @@ fn test_ball() {
         assert!((pose.h.w[2] - analytical_z).abs() < 0.01);
     }
 }
+
+#[test]
+fn test_ball_drag_wind() {
+    use components::atmosphere::{Atmosphere, AtmosphereConfig, WindConfig};
+    use components::point_projectile::PointProjectile;
+
+    let mut world = World::new();
+    let clock_id = world.add_entity();
+    world.add_component(clock_id, Clock::new());
+    let atmosphere_id = world.add_entity();
+    world.add_component(
+        atmosphere_id,
+        Atmosphere::new(AtmosphereConfig {
+            drag_coefficient: 0.05,
+            wind: WindConfig {
+                y: 2.0,
+                ..Default::default()
+            },
+        }),
+    );
+
+    // One ball experiences drag and wind, the other follows the ideal trajectory.
+    let mut balls = vec![];
+    for is_projectile in [true, false] {
+        let ball = world.add_entity();
+        world.add_component(ball, Pose::new());
+        world.add_component(ball, Velocity::from_linear(cgmath::vec3(5.0, 0.0, 5.0)));
+        world.add_component(ball, Acceleration::gravity());
+        if is_projectile {
+            world.add_component(ball, PointProjectile::new());
+        }
+        balls.push(ball);
+    }
+
+    let mut systems = Systems::new();
+    systems.add_system(Box::new(ClockSystem {}));
+    systems.add_system(Box::new(systems::atmosphere_drag::AtmosphereDrag {}));
+    systems.add_system(Box::new(
+        systems::acceleration_velocity::AccelerationVelocity {},
+    ));
+    systems.add_system(Box::new(systems::velocity_pose::VelocityPose {}));
+    for _ in 0..500 {
+        systems.update(&mut world);
+    }
+
+    let dragged = world.component::<Pose>(balls[0]).unwrap().h.w;
+    let ideal = world.component::<Pose>(balls[1]).unwrap().h.w;
+    // The ideal ball has no sideways motion, the dragged one is pushed along by the wind and
+    // travels less far.
+    assert_eq!(ideal[1], 0.0);
+    assert!(dragged[1] > 0.01);
+    assert!(dragged[0] < ideal[0]);
+    assert!(dragged[2] < ideal[2]);
+}
@@ -0,0 +1,80 @@
+use battleground_construct::components::control_recording::ControlRecording;
+use battleground_construct::config;
+use battleground_construct::config::specification::*;
+use battleground_unit_control::control_log::{replay, ReplayDifference};
+use battleground_unit_control::modules::clock::REG_CLOCK_ELAPSED;
+use battleground_unit_control::modules::differential_drive::*;
+use battleground_unit_control::units::common::MODULE_CLOCK;
+use battleground_unit_control::units::tank::MODULE_TANK_DIFF_DRIVE;
+use battleground_unit_control::{Interface, UnitControl};
+
+/// Drives forward, turning after a while.
+struct Driver {
+    turn_after: f32,
+}
+impl UnitControl for Driver {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        let time = interface.get_f32(MODULE_CLOCK, REG_CLOCK_ELAPSED)?;
+        let right = if time > self.turn_after { 0.5 } else { 1.0 };
+        interface.set_f32(MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD, 1.0)?;
+        interface.set_f32(MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_RIGHT_CMD, right)?;
+        Ok(())
+    }
+}
+
+fn driver() -> Box<dyn UnitControl> {
+    Box::new(Driver { turn_after: 0.5 })
+}
+
+#[test]
+fn control_recording_replays() {
+    let scenario = ScenarioConfig {
+        record_control: true,
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                controller: ControllerType::Function(driver),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = config::setup::setup_scenario(scenario).unwrap();
+    for _ in 0..1000 {
+        construct.update();
+    }
+
+    // Write and load the logs, like they would be stored alongside the recording.
+    let path = std::env::temp_dir().join("battleground_control_replay.control");
+    let path = path.to_str().unwrap();
+    construct
+        .world()
+        .component_iter::<ControlRecording>()
+        .next()
+        .unwrap()
+        .1
+        .write_file(path)
+        .unwrap();
+    let logs = ControlRecording::load_file(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(logs.len(), 1);
+    let log = logs.values().next().unwrap();
+    assert!(log.updates.len() > 10);
+
+    // The same controller behaves identically.
+    assert!(replay(log, &mut Driver { turn_after: 0.5 }).is_empty());
+
+    // A controller that turns later differs in the right track command only.
+    let mismatches = replay(log, &mut Driver { turn_after: 0.8 });
+    assert!(!mismatches.is_empty());
+    for mismatch in mismatches {
+        assert!(mismatch.time > 0.5 && mismatch.time <= 0.8);
+        assert!(matches!(
+            mismatch.difference,
+            ReplayDifference::Write {
+                register: REG_DIFF_DRIVE_RIGHT_CMD,
+                ..
+            }
+        ));
+    }
+}
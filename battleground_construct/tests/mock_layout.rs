@@ -0,0 +1,70 @@
+use battleground_construct::components::control_recording::ControlRecording;
+use battleground_construct::config;
+use battleground_construct::config::specification::*;
+use battleground_unit_control::control_log::LogModule;
+use unit_control_mock::MockInterface;
+
+fn idle() -> Box<dyn battleground_unit_control::UnitControl> {
+    Box::new(unit_control_builtin::idle::Idle {})
+}
+
+/// The layout of a unit as recorded during its first update in the construct.
+fn spawned_layout(unit: Unit) -> Vec<LogModule> {
+    let scenario = ScenarioConfig {
+        record_control: true,
+        spawn_config: SpawnConfig {
+            spawns: vec![Spawn {
+                unit,
+                controller: ControllerType::Function(idle),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = config::setup::setup_scenario(scenario).unwrap();
+    construct.update();
+    let (_e, recording) = construct
+        .world()
+        .component_iter::<ControlRecording>()
+        .next()
+        .unwrap();
+    let logs = recording.logs();
+    let logs = logs.borrow();
+    logs.values().next().unwrap().modules.clone()
+}
+
+/// Compare module and register ids, names, types and access.
+fn assert_same_layout(mock: &[LogModule], spawned: &[LogModule]) {
+    let ids = |layout: &[LogModule]| layout.iter().map(|m| m.id).collect::<Vec<_>>();
+    assert_eq!(ids(mock), ids(spawned));
+    for (mock_module, module) in mock.iter().zip(spawned.iter()) {
+        assert_eq!(mock_module.name, module.name, "module 0x{:x}", module.id);
+        let registers = |m: &LogModule| {
+            m.registers
+                .iter()
+                .map(|r| (r.id, r.name.clone(), r.register_type, r.access))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            registers(mock_module),
+            registers(module),
+            "module {} 0x{:x}",
+            module.name,
+            module.id
+        );
+    }
+}
+
+#[test]
+fn mock_tank_layout_matches() {
+    assert_same_layout(&MockInterface::tank().layout(), &spawned_layout(Unit::Tank));
+}
+
+#[test]
+fn mock_artillery_layout_matches() {
+    assert_same_layout(
+        &MockInterface::artillery().layout(),
+        &spawned_layout(Unit::Artillery),
+    );
+}
@@ -0,0 +1,94 @@
+use battleground_construct::components::differential_drive_base::DifferentialDriveBase;
+use battleground_construct::config;
+use battleground_construct::config::specification::*;
+use battleground_unit_control::{Interface, UnitControl};
+
+/// Fails on the first update, such that the unit is destroyed.
+struct SelfDestruct {}
+impl UnitControl for SelfDestruct {
+    fn update(&mut self, _interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        Err("self destruct".into())
+    }
+}
+
+fn self_destruct() -> Box<dyn UnitControl> {
+    Box::new(SelfDestruct {})
+}
+
+fn idle() -> Box<dyn UnitControl> {
+    Box::new(unit_control_builtin::idle::Idle {})
+}
+
+fn scenario(respawn_delay: f32, wave_interval: f32) -> ScenarioConfig {
+    ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![Team {
+                name: "red".to_owned(),
+                color: (255, 0, 0),
+                ..Default::default()
+            }],
+            spawns: vec![Spawn {
+                team: Some(0),
+                controller: ControllerType::Function(self_destruct),
+                ..Default::default()
+            }],
+            reinforcements: Some(ReinforcementConfig {
+                respawn_delay,
+                wave_interval: Some(wave_interval),
+                teams: vec![TeamReinforcements {
+                    team: 0,
+                    tickets: Some(1),
+                    spawn_zones: vec![SpawnZone {
+                        x: 20.0,
+                        y: 5.0,
+                        ..Default::default()
+                    }],
+                    controller: Some(ControllerType::Function(idle)),
+                    radio: Default::default(),
+                }],
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn reinforcement_wave_spawns() {
+    let mut construct = config::setup::setup_scenario(scenario(0.5, 2.0)).unwrap();
+    // The base is removed with the unit, the unit entity itself is eternal.
+    let alive = |construct: &battleground_construct::Construct| {
+        construct
+            .world()
+            .component_iter::<DifferentialDriveBase>()
+            .map(|(e, _)| e)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(alive(&construct).len(), 1);
+    let original = alive(&construct)[0];
+
+    // The unit destroys itself, its replacement only appears at the first wave.
+    while construct.elapsed_as_f32() < 1.9 {
+        construct.update();
+    }
+    assert!(alive(&construct).is_empty());
+    while construct.elapsed_as_f32() < 2.1 {
+        construct.update();
+    }
+    let spawned = alive(&construct);
+    assert_eq!(spawned.len(), 1);
+    assert_ne!(spawned[0], original);
+    let pose = construct.entity_pose(spawned[0]);
+    assert_eq!((pose.w.x, pose.w.y), (20.0, 5.0));
+}
+
+#[test]
+fn reinforcement_timing_must_be_valid() {
+    for wave_interval in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        assert!(config::setup::setup_scenario(scenario(0.5, wave_interval)).is_err());
+    }
+    for respawn_delay in [-1.0, f32::NAN, f32::INFINITY] {
+        assert!(config::setup::setup_scenario(scenario(respawn_delay, 2.0)).is_err());
+    }
+    assert!(config::setup::setup_scenario(scenario(0.0, 2.0)).is_ok());
+}
@@ -0,0 +1,179 @@
+use battleground_construct::config;
+use battleground_construct::config::specification::*;
+use battleground_unit_control::modules::commander::*;
+use battleground_unit_control::modules::orders::*;
+use battleground_unit_control::units::commander::MODULE_COMMANDER;
+use battleground_unit_control::units::common;
+use battleground_unit_control::{Interface, UnitControl};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+static UNIT_COUNT: AtomicI32 = AtomicI32::new(0);
+static ORDERS_RECEIVED: AtomicI32 = AtomicI32::new(0);
+
+/// Orders each unit to report its unit id back.
+struct Commander {}
+impl UnitControl for Commander {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        let count = interface.get_i32(MODULE_COMMANDER, REG_COMMANDER_UNIT_COUNT)?;
+        UNIT_COUNT.store(count, Ordering::SeqCst);
+        for i in 0..count as u32 {
+            let base = REG_COMMANDER_UNIT_START + i * REG_COMMANDER_UNIT_STRIDE;
+            let unit_id =
+                interface.get_i32(MODULE_COMMANDER, base + REG_COMMANDER_UNIT_OFFSET_UNIT_ID)?;
+            interface.set_bytes(
+                MODULE_COMMANDER,
+                base + REG_COMMANDER_UNIT_OFFSET_ORDER,
+                &unit_id.to_le_bytes(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the order it received holds its own unit id.
+struct Soldier {}
+impl UnitControl for Soldier {
+    fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        let unit_id = interface.get_i32(
+            common::MODULE_UNIT,
+            battleground_unit_control::modules::unit::REG_UNIT_UNIT_ID,
+        )?;
+        let sequence = interface.get_i32(common::MODULE_ORDERS, REG_ORDERS_SEQUENCE)?;
+        let mut order = [0u8; 4];
+        if interface.get_bytes_len(common::MODULE_ORDERS, REG_ORDERS_ORDER)? == order.len() {
+            interface.get_bytes(common::MODULE_ORDERS, REG_ORDERS_ORDER, &mut order)?;
+            assert_eq!(i32::from_le_bytes(order), unit_id);
+            // The order is only posted once, since the commander writes the same order again.
+            assert_eq!(sequence, 1);
+            ORDERS_RECEIVED.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+}
+
+fn commander() -> Box<dyn UnitControl> {
+    Box::new(Commander {})
+}
+fn soldier() -> Box<dyn UnitControl> {
+    Box::new(Soldier {})
+}
+
+#[test]
+fn team_commander_posts_orders() {
+    let spawn = |x: f32, team: usize| Spawn {
+        team: Some(team),
+        x,
+        controller: ControllerType::Function(soldier),
+        ..Default::default()
+    };
+    let scenario = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![
+                Team {
+                    name: "red".to_owned(),
+                    color: (255, 0, 0),
+                    commander: Some(ControllerType::Function(commander)),
+                    ..Default::default()
+                },
+                Team {
+                    name: "blue".to_owned(),
+                    color: (0, 0, 255),
+                    ..Default::default()
+                },
+            ],
+            spawns: vec![spawn(0.0, 0), spawn(5.0, 0), spawn(10.0, 1)],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = config::setup::setup_scenario(scenario).unwrap();
+    for _ in 0..100 {
+        construct.update();
+    }
+
+    // The commander only sees its own team, and the blue unit never receives an order.
+    assert_eq!(UNIT_COUNT.load(Ordering::SeqCst), 2);
+    assert!(ORDERS_RECEIVED.load(Ordering::SeqCst) > 0);
+    let ordered = construct
+        .world()
+        .component_iter::<battleground_construct::components::orders::Orders>()
+        .filter(|(_e, orders)| !orders.order().is_empty())
+        .count();
+    assert_eq!(ordered, 2);
+}
+
+static FAILING_UPDATES: AtomicI32 = AtomicI32::new(0);
+
+/// Fails on its first update.
+struct FailingCommander {}
+impl UnitControl for FailingCommander {
+    fn update(&mut self, _interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+        FAILING_UPDATES.fetch_add(1, Ordering::SeqCst);
+        Err("commander failed".into())
+    }
+}
+
+fn failing_commander() -> Box<dyn UnitControl> {
+    Box::new(FailingCommander {})
+}
+
+#[test]
+fn team_commander_failure_handled_once() {
+    use battleground_construct::components;
+    let scenario = ScenarioConfig {
+        spawn_config: SpawnConfig {
+            teams: vec![
+                Team {
+                    name: "red".to_owned(),
+                    color: (255, 0, 0),
+                    commander: Some(ControllerType::Function(failing_commander)),
+                    ..Default::default()
+                },
+                Team {
+                    name: "blue".to_owned(),
+                    color: (0, 0, 255),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut construct = config::setup::setup_scenario(scenario).unwrap();
+    let commander = construct
+        .world()
+        .component_iter::<components::commander::Commander>()
+        .next()
+        .unwrap()
+        .0;
+
+    // The destroyed state of the commander is only counted on the update it failed.
+    let mut destroyed_updates = 0;
+    for _ in 0..100 {
+        construct.update();
+        if construct
+            .world()
+            .component::<components::match_team_deathmatch::MatchTeamDeathmatchJustDestroyed>(
+                commander,
+            )
+            .is_some()
+        {
+            destroyed_updates += 1;
+        }
+    }
+    assert_eq!(destroyed_updates, 1);
+    assert_eq!(FAILING_UPDATES.load(Ordering::SeqCst), 1);
+    assert!(construct
+        .world()
+        .component::<components::unit_controller::UnitController>(commander)
+        .is_none());
+    let failed_teams = construct
+        .world()
+        .component_iter::<components::match_rules::MatchRules>()
+        .next()
+        .unwrap()
+        .1
+        .failed_teams()
+        .to_vec();
+    assert_eq!(failed_teams.len(), 1);
+}
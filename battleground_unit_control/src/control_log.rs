@@ -0,0 +1,726 @@
+//! Recording and replaying the register reads and writes of a unit controller.
+//!
+//! A [`ControlLog`] holds the modules and registers of a unit, and for each update the values the
+//! controller read and the values it wrote. Registers come and go, like radar reflections, so the
+//! layout is stored again with each update in which it changed. The construct can record these
+//! logs during a match, after which [`replay`] feeds the recorded values into a controller and
+//! reports where its writes differ from the recording. This turns behaviour observed in a match
+//! into a deterministic unit test:
+//! ```ignore
+//! let differences = replay(&log, &mut MyController::new());
+//! assert!(differences.is_empty(), "{differences:?}");
+//! ```
+
+use crate::interface::{InterfaceError, InterfaceErrorType};
+use crate::{Interface, RegisterAccess, RegisterMetadata, RegisterType, UnitControl};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+type BoxedError = Box<InterfaceError>;
+
+/// A register value as read or written by the controller.
+pub use crate::interface::RegisterValue as LogValue;
+
+fn zero(register_type: RegisterType) -> LogValue {
+    match register_type {
+        RegisterType::I32 => LogValue::I32(0),
+        RegisterType::F32 => LogValue::F32(0.0),
+        RegisterType::Bytes => LogValue::Bytes(vec![]),
+    }
+}
+
+/// A register as it exists in the unit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogRegister {
+    pub id: u32,
+    pub name: String,
+    pub register_type: RegisterType,
+    /// Logs recorded before access was captured treat all registers as writable.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub access: RegisterAccess,
+}
+
+/// A module and its registers as it exists in the unit.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogModule {
+    pub id: u32,
+    pub name: String,
+    pub registers: Vec<LogRegister>,
+}
+
+/// The register accesses of a single update.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogUpdate {
+    /// Time at which the update happened.
+    pub time: f32,
+    /// First value read from each register, registers written before they are read are omitted.
+    pub reads: Vec<(u32, u32, LogValue)>,
+    /// All successful writes, in order.
+    pub writes: Vec<(u32, u32, LogValue)>,
+    /// The error returned by the controller, if any.
+    pub error: Option<String>,
+    /// The modules and registers during this update, only present if they changed since the
+    /// previous update.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub modules: Option<Vec<LogModule>>,
+}
+
+/// The modules of a unit and all register accesses made by its controller.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ControlLog {
+    /// The modules and registers during the first update.
+    pub modules: Vec<LogModule>,
+    pub updates: Vec<LogUpdate>,
+    /// Module, register and type of each register during the last update, to detect changes.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    layout: Vec<(u32, u32, RegisterType)>,
+}
+
+impl ControlLog {
+    /// Run an update of the controller, recording its register accesses.
+    pub fn record(
+        &mut self,
+        time: f32,
+        interface: &mut dyn Interface,
+        control: &mut dyn UnitControl,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // Only store the layout if it changed, it rarely does for most modules.
+        let mut modules = match self.changed_modules(interface) {
+            Ok(modules) => modules,
+            Err(e) => {
+                // Recording is not a reason to fail the controller.
+                log::warn!("Failed to record the registers, updating unrecorded: {e}");
+                return control.update(interface);
+            }
+        };
+        if self.updates.is_empty() {
+            self.modules = modules.take().unwrap_or_default();
+        }
+        let mut recorder = Recorder {
+            interface,
+            update: RefCell::new(LogUpdate {
+                time,
+                modules,
+                ..Default::default()
+            }),
+        };
+        let result = control.update(&mut recorder);
+        let mut update = recorder.update.into_inner();
+        update.error = result.as_ref().err().map(|e| e.to_string());
+        self.updates.push(update);
+        result
+    }
+
+    /// Capture the modules and registers if their ids or types changed since the last update.
+    fn changed_modules(
+        &mut self,
+        interface: &dyn Interface,
+    ) -> Result<Option<Vec<LogModule>>, BoxedError> {
+        let mut layout = vec![];
+        for module in interface.modules()? {
+            for register in interface.registers(module)? {
+                layout.push((module, register, interface.register_type(module, register)?));
+            }
+        }
+        if !self.updates.is_empty() && layout == self.layout {
+            return Ok(None);
+        }
+        let modules = capture_modules(interface)?;
+        self.layout = layout;
+        Ok(Some(modules))
+    }
+
+    /// The modules and registers during an update.
+    pub fn modules_at(&self, update: usize) -> &[LogModule] {
+        self.updates
+            .iter()
+            .take(update + 1)
+            .rev()
+            .find_map(|u| u.modules.as_deref())
+            .unwrap_or(&self.modules)
+    }
+}
+
+fn capture_modules(interface: &dyn Interface) -> Result<Vec<LogModule>, BoxedError> {
+    let mut modules = vec![];
+    for module in interface.modules()? {
+        let mut registers = vec![];
+        for register in interface.registers(module)? {
+            registers.push(LogRegister {
+                id: register,
+                name: interface.register_name(module, register)?,
+                register_type: interface.register_type(module, register)?,
+                access: interface.register_metadata(module, register)?.access,
+            });
+        }
+        modules.push(LogModule {
+            id: module,
+            name: interface.module_name(module)?,
+            registers,
+        });
+    }
+    Ok(modules)
+}
+
+/// Interface that passes everything through, while recording the reads and writes.
+struct Recorder<'a> {
+    interface: &'a mut dyn Interface,
+    update: RefCell<LogUpdate>,
+}
+
+impl Recorder<'_> {
+    fn read(&self, module: u32, register: u32, value: LogValue) {
+        let mut update = self.update.borrow_mut();
+        let seen = |(m, r, _): &(u32, u32, LogValue)| *m == module && *r == register;
+        if !update.reads.iter().any(seen) && !update.writes.iter().any(seen) {
+            update.reads.push((module, register, value));
+        }
+    }
+
+    fn write(&self, module: u32, register: u32, value: LogValue) {
+        self.update
+            .borrow_mut()
+            .writes
+            .push((module, register, value));
+    }
+
+    fn read_bytes(&self, module: u32, register: u32) -> Result<(), BoxedError> {
+        let mut values = vec![0u8; self.interface.get_bytes_len(module, register)?];
+        self.interface.get_bytes(module, register, &mut values)?;
+        self.read(module, register, LogValue::Bytes(values));
+        Ok(())
+    }
+}
+
+impl Interface for Recorder<'_> {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        self.interface.modules()
+    }
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        self.interface.module_name(module)
+    }
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        self.interface.registers(module)
+    }
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        self.interface.register_name(module, register)
+    }
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        self.interface.register_type(module, register)
+    }
+    fn register_metadata(
+        &self,
+        module: u32,
+        register: u32,
+    ) -> Result<RegisterMetadata, BoxedError> {
+        self.interface.register_metadata(module, register)
+    }
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        let value = self.interface.get_i32(module, register)?;
+        self.read(module, register, LogValue::I32(value));
+        Ok(value)
+    }
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        let old = self.interface.set_i32(module, register, value)?;
+        self.read(module, register, LogValue::I32(old));
+        self.write(module, register, LogValue::I32(value));
+        Ok(old)
+    }
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        let value = self.interface.get_f32(module, register)?;
+        self.read(module, register, LogValue::F32(value));
+        Ok(value)
+    }
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        let old = self.interface.set_f32(module, register, value)?;
+        self.read(module, register, LogValue::F32(old));
+        self.write(module, register, LogValue::F32(value));
+        Ok(old)
+    }
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        let len = self.interface.get_bytes_len(module, register)?;
+        self.read_bytes(module, register)?;
+        Ok(len)
+    }
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        let len = self.interface.get_bytes(module, register, destination)?;
+        self.read_bytes(module, register)?;
+        Ok(len)
+    }
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        self.interface.set_bytes(module, register, values)?;
+        self.write(module, register, LogValue::Bytes(values.to_vec()));
+        Ok(())
+    }
+}
+
+/// A difference between the recording and the replay.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayDifference {
+    /// The controller read a register that wasn't read in the recording, it obtained zero.
+    UnrecordedRead { module: u32, register: u32 },
+    /// The final value written to a register differs, `None` if it wasn't written.
+    Write {
+        module: u32,
+        register: u32,
+        recorded: Option<LogValue>,
+        replayed: Option<LogValue>,
+    },
+    /// The error returned by the controller differs.
+    Error {
+        recorded: Option<String>,
+        replayed: Option<String>,
+    },
+}
+
+/// A difference found during a particular update of the replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayMismatch {
+    /// Index of the update in the log.
+    pub update: usize,
+    /// Time at which the update happened.
+    pub time: f32,
+    pub difference: ReplayDifference,
+}
+
+/// Replay the recorded updates into a controller, returning all differences in its behaviour.
+pub fn replay(log: &ControlLog, control: &mut dyn UnitControl) -> Vec<ReplayMismatch> {
+    let mut mismatches = vec![];
+    let mut modules = &log.modules;
+    for (index, update) in log.updates.iter().enumerate() {
+        if let Some(changed) = &update.modules {
+            modules = changed;
+        }
+        let mut interface = Replayer {
+            modules,
+            values: update
+                .reads
+                .iter()
+                .map(|(m, r, v)| ((*m, *r), v.clone()))
+                .collect(),
+            writes: vec![],
+            unrecorded: RefCell::new(vec![]),
+        };
+        let error = control.update(&mut interface).err().map(|e| e.to_string());
+
+        let mut differences = vec![];
+        for (module, register) in interface.unrecorded.into_inner() {
+            differences.push(ReplayDifference::UnrecordedRead { module, register });
+        }
+        let recorded = final_writes(&update.writes);
+        let replayed = final_writes(&interface.writes);
+        let mut registers = recorded.keys().chain(replayed.keys()).collect::<Vec<_>>();
+        registers.sort();
+        registers.dedup();
+        for (module, register) in registers {
+            let recorded = recorded.get(&(*module, *register));
+            let replayed = replayed.get(&(*module, *register));
+            if recorded != replayed {
+                differences.push(ReplayDifference::Write {
+                    module: *module,
+                    register: *register,
+                    recorded: recorded.cloned(),
+                    replayed: replayed.cloned(),
+                });
+            }
+        }
+        if error != update.error {
+            differences.push(ReplayDifference::Error {
+                recorded: update.error.clone(),
+                replayed: error,
+            });
+        }
+
+        mismatches.extend(differences.drain(..).map(|difference| ReplayMismatch {
+            update: index,
+            time: update.time,
+            difference,
+        }));
+    }
+    mismatches
+}
+
+fn final_writes(writes: &[(u32, u32, LogValue)]) -> HashMap<(u32, u32), LogValue> {
+    writes
+        .iter()
+        .map(|(m, r, v)| ((*m, *r), v.clone()))
+        .collect()
+}
+
+/// Interface that serves the recorded values and collects the writes.
+struct Replayer<'a> {
+    modules: &'a [LogModule],
+    values: HashMap<(u32, u32), LogValue>,
+    writes: Vec<(u32, u32, LogValue)>,
+    unrecorded: RefCell<Vec<(u32, u32)>>,
+}
+
+impl Replayer<'_> {
+    fn error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
+        Box::new(InterfaceError {
+            module,
+            register,
+            error_type,
+        })
+    }
+
+    fn module(&self, module: u32) -> Result<&LogModule, BoxedError> {
+        self.modules
+            .iter()
+            .find(|m| m.id == module)
+            .ok_or_else(|| Self::error(module, 0, InterfaceErrorType::NoSuchModule))
+    }
+
+    fn register(&self, module: u32, register: u32) -> Result<&LogRegister, BoxedError> {
+        self.module(module)?
+            .registers
+            .iter()
+            .find(|r| r.id == register)
+            .ok_or_else(|| Self::error(module, register, InterfaceErrorType::NoSuchRegister))
+    }
+
+    /// Retrieve the current value of a register of the expected type.
+    fn value(
+        &self,
+        module: u32,
+        register: u32,
+        register_type: RegisterType,
+    ) -> Result<LogValue, BoxedError> {
+        if self.register(module, register)?.register_type != register_type {
+            return Err(Self::error(module, register, InterfaceErrorType::WrongType));
+        }
+        Ok(match self.values.get(&(module, register)) {
+            Some(value) if value.register_type() == register_type => value.clone(),
+            _ => {
+                self.unrecorded.borrow_mut().push((module, register));
+                zero(register_type)
+            }
+        })
+    }
+
+    fn set(&mut self, module: u32, register: u32, value: LogValue) -> Result<LogValue, BoxedError> {
+        let register_type = value.register_type();
+        let log_register = self.register(module, register)?;
+        if log_register.access == RegisterAccess::ReadOnly {
+            return Err(Self::error(module, register, InterfaceErrorType::ReadOnly));
+        }
+        if log_register.register_type != register_type {
+            return Err(Self::error(module, register, InterfaceErrorType::WrongType));
+        }
+        // The old value is only recorded for numeric registers, so this is not an unrecorded read.
+        let old = self
+            .values
+            .insert((module, register), value.clone())
+            .unwrap_or_else(|| zero(register_type));
+        self.writes.push((module, register, value));
+        Ok(old)
+    }
+}
+
+impl Interface for Replayer<'_> {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        Ok(self.modules.iter().map(|m| m.id).collect())
+    }
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        Ok(self.module(module)?.name.clone())
+    }
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        Ok(self
+            .module(module)?
+            .registers
+            .iter()
+            .map(|r| r.id)
+            .collect())
+    }
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        Ok(self.register(module, register)?.name.clone())
+    }
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        Ok(self.register(module, register)?.register_type)
+    }
+    fn register_metadata(
+        &self,
+        module: u32,
+        register: u32,
+    ) -> Result<RegisterMetadata, BoxedError> {
+        Ok(RegisterMetadata {
+            access: self.register(module, register)?.access,
+            ..Default::default()
+        })
+    }
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        match self.value(module, register, RegisterType::I32)? {
+            LogValue::I32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        match self.set(module, register, LogValue::I32(value))? {
+            LogValue::I32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        match self.value(module, register, RegisterType::F32)? {
+            LogValue::F32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        match self.set(module, register, LogValue::F32(value))? {
+            LogValue::F32(v) => Ok(v),
+            _ => unreachable!(),
+        }
+    }
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        match self.value(module, register, RegisterType::Bytes)? {
+            LogValue::Bytes(v) => Ok(v.len()),
+            _ => unreachable!(),
+        }
+    }
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        match self.value(module, register, RegisterType::Bytes)? {
+            LogValue::Bytes(v) => {
+                if destination.len() < v.len() {
+                    return Err(Self::error(
+                        module,
+                        register,
+                        InterfaceErrorType::ReadOverflow,
+                    ));
+                }
+                destination[..v.len()].copy_from_slice(&v);
+                Ok(v.len())
+            }
+            _ => unreachable!(),
+        }
+    }
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        self.set(module, register, LogValue::Bytes(values.to_vec()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Drives forward if the first register is positive, echoes the bytes register.
+    struct Echo {
+        speed: f32,
+    }
+    impl UnitControl for Echo {
+        fn update(
+            &mut self,
+            interface: &mut dyn Interface,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            if interface.get_i32(1, 0)? > 0 {
+                interface.set_f32(1, 1, self.speed)?;
+            }
+            let mut bytes = vec![0u8; interface.get_bytes_len(1, 2)?];
+            interface.get_bytes(1, 2, &mut bytes)?;
+            interface.set_bytes(1, 3, &bytes)?;
+            Ok(())
+        }
+    }
+
+    fn unit() -> ControlLog {
+        let register = |id, register_type| LogRegister {
+            id,
+            name: format!("r{id}"),
+            register_type,
+            access: RegisterAccess::ReadWrite,
+        };
+        // The first register is only read by the controller.
+        let read_only = LogRegister {
+            access: RegisterAccess::ReadOnly,
+            ..register(0, RegisterType::I32)
+        };
+        ControlLog {
+            modules: vec![LogModule {
+                id: 1,
+                name: "unit".to_owned(),
+                registers: vec![
+                    read_only,
+                    register(1, RegisterType::F32),
+                    register(2, RegisterType::Bytes),
+                    register(3, RegisterType::Bytes),
+                ],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_replay() {
+        // Record against a replayer of a hand crafted unit.
+        let unit = unit();
+        let mut log = ControlLog::default();
+        for (i, state) in [0, 1, 2].iter().enumerate() {
+            let mut interface = Replayer {
+                modules: &unit.modules,
+                values: [
+                    ((1, 0), LogValue::I32(*state)),
+                    ((1, 1), LogValue::F32(0.0)),
+                    ((1, 2), LogValue::Bytes(vec![*state as u8; 3])),
+                ]
+                .into_iter()
+                .collect(),
+                writes: vec![],
+                unrecorded: RefCell::new(vec![]),
+            };
+            log.record(i as f32, &mut interface, &mut Echo { speed: 1.0 })
+                .unwrap();
+        }
+        assert_eq!(log.modules.len(), 1);
+        assert_eq!(log.modules[0].registers[0].access, RegisterAccess::ReadOnly);
+        assert_eq!(log.updates.len(), 3);
+        assert_eq!(log.updates[0].reads.len(), 2);
+        assert_eq!(log.updates[1].reads.len(), 3);
+        assert_eq!(log.updates[1].writes.len(), 2);
+
+        // Writing the read only register fails.
+        let mut interface = Replayer {
+            modules: &log.modules,
+            values: Default::default(),
+            writes: vec![],
+            unrecorded: RefCell::new(vec![]),
+        };
+        assert_eq!(
+            interface.set_i32(1, 0, 1).unwrap_err().error_type,
+            InterfaceErrorType::ReadOnly
+        );
+
+        // The same controller replays identically.
+        assert!(replay(&log, &mut Echo { speed: 1.0 }).is_empty());
+
+        // A different one only differs when it drives.
+        let mismatches = replay(&log, &mut Echo { speed: 0.5 });
+        assert_eq!(mismatches.len(), 2);
+        assert_eq!(mismatches[0].update, 1);
+        assert_eq!(
+            mismatches[0].difference,
+            ReplayDifference::Write {
+                module: 1,
+                register: 1,
+                recorded: Some(LogValue::F32(1.0)),
+                replayed: Some(LogValue::F32(0.5)),
+            }
+        );
+    }
+
+    /// Passes through to a replayer, counting the register names looked up.
+    struct Counting<'a> {
+        replayer: Replayer<'a>,
+        names: std::cell::Cell<usize>,
+        fail: bool,
+    }
+    impl Interface for Counting<'_> {
+        fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+            if self.fail {
+                return Err(Replayer::error(0, 0, InterfaceErrorType::NoSuchModule));
+            }
+            self.replayer.modules()
+        }
+        fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+            self.replayer.module_name(module)
+        }
+        fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+            self.replayer.registers(module)
+        }
+        fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+            self.names.set(self.names.get() + 1);
+            self.replayer.register_name(module, register)
+        }
+        fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+            self.replayer.register_type(module, register)
+        }
+        fn register_metadata(
+            &self,
+            module: u32,
+            register: u32,
+        ) -> Result<RegisterMetadata, BoxedError> {
+            self.replayer.register_metadata(module, register)
+        }
+        fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+            self.replayer.get_i32(module, register)
+        }
+        fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+            self.replayer.set_i32(module, register, value)
+        }
+        fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+            self.replayer.get_f32(module, register)
+        }
+        fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+            self.replayer.set_f32(module, register, value)
+        }
+        fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+            self.replayer.get_bytes_len(module, register)
+        }
+        fn get_bytes(
+            &self,
+            module: u32,
+            register: u32,
+            destination: &mut [u8],
+        ) -> Result<usize, BoxedError> {
+            self.replayer.get_bytes(module, register, destination)
+        }
+        fn set_bytes(
+            &mut self,
+            module: u32,
+            register: u32,
+            values: &[u8],
+        ) -> Result<(), BoxedError> {
+            self.replayer.set_bytes(module, register, values)
+        }
+    }
+
+    #[test]
+    fn test_record_layout_changes() {
+        let unit = unit();
+        let mut changed = unit.modules.clone();
+        changed[0].registers.pop();
+        let mut log = ControlLog::default();
+        let mut record = |modules: &[LogModule], fail: bool| {
+            let mut interface = Counting {
+                replayer: Replayer {
+                    modules,
+                    values: Default::default(),
+                    writes: vec![],
+                    unrecorded: RefCell::new(vec![]),
+                },
+                names: Default::default(),
+                fail,
+            };
+            let result = log.record(0.0, &mut interface, &mut Echo { speed: 1.0 });
+            (result.is_ok(), interface.names.get())
+        };
+
+        // The names are only looked up when the registers change.
+        assert_eq!(record(&unit.modules, false), (true, 4));
+        assert_eq!(record(&unit.modules, false), (true, 0));
+        assert_eq!(record(&changed, false), (false, 3));
+        assert_eq!(record(&unit.modules, false), (true, 4));
+
+        // Failing to capture the registers still updates the controller, unrecorded.
+        assert_eq!(record(&unit.modules, true), (true, 0));
+
+        assert_eq!(log.updates.len(), 4);
+        assert!(log.updates[1].modules.is_none());
+        assert_eq!(log.modules_at(2), &changed[..]);
+        assert_eq!(log.modules_at(3), &unit.modules[..]);
+    }
+}
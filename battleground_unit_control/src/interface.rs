@@ -1,8 +1,11 @@
+use std::borrow::Cow;
+
 /// Error type used.
 type BoxedError = Box<InterfaceError>;
 
 /// Enum to denote register type.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RegisterType {
     I32,
     F32,
@@ -21,6 +24,210 @@ impl TryFrom<u32> for RegisterType {
     }
 }
 
+/// Enum to denote whether the controller may write a register.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegisterAccess {
+    /// Writes fail with [`InterfaceErrorType::ReadOnly`].
+    ReadOnly,
+    #[default]
+    ReadWrite,
+}
+
+impl TryFrom<u32> for RegisterAccess {
+    type Error = ();
+    fn try_from(v: u32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == RegisterAccess::ReadOnly as u32 => Ok(RegisterAccess::ReadOnly),
+            x if x == RegisterAccess::ReadWrite as u32 => Ok(RegisterAccess::ReadWrite),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Metadata describing a register, the unit and description are empty if not provided.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RegisterMetadata {
+    /// Whether the controller may write the register.
+    pub access: RegisterAccess,
+    /// The physical unit of the value, like `m`, `rad` or `rad/s`.
+    pub unit: Cow<'static, str>,
+    /// The valid range of the value, both bounds inclusive.
+    pub range: Option<(f32, f32)>,
+    /// Description of the register.
+    pub description: Cow<'static, str>,
+}
+
+impl RegisterMetadata {
+    /// Serialize the metadata, used to transfer it into wasm controllers.
+    pub fn to_le_bytes(&self) -> Vec<u8> {
+        let mut v = vec![];
+        v.extend((self.access as u32).to_le_bytes());
+        let (has_range, (min, max)) = match self.range {
+            Some(range) => (1u32, range),
+            None => (0u32, (0.0, 0.0)),
+        };
+        v.extend(has_range.to_le_bytes());
+        v.extend(min.to_le_bytes());
+        v.extend(max.to_le_bytes());
+        for s in [&self.unit, &self.description] {
+            v.extend((s.len() as u32).to_le_bytes());
+            v.extend(s.as_bytes());
+        }
+        v
+    }
+
+    /// Deserialize metadata created by [`RegisterMetadata::to_le_bytes`].
+    pub fn from_le_bytes(data: &[u8]) -> Option<Self> {
+        let mut data = data;
+        let access = take_u32(&mut data)?.try_into().ok()?;
+        let has_range = take_u32(&mut data)?;
+        let min = f32::from_bits(take_u32(&mut data)?);
+        let max = f32::from_bits(take_u32(&mut data)?);
+        Some(RegisterMetadata {
+            access,
+            range: (has_range != 0).then_some((min, max)),
+            unit: take_string(&mut data)?.into(),
+            description: take_string(&mut data)?.into(),
+        })
+    }
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    let v = data.get(..len)?;
+    *data = &data[len..];
+    Some(v)
+}
+
+fn take_u32(data: &mut &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(take(data, 4)?.try_into().ok()?))
+}
+
+fn take_string(data: &mut &[u8]) -> Option<String> {
+    let len = take_u32(data)? as usize;
+    String::from_utf8(take(data, len)?.to_vec()).ok()
+}
+
+/// The value of a register, used to read and write many registers at once.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegisterValue {
+    I32(i32),
+    F32(f32),
+    Bytes(Vec<u8>),
+}
+
+impl RegisterValue {
+    /// The type of register this value belongs to.
+    pub fn register_type(&self) -> RegisterType {
+        match self {
+            RegisterValue::I32(_) => RegisterType::I32,
+            RegisterValue::F32(_) => RegisterType::F32,
+            RegisterValue::Bytes(_) => RegisterType::Bytes,
+        }
+    }
+}
+
+/// Floats are compared by their bits, such that a NaN compares equal to the same NaN.
+impl PartialEq for RegisterValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (RegisterValue::I32(a), RegisterValue::I32(b)) => a == b,
+            (RegisterValue::F32(a), RegisterValue::F32(b)) => a.to_bits() == b.to_bits(),
+            (RegisterValue::Bytes(a), RegisterValue::Bytes(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Serialize `(module, register, value)` entries, used to transfer batches into and out of wasm
+/// controllers.
+pub fn values_to_le_bytes(values: &[(u32, u32, RegisterValue)]) -> Vec<u8> {
+    let mut v = vec![];
+    for (module, register, value) in values {
+        v.extend(module.to_le_bytes());
+        v.extend(register.to_le_bytes());
+        v.extend((value.register_type() as u32).to_le_bytes());
+        match value {
+            RegisterValue::I32(x) => v.extend(x.to_le_bytes()),
+            RegisterValue::F32(x) => v.extend(x.to_le_bytes()),
+            RegisterValue::Bytes(x) => {
+                v.extend((x.len() as u32).to_le_bytes());
+                v.extend(x);
+            }
+        }
+    }
+    v
+}
+
+/// Deserialize entries created by [`values_to_le_bytes`].
+pub fn values_from_le_bytes(data: &[u8]) -> Option<Vec<(u32, u32, RegisterValue)>> {
+    let mut data = data;
+    let mut values = vec![];
+    while !data.is_empty() {
+        let module = take_u32(&mut data)?;
+        let register = take_u32(&mut data)?;
+        let value = match take_u32(&mut data)?.try_into().ok()? {
+            RegisterType::I32 => RegisterValue::I32(take_u32(&mut data)? as i32),
+            RegisterType::F32 => RegisterValue::F32(f32::from_bits(take_u32(&mut data)?)),
+            RegisterType::Bytes => {
+                let len = take_u32(&mut data)? as usize;
+                RegisterValue::Bytes(take(&mut data, len)?.to_vec())
+            }
+        };
+        values.push((module, register, value));
+    }
+    Some(values)
+}
+
+/// Handle a batched read for a wasm controller, the request holds the module ids as little endian
+/// `u32`s. Returns the values serialized by [`values_to_le_bytes`], or the error together with the
+/// index of the module that failed.
+pub fn get_values_request(
+    interface: &dyn Interface,
+    request: &[u8],
+) -> Result<Vec<u8>, (BoxedError, u32)> {
+    let modules = request.chunks_exact(4);
+    if !modules.remainder().is_empty() {
+        let e = Box::new(InterfaceError {
+            module: 0,
+            register: 0,
+            error_type: InterfaceErrorType::WrongType,
+        });
+        return Err((e, (request.len() / 4) as u32));
+    }
+    let mut values = vec![];
+    for (i, module) in modules.enumerate() {
+        let module = u32::from_le_bytes(module.try_into().expect("chunks of 4"));
+        let module_values = interface.get_values(&[module]).map_err(|e| (e, i as u32))?;
+        values.extend(module_values);
+    }
+    Ok(values_to_le_bytes(&values))
+}
+
+/// Handle a batched write for a wasm controller, the request is created by
+/// [`values_to_le_bytes`]. Returns the number of values written, or the error together with the
+/// index of the value that failed; earlier values remain written.
+pub fn set_values_request(
+    interface: &mut dyn Interface,
+    request: &[u8],
+) -> Result<u32, (BoxedError, u32)> {
+    let values = values_from_le_bytes(request).ok_or_else(|| {
+        let e = Box::new(InterfaceError {
+            module: 0,
+            register: 0,
+            error_type: InterfaceErrorType::WrongType,
+        });
+        (e, 0)
+    })?;
+    for (i, value) in values.iter().enumerate() {
+        interface
+            .set_values(std::slice::from_ref(value))
+            .map_err(|e| (e, i as u32))?;
+    }
+    Ok(values.len() as u32)
+}
+
 /// Interface to control the unit, the unit controller uses this to interact with the unit.
 pub trait Interface {
     /// Retrieve the list of module ids that are available.
@@ -38,6 +245,16 @@ pub trait Interface {
     /// Retrieve a register type.
     fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError>;
 
+    /// Retrieve the metadata of a register.
+    fn register_metadata(
+        &self,
+        module: u32,
+        register: u32,
+    ) -> Result<RegisterMetadata, BoxedError> {
+        let _ = (module, register);
+        Ok(RegisterMetadata::default())
+    }
+
     /// Get an i32 register.
     fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError>;
 
@@ -63,6 +280,45 @@ pub trait Interface {
 
     /// Set a byte register.
     fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError>;
+
+    /// Get the values of all registers in the provided modules as `(module, register, value)`.
+    ///
+    /// This is equivalent to reading each register individually, but implementations may do so
+    /// more efficiently; the wasm interface transfers all values in a single call.
+    fn get_values(&self, modules: &[u32]) -> Result<Vec<(u32, u32, RegisterValue)>, BoxedError> {
+        let mut values = vec![];
+        for &module in modules {
+            for register in self.registers(module)? {
+                let value = match self.register_type(module, register)? {
+                    RegisterType::I32 => RegisterValue::I32(self.get_i32(module, register)?),
+                    RegisterType::F32 => RegisterValue::F32(self.get_f32(module, register)?),
+                    RegisterType::Bytes => {
+                        let mut v = vec![0; self.get_bytes_len(module, register)?];
+                        let len = self.get_bytes(module, register, &mut v)?;
+                        v.truncate(len);
+                        RegisterValue::Bytes(v)
+                    }
+                };
+                values.push((module, register, value));
+            }
+        }
+        Ok(values)
+    }
+
+    /// Set the values of several registers, provided as `(module, register, value)`.
+    ///
+    /// The writes are applied in order, the first write that fails stops the batch and its error
+    /// is returned; earlier writes remain applied.
+    fn set_values(&mut self, values: &[(u32, u32, RegisterValue)]) -> Result<(), BoxedError> {
+        for (module, register, value) in values {
+            match value {
+                RegisterValue::I32(v) => self.set_i32(*module, *register, *v).map(|_| ())?,
+                RegisterValue::F32(v) => self.set_f32(*module, *register, *v).map(|_| ())?,
+                RegisterValue::Bytes(v) => self.set_bytes(*module, *register, v)?,
+            }
+        }
+        Ok(())
+    }
 }
 
 /// If an error occurs in the interface, the follow boxed error is returned.
@@ -82,6 +338,7 @@ pub enum InterfaceErrorType {
     ReadOverflow,
     WriteOverflow,
     WriteUnderflow,
+    ReadOnly,
 }
 
 impl TryFrom<u32> for InterfaceErrorType {
@@ -104,6 +361,7 @@ impl TryFrom<u32> for InterfaceErrorType {
             x if x == InterfaceErrorType::WriteUnderflow as u32 => {
                 Ok(InterfaceErrorType::WriteUnderflow)
             }
+            x if x == InterfaceErrorType::ReadOnly as u32 => Ok(InterfaceErrorType::ReadOnly),
             _ => Err(()),
         }
     }
@@ -147,6 +405,9 @@ impl std::fmt::Display for InterfaceError {
                     self.register, self.module
                 )
             }
+            InterfaceErrorType::ReadOnly => {
+                write!(f, "{}:{} is read only", self.module, self.register)
+            }
         }
     }
 }
@@ -193,6 +454,10 @@ mod test {
                 .try_into()
                 .unwrap()
         );
+        assert_eq!(
+            InterfaceErrorType::ReadOnly,
+            (InterfaceErrorType::ReadOnly as u32).try_into().unwrap()
+        );
     }
     #[test]
     fn test_register_type_conversion() {
@@ -209,4 +474,34 @@ mod test {
             (RegisterType::Bytes as u32).try_into().unwrap()
         );
     }
+
+    #[test]
+    fn test_register_metadata_conversion() {
+        let metadata = RegisterMetadata {
+            access: RegisterAccess::ReadOnly,
+            unit: "rad/s".into(),
+            range: Some((-1.0, 1.0)),
+            description: "The velocity.".into(),
+        };
+        let bytes = metadata.to_le_bytes();
+        assert_eq!(RegisterMetadata::from_le_bytes(&bytes), Some(metadata));
+        assert_eq!(RegisterMetadata::from_le_bytes(&bytes[..10]), None);
+        let metadata = RegisterMetadata::default();
+        let bytes = metadata.to_le_bytes();
+        assert_eq!(RegisterMetadata::from_le_bytes(&bytes), Some(metadata));
+    }
+
+    #[test]
+    fn test_register_values_conversion() {
+        let values = vec![
+            (1, 2, RegisterValue::I32(-3)),
+            (1, 3, RegisterValue::F32(f32::NAN)),
+            (4, 5, RegisterValue::Bytes(vec![1, 2, 3])),
+            (4, 6, RegisterValue::Bytes(vec![])),
+        ];
+        let bytes = values_to_le_bytes(&values);
+        assert_eq!(values_from_le_bytes(&bytes), Some(values));
+        assert_eq!(values_from_le_bytes(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(values_from_le_bytes(&[]), Some(vec![]));
+    }
 }
@@ -25,6 +25,16 @@ pub mod modules;
 /// The constants for units.
 pub mod units;
 
+/// Recording and replaying the register accesses of a controller.
+pub mod control_log;
+
+/// Typed messaging over the radio modules.
+pub mod radio;
+
+/// Typed handles to the modules of each unit, an alternative to accessing registers directly.
+#[cfg(feature = "sdk")]
+pub mod sdk;
+
 /// Export the log interface, this is used on wasm32 to be able to print.
 pub use log;
 
@@ -33,7 +43,9 @@ pub type ControllerSpawn = fn() -> Box<dyn UnitControl>;
 
 /// The interface trait and types.
 pub mod interface;
-pub use interface::{Interface, InterfaceError, RegisterType};
+pub use interface::{
+    Interface, InterfaceError, RegisterAccess, RegisterMetadata, RegisterType, RegisterValue,
+};
 
 /// The unit control trait and related types.
 pub mod unit_control;
@@ -12,3 +12,7 @@ pub const REG_CANNON_READY: u32 = 2;
 
 /// Provides the reload time in seconds, float value.
 pub const REG_CANNON_RELOAD_TIME: u32 = 3;
+
+/// The integrity of the cannon, float value, 1.0 is undamaged, 0.0 is fully damaged. A damaged
+/// cannon has an increased reload time.
+pub const REG_CANNON_INTEGRITY: u32 = 4;
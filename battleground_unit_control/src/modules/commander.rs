@@ -0,0 +1,29 @@
+//! Summary of the team's units, for the team commander.
+
+//!
+//! This module is only available to the team commander, it lists the units of its team. Orders
+//! are posted to a unit by writing a different value to its order register, the unit receives it
+//! through its [`orders`](super::orders) module.
+
+/// The unit count, integer value.
+pub const REG_COMMANDER_UNIT_COUNT: u32 = 0x1000;
+/// The unit start offset.
+pub const REG_COMMANDER_UNIT_START: u32 = 0x1001;
+/// The unit id offset, integer value.
+pub const REG_COMMANDER_UNIT_OFFSET_UNIT_ID: u32 = 0;
+/// The unit type offset, integer value.
+pub const REG_COMMANDER_UNIT_OFFSET_UNIT_TYPE: u32 = 1;
+/// The x position offset, float value.
+pub const REG_COMMANDER_UNIT_OFFSET_X: u32 = 2;
+/// The y position offset, float value.
+pub const REG_COMMANDER_UNIT_OFFSET_Y: u32 = 3;
+/// The yaw offset, float value.
+pub const REG_COMMANDER_UNIT_OFFSET_YAW: u32 = 4;
+/// The health offset, float value.
+pub const REG_COMMANDER_UNIT_OFFSET_HEALTH: u32 = 5;
+/// The order offset, bytes value, writing a different order posts it to the unit.
+pub const REG_COMMANDER_UNIT_OFFSET_ORDER: u32 = 6;
+/// The order sequence offset, integer value, the number of orders the unit received.
+pub const REG_COMMANDER_UNIT_OFFSET_ORDER_SEQUENCE: u32 = 7;
+/// The stride of units.
+pub const REG_COMMANDER_UNIT_STRIDE: u32 = 8;
@@ -0,0 +1,36 @@
+//! Health of the unit and the hits it received.
+//!
+//! The hit list holds the most recent hits, oldest first, up to [`DAMAGE_HITS_MAX`] entries. The
+//! total hit count can be used to detect new hits. The impact bearing is the direction of the
+//! impact position as seen from the body origin, in the current body frame. This is where the unit
+//! was hit, not the direction the projectile came from.
+
+/// The health of the unit, float value, 1.0 is full health, destroyed at 0.0 or below.
+pub const REG_DAMAGE_HEALTH: u32 = 0x10;
+
+/// The total number of hits the unit received, integer value.
+pub const REG_DAMAGE_TOTAL_HITS: u32 = 0x11;
+
+/// The maximum number of hits in the hit list.
+pub const DAMAGE_HITS_MAX: usize = 16;
+
+/// The number of hit records.
+pub const REG_DAMAGE_HIT_COUNT: u32 = 0x1000;
+/// The start of the hit list.
+pub const REG_DAMAGE_HIT_START: u32 = 0x1001;
+
+/// The offset of the time of the hit, float value, seconds.
+pub const REG_DAMAGE_HIT_OFFSET_TIME: u32 = 0;
+/// The offset of the damage of the hit, float value.
+pub const REG_DAMAGE_HIT_OFFSET_DAMAGE: u32 = 1;
+/// The offset of the yaw of the impact bearing, float value, radians.
+pub const REG_DAMAGE_HIT_OFFSET_BEARING_YAW: u32 = 2;
+/// The offset of the pitch of the impact bearing, float value, radians.
+pub const REG_DAMAGE_HIT_OFFSET_BEARING_PITCH: u32 = 3;
+/// The offset of the unit id that caused the hit, integer value.
+pub const REG_DAMAGE_HIT_OFFSET_SOURCE: u32 = 4;
+/// The stride of each hit record.
+pub const REG_DAMAGE_HIT_STRIDE: u32 = 5;
+
+/// Sentinel value used for the source if the hit was not caused by a unit, or it is unknown.
+pub const DAMAGE_HIT_SOURCE_UNKNOWN: i32 = -1;
@@ -17,3 +17,12 @@ pub const REG_DIFF_DRIVE_TRACK_WIDTH: u32 = 4;
 pub const REG_DIFF_DRIVE_ACCELERATION_LOWER: u32 = 5;
 /// The limit on the acceleration upper bound, float value.
 pub const REG_DIFF_DRIVE_ACCELERATION_UPPER: u32 = 6;
+
+/// The lower bound on the wheel/track velocity, float value.
+pub const REG_DIFF_DRIVE_VELOCITY_LOWER: u32 = 7;
+/// The upper bound on the wheel/track velocity, float value.
+pub const REG_DIFF_DRIVE_VELOCITY_UPPER: u32 = 8;
+
+/// The integrity of the tracks, float value, 1.0 is undamaged, 0.0 is fully damaged. A damaged
+/// track reduces the velocity bounds.
+pub const REG_DIFF_DRIVE_INTEGRITY: u32 = 9;
@@ -1,5 +1,9 @@
 //! The global position of the unit.
 
+//!
+//! Depending on the scenario, the position may be noisy, biased, updated at a reduced rate, or
+//! unavailable inside gps denied zones.
+
 /// Register holding the x coordinate, float value.
 pub const REG_GPS_X: u32 = 0;
 /// Register holding the y coordinate, float value.
@@ -12,3 +16,8 @@ pub const REG_GPS_ROLL: u32 = 3;
 pub const REG_GPS_PITCH: u32 = 4;
 /// Register holding the yaw, float value, radians.
 pub const REG_GPS_YAW: u32 = 5;
+/// Register denoting whether the fix is current, integer value. Zero inside a gps denied zone,
+/// where the last fix is reported, or NaN values.
+pub const REG_GPS_VALID: u32 = 6;
+/// Register holding the time at which the reported fix was taken, float value, seconds.
+pub const REG_GPS_FIX_TIME: u32 = 7;
@@ -22,6 +22,10 @@ pub const REG_GUN_BATTERY_RELOAD: u32 = 5;
 /// The index of the gun that will fire next.
 pub const REG_GUN_BATTERY_FIRE_INDEX: u32 = 6;
 
+/// The integrity of the gun battery, float value, 1.0 is undamaged, 0.0 is fully damaged. A
+/// damaged gun battery has increased reload times.
+pub const REG_GUN_BATTERY_INTEGRITY: u32 = 7;
+
 /// The number of guns in this battery.
 pub const REG_GUN_BATTERY_COUNT: u32 = 0x1000;
 /// The start of the gun list.
@@ -0,0 +1,17 @@
+//! Inertial measurement unit, angular rates and accelerations in the body frame.
+
+//!
+//! The accelerations exclude gravity.
+
+/// Register holding the angular rate around x, float value, radians per second.
+pub const REG_IMU_ANGULAR_RATE_X: u32 = 0;
+/// Register holding the angular rate around y, float value, radians per second.
+pub const REG_IMU_ANGULAR_RATE_Y: u32 = 1;
+/// Register holding the angular rate around z, float value, radians per second.
+pub const REG_IMU_ANGULAR_RATE_Z: u32 = 2;
+/// Register holding the acceleration along x, float value, meters per second squared.
+pub const REG_IMU_ACCELERATION_X: u32 = 3;
+/// Register holding the acceleration along y, float value, meters per second squared.
+pub const REG_IMU_ACCELERATION_Y: u32 = 4;
+/// Register holding the acceleration along z, float value, meters per second squared.
+pub const REG_IMU_ACCELERATION_Z: u32 = 5;
@@ -0,0 +1,22 @@
+//! Ranges measured by a fan of lidar rays.
+
+//!
+//! The lidar casts a fan of rays in its local xy plane, symmetric around the local x axis. Each
+//! ray reports the distance to the first hit volume it meets, or the maximum range if it hits
+//! nothing. The hit volumes of the unit itself are ignored.
+
+/// The maximum range of the rays, float value.
+pub const REG_LIDAR_RANGE_MAX: u32 = 0x10;
+
+/// The total angle spanned by the fan of rays, float value, radians.
+pub const REG_LIDAR_FAN_ANGLE: u32 = 0x11;
+
+/// The number of rays in the fan, integer value.
+pub const REG_LIDAR_RAY_COUNT: u32 = 0x12;
+
+/// The integrity of the lidar, float value, 1.0 is undamaged, 0.0 is fully damaged.
+pub const REG_LIDAR_INTEGRITY: u32 = 0x13;
+
+/// The measured ranges, bytes value. Each range is a little endian `f32`, ordered from the ray at
+/// yaw `-fan_angle / 2` to the ray at yaw `fan_angle / 2`, spaced evenly.
+pub const REG_LIDAR_RANGES: u32 = 0x1000;
@@ -0,0 +1,28 @@
+//! Deployment of mines at the unit's current position.
+//!
+//! Mines arm after a delay, once armed they detonate when the body of a unit of another team comes
+//! within the trigger radius, dealing splash damage. Mines are only visible to the radar of the team
+//! that deployed them.
+
+/// Deploy a mine, is_triggered will go true, and a mine is deployed as soon as the layer is ready,
+/// then the trigger becomes inactive until activated again.
+pub const REG_MINE_LAYER_DEPLOY: u32 = 0;
+
+/// Denotes if the mine layer has been triggered.
+pub const REG_MINE_LAYER_IS_TRIGGERED: u32 = 1;
+
+/// Denotes if the mine layer is ready to deploy a mine, boolean value. It is not ready if all mines
+/// have been deployed.
+pub const REG_MINE_LAYER_READY: u32 = 2;
+
+/// The number of mines remaining, integer value.
+pub const REG_MINE_LAYER_REMAINING: u32 = 3;
+
+/// The duration between deployment and the mine being armed, float value, seconds.
+pub const REG_MINE_LAYER_ARMING_DELAY: u32 = 4;
+
+/// The horizontal distance from the mine within which a unit's body triggers it, float value.
+pub const REG_MINE_LAYER_TRIGGER_RADIUS: u32 = 5;
+
+/// The minimum duration between two deployments, float value, seconds.
+pub const REG_MINE_LAYER_DEPLOY_INTERVAL: u32 = 6;
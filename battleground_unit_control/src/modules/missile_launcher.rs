@@ -0,0 +1,47 @@
+//! Fire control of guided missile launchers.
+//!
+//! Missiles fly at a constant speed while they have fuel, turning towards their target at a limited
+//! turn rate. Once the fuel runs out the missile follows a ballistic trajectory. The seeker mode and
+//! target are copied into the missile at launch, changing them does not affect missiles in flight.
+
+/// Trigger the launcher to fire, is_triggered will go true, and the launcher will fire as soon as
+/// ready then the trigger becomes inactive until activated again.
+pub const REG_MISSILE_LAUNCHER_TRIGGER: u32 = 0;
+
+/// Denotes if the launcher has been triggered.
+pub const REG_MISSILE_LAUNCHER_IS_TRIGGERED: u32 = 1;
+
+/// Denotes if the launcher is ready to fire, boolean value.
+pub const REG_MISSILE_LAUNCHER_READY: u32 = 2;
+
+/// Provides the reload time in seconds, float value.
+pub const REG_MISSILE_LAUNCHER_RELOAD_TIME: u32 = 3;
+
+/// The seeker mode for the next missile, integer value, one of the `MISSILE_SEEKER_MODE_` values.
+pub const REG_MISSILE_LAUNCHER_SEEKER_MODE: u32 = 4;
+
+/// The target point's x coordinate in the world frame for the point seeker, float value.
+pub const REG_MISSILE_LAUNCHER_TARGET_X: u32 = 5;
+/// The target point's y coordinate in the world frame for the point seeker, float value.
+pub const REG_MISSILE_LAUNCHER_TARGET_Y: u32 = 6;
+/// The target point's z coordinate in the world frame for the point seeker, float value.
+pub const REG_MISSILE_LAUNCHER_TARGET_Z: u32 = 7;
+
+/// The missile's speed while it has fuel, float value, meters per second.
+pub const REG_MISSILE_LAUNCHER_MISSILE_SPEED: u32 = 8;
+/// The missile's maximum turn rate, float value, radians per second.
+pub const REG_MISSILE_LAUNCHER_MISSILE_TURN_RATE: u32 = 9;
+/// The duration the missile has fuel for, float value, seconds.
+pub const REG_MISSILE_LAUNCHER_MISSILE_FUEL: u32 = 10;
+/// The maximum range of the radar seeker, float value.
+pub const REG_MISSILE_LAUNCHER_SEEKER_RANGE: u32 = 11;
+/// The detection angle of the radar seeker, symmetric around the missile's heading, float value,
+/// radians.
+pub const REG_MISSILE_LAUNCHER_SEEKER_ANGLE: u32 = 12;
+
+/// The missile flies straight ahead.
+pub const MISSILE_SEEKER_MODE_NONE: i32 = 0;
+/// The missile steers towards the target point.
+pub const MISSILE_SEEKER_MODE_POINT: i32 = 1;
+/// The missile steers towards the strongest radar reflection in its seeker's cone.
+pub const MISSILE_SEEKER_MODE_RADAR: i32 = 2;
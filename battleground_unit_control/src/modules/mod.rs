@@ -16,14 +16,24 @@
 
 pub mod cannon;
 pub mod clock;
+pub mod commander;
+pub mod damage;
 pub mod differential_drive;
 pub mod draw;
 pub mod gps;
 pub mod gun_battery;
+pub mod imu;
+pub mod lidar;
+pub mod mine_layer;
+pub mod missile_launcher;
 pub mod objectives;
+pub mod odometry;
+pub mod orders;
 pub mod radar;
+pub mod radio_jammer;
 pub mod radio_receiver;
 pub mod radio_transmitter;
 pub mod revolute;
 pub mod team;
 pub mod unit;
+pub mod wind;
@@ -1,5 +1,9 @@
 //! Objective information.
 
+/// Register index denoting whether fog of war is active, integer value. If it is, objectives
+/// that are not observed by the unit's team report unknown values.
+pub const REG_OBJECTIVES_FOG_OF_WAR: u32 = 0x0000;
+
 /// Register index to obtain the capture point count.
 pub const REG_OBJECTIVES_CAPTURE_POINT_COUNT: u32 = 0x1000;
 
@@ -19,3 +23,78 @@ pub const REG_OBJECTIVES_CAPTURE_POINT_STRIDE: u32 = 4;
 
 /// Sentinel value used for the owner if a capture point is unused.
 pub const OBJECTIVES_CAPTURE_POINT_UNOWNED: i32 = -1;
+/// Sentinel value used for the owner if a capture point is not observed by the team.
+pub const OBJECTIVES_CAPTURE_POINT_UNKNOWN: i32 = -2;
+
+/// Register index to obtain the flag count, used by capture the flag matches.
+pub const REG_OBJECTIVES_FLAG_COUNT: u32 = 0x2000;
+
+/// Register start index for the flag list.
+pub const REG_OBJECTIVES_FLAG_START: u32 = 0x2001;
+
+/// Register offset for the x position of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_X: u32 = 0;
+/// Register offset for the y position of a flag, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_Y: u32 = 1;
+/// Register offset for the team the flag belongs to, integer value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_TEAM: u32 = 2;
+/// Register offset for the unit id carrying the flag, integer value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_CARRIER: u32 = 3;
+/// Register offset for the x position of the flag's base, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_BASE_X: u32 = 4;
+/// Register offset for the y position of the flag's base, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_BASE_Y: u32 = 5;
+/// Register offset for the radius of the flag's base, float value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_RADIUS: u32 = 6;
+/// Register offset for the state of the flag, integer value.
+pub const REG_OBJECTIVES_FLAG_OFFSET_STATE: u32 = 7;
+/// Register stride for each flag.
+pub const REG_OBJECTIVES_FLAG_STRIDE: u32 = 8;
+
+/// Sentinel value used for the carrier if a flag is not carried.
+pub const OBJECTIVES_FLAG_NO_CARRIER: i32 = -1;
+/// Sentinel value used for the carrier if a flag is not observed by the team.
+pub const OBJECTIVES_FLAG_CARRIER_UNKNOWN: i32 = -2;
+
+/// Flag state value if the flag is at its base.
+pub const OBJECTIVES_FLAG_STATE_AT_BASE: i32 = 0;
+/// Flag state value if the flag is carried by a unit.
+pub const OBJECTIVES_FLAG_STATE_CARRIED: i32 = 1;
+/// Flag state value if the flag was dropped and lies on the ground.
+pub const OBJECTIVES_FLAG_STATE_DROPPED: i32 = 2;
+/// Flag state value if the flag is not observed by the team, the position is that of its base.
+pub const OBJECTIVES_FLAG_STATE_UNKNOWN: i32 = 3;
+
+/// Register index for the attacking team in an attack defend match, integer value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_ATTACKER: u32 = 0x3000;
+/// Register index for the defending team in an attack defend match, integer value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_DEFENDER: u32 = 0x3001;
+/// Register index for the total number of points to be captured, integer value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_POINT_COUNT: u32 = 0x3002;
+/// Register index for the number of points captured by the attacker, integer value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_CAPTURED: u32 = 0x3003;
+/// Register index for the x position of the point that can currently be captured, float value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_X: u32 = 0x3004;
+/// Register index for the y position of the point that can currently be captured, float value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_Y: u32 = 0x3005;
+/// Register index for the radius of the point that can currently be captured, float value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_RADIUS: u32 = 0x3006;
+/// Register index for the attacker's progress on the active point, between 0.0 and 1.0, float value.
+pub const REG_OBJECTIVES_ATTACK_DEFEND_ACTIVE_PROGRESS: u32 = 0x3007;
+
+/// Register index for the attacking team in a payload match, integer value.
+pub const REG_OBJECTIVES_PAYLOAD_ATTACKER: u32 = 0x4000;
+/// Register index for the defending team in a payload match, integer value.
+pub const REG_OBJECTIVES_PAYLOAD_DEFENDER: u32 = 0x4001;
+/// Register index for the x position of the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_X: u32 = 0x4002;
+/// Register index for the y position of the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_Y: u32 = 0x4003;
+/// Register index for the radius within which units influence the payload, float value.
+pub const REG_OBJECTIVES_PAYLOAD_RADIUS: u32 = 0x4004;
+/// Register index for the distance the payload travelled along its path, float value.
+pub const REG_OBJECTIVES_PAYLOAD_DISTANCE: u32 = 0x4005;
+/// Register index for the total length of the payload's path, float value.
+pub const REG_OBJECTIVES_PAYLOAD_PATH_LENGTH: u32 = 0x4006;
+/// Register index for whether the payload is moving, integer value.
+pub const REG_OBJECTIVES_PAYLOAD_IS_MOVING: u32 = 0x4007;
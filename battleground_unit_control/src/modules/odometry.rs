@@ -0,0 +1,16 @@
+//! Wheel odometry, dead reckoning from the velocity of the base.
+
+//!
+//! The pose is integrated from the measured velocities and is relative to the pose at which the
+//! unit spawned. Noise on the velocities makes the pose drift over time.
+
+/// Register holding the integrated x coordinate, float value.
+pub const REG_ODOMETRY_X: u32 = 0;
+/// Register holding the integrated y coordinate, float value.
+pub const REG_ODOMETRY_Y: u32 = 1;
+/// Register holding the integrated yaw, float value, radians.
+pub const REG_ODOMETRY_YAW: u32 = 2;
+/// Register holding the measured forward velocity, float value, meters per second.
+pub const REG_ODOMETRY_VELOCITY: u32 = 3;
+/// Register holding the measured yaw rate, float value, radians per second.
+pub const REG_ODOMETRY_YAW_RATE: u32 = 4;
@@ -0,0 +1,17 @@
+//! Orders posted by the team commander.
+
+//!
+//! Only teams that have a commander receive orders, the commander posts these through the
+//! [`commander`](super::commander) module. An order is opaque data, its meaning is up to the
+//! controllers of the team.
+
+/// The number of orders received so far, integer value. Increments when a new order is posted.
+pub const REG_ORDERS_SEQUENCE: u32 = 0x10;
+/// The time at which the last order was posted, float value.
+pub const REG_ORDERS_TIME: u32 = 0x11;
+
+/// The last order, bytes value, empty if no order was posted.
+pub const REG_ORDERS_ORDER: u32 = 0x1000;
+
+/// The maximum size of an order, in bytes.
+pub const ORDERS_SIZE_LIMIT: usize = 64;
@@ -12,6 +12,10 @@ pub const REG_RADAR_DETECTION_ANGLE_YAW: u32 = 0x11;
 /// The maximum detection pitch of the radar, float value, radians.
 pub const REG_RADAR_DETECTION_ANGLE_PITCH: u32 = 0x12;
 
+/// The integrity of the radar, float value, 1.0 is undamaged, 0.0 is fully damaged. A damaged
+/// radar has a reduced maximum range.
+pub const REG_RADAR_INTEGRITY: u32 = 0x13;
+
 /// The number of reflection records.
 pub const REG_RADAR_REFLECTION_COUNT: u32 = 0x1000;
 /// The start of the reflection list.
@@ -0,0 +1,14 @@
+//! Jammer that raises the radio noise floor around the unit.
+
+//!
+//! While active, the jammer adds its power to the noise floor of all receivers within its range,
+//! on all channels. Its power falls off with the square of the distance, like a transmission.
+//! Receivers close to the jammer, including the unit's own, will lose most messages.
+
+/// The power of the jammer, float value.
+pub const REG_RADIO_JAMMER_POWER: u32 = 0x10;
+/// The range within which the jammer affects receivers, float value.
+pub const REG_RADIO_JAMMER_RANGE: u32 = 0x11;
+
+/// Whether the jammer is active, integer value, writable.
+pub const REG_RADIO_JAMMER_ACTIVE: u32 = 0x1000;
@@ -9,6 +9,10 @@ pub const REG_RADIO_RX_CHANNEL_MAX: u32 = 0x13;
 /// full, the [`REG_RADIO_RX_MSG_OVERFLOW`] register is incremented by one.
 pub const REG_RADIO_RX_MSG_COUNT_LIMIT: u32 = 0x15;
 
+/// Whether the receiver is capable of direction finding, integer value. If it is, the bearing of
+/// each message is provided.
+pub const REG_RADIO_RX_DIRECTION_FINDING: u32 = 0x16;
+
 /// The currently selected channel, integer value.
 pub const REG_RADIO_RX_CHANNEL_SELECT: u32 = 0x1000;
 
@@ -26,3 +30,8 @@ pub const REG_RADIO_RX_MSG_OFFSET_STRENGTH: u32 = 0;
 pub const REG_RADIO_RX_MSG_OFFSET_DATA: u32 = 1;
 /// The stride of MSGs.
 pub const REG_RADIO_RX_MSG_STRIDE: u32 = 2;
+
+/// The start of the bearings, one per message in the same order, float value, radians. The yaw
+/// towards the transmitter in the body frame, only present if the receiver is capable of
+/// direction finding.
+pub const REG_RADIO_RX_BEARING_START: u32 = 0x3000;
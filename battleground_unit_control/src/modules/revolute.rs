@@ -19,3 +19,7 @@ pub const REG_REVOLUTE_VELOCITY_CMD: u32 = 4;
 pub const REG_REVOLUTE_ACCELERATION_LOWER: u32 = 2;
 /// The upper bound on acceleration, float value.
 pub const REG_REVOLUTE_ACCELERATION_UPPER: u32 = 3;
+
+/// The integrity of the joint, float value, 1.0 is undamaged, 0.0 is fully damaged. A damaged joint
+/// has reduced velocity bounds.
+pub const REG_REVOLUTE_INTEGRITY: u32 = 5;
@@ -0,0 +1,14 @@
+//! Wind and air drag affecting projectiles.
+//!
+//! Ballistic projectiles experience a deceleration of `drag_coefficient * |airspeed| * airspeed`,
+//! where the airspeed is the projectile's velocity minus the wind velocity. The wind may vary over
+//! time.
+
+/// The current wind velocity in the world's x direction, float value, meters per second.
+pub const REG_WIND_X: u32 = 0;
+
+/// The current wind velocity in the world's y direction, float value, meters per second.
+pub const REG_WIND_Y: u32 = 1;
+
+/// The quadratic drag coefficient applied to projectiles, float value.
+pub const REG_WIND_DRAG_COEFFICIENT: u32 = 2;
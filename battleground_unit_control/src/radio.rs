@@ -0,0 +1,789 @@
+//! Typed messaging over the radio modules.
+//!
+//! The radio transmitter only sends small byte messages, see
+//! [`REG_RADIO_TX_MSG_SIZE_LIMIT`](crate::modules::radio_transmitter::REG_RADIO_TX_MSG_SIZE_LIMIT).
+//! The [`RadioLink`] serializes anything implementing [`RadioMessage`], splits it into fragments
+//! that fit in a single transmission and reassembles them on the receiving side. Each message is
+//! tagged with the unit id of the sender, a sequence number and the [`RadioMessage::TAG`] of its
+//! type.
+//!
+//! If an acknowledgement channel is configured, receivers acknowledge each complete message on
+//! that channel and the sender retransmits messages that are not acknowledged in time. A unit only
+//! has a single receiver, so after sending a message it listens on the acknowledgement channel for
+//! the first half of the retry interval, it doesn't receive messages during that time.
+//!
+//! Every fragment starts with a header:
+//! - `kind`, one byte, [`FRAGMENT_KIND_DATA`] or [`FRAGMENT_KIND_ACK`].
+//! - `sender`, four bytes, unit id of the sender.
+//! - `sequence`, two bytes, sequence number of the message.
+//! - `tag`, two bytes, [`RadioMessage::TAG`] of the message type, zero for acknowledgements.
+//! - `index`, one byte, index of this fragment.
+//! - `count`, one byte, number of fragments in this message.
+//!
+//! An acknowledgement carries the sequence number of the acknowledged message and the unit id of
+//! its original sender as payload. All values are little endian.
+//!
+//! [`RadioLink::receive`] returns the oldest message with the tag of the requested type that
+//! decodes as that type, and keeps the others. The built-in types use tags below
+//! [`RADIO_MESSAGE_TAG_USER`], tuples derive their tag from those of their elements and use tags
+//! from [`RADIO_MESSAGE_TAG_TUPLE`] onwards. The tags in between are available to controllers.
+
+use crate::interface::InterfaceError;
+use crate::modules::clock::REG_CLOCK_ELAPSED;
+use crate::modules::radio_receiver::*;
+use crate::modules::radio_transmitter::*;
+use crate::modules::unit::REG_UNIT_UNIT_ID;
+use crate::units::common;
+use crate::Interface;
+use std::collections::VecDeque;
+
+/// Fragment that is part of a message.
+pub const FRAGMENT_KIND_DATA: u8 = 0;
+/// Fragment that acknowledges a message.
+pub const FRAGMENT_KIND_ACK: u8 = 1;
+/// Size of the header in front of each fragment.
+pub const FRAGMENT_HEADER_SIZE: usize = 11;
+/// Maximum number of fragments a single message can be split into.
+pub const FRAGMENT_COUNT_MAX: usize = u8::MAX as usize;
+
+/// Number of incomplete messages held, the oldest is discarded if more messages are incomplete.
+const PARTIAL_LIMIT: usize = 16;
+/// Number of completed messages remembered, to discard retransmissions.
+const RECENT_LIMIT: usize = 64;
+/// Number of received messages held, the oldest is discarded if more messages are received.
+const RECEIVED_LIMIT: usize = 64;
+
+/// Serializes values into bytes.
+#[derive(Debug, Default, Clone)]
+pub struct Writer {
+    data: Vec<u8>,
+}
+
+/// Deserializes values from bytes, returns `None` if the data is exhausted.
+#[derive(Debug, Clone)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+macro_rules! numeric_access {
+    ($t:ty, $put:ident, $get:ident) => {
+        impl Writer {
+            pub fn $put(&mut self, value: $t) {
+                self.data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        impl<'a> Reader<'a> {
+            pub fn $get(&mut self) -> Option<$t> {
+                let v = self.take(std::mem::size_of::<$t>())?;
+                Some(<$t>::from_le_bytes(v.try_into().unwrap()))
+            }
+        }
+    };
+}
+numeric_access!(u8, put_u8, get_u8);
+numeric_access!(u16, put_u16, get_u16);
+numeric_access!(u32, put_u32, get_u32);
+numeric_access!(i32, put_i32, get_i32);
+numeric_access!(f32, put_f32, get_f32);
+
+impl Writer {
+    pub fn new() -> Self {
+        Writer::default()
+    }
+
+    /// Write bytes, prefixed by their length.
+    pub fn put_bytes(&mut self, value: &[u8]) {
+        let value = &value[..value.len().min(u16::MAX as usize)];
+        self.put_u16(value.len() as u16);
+        self.data.extend_from_slice(value);
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data, position: 0 }
+    }
+
+    /// Read bytes written by [`Writer::put_bytes`].
+    pub fn get_bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.get_u16()? as usize;
+        self.take(len)
+    }
+
+    /// Whether all data has been read.
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.position.checked_add(len)?;
+        let v = self.data.get(self.position..end)?;
+        self.position = end;
+        Some(v)
+    }
+}
+
+/// First tag available for message types defined by controllers.
+pub const RADIO_MESSAGE_TAG_USER: u16 = 0x100;
+/// First tag used by tuples.
+pub const RADIO_MESSAGE_TAG_TUPLE: u16 = 0x8000;
+
+/// A value that can be sent over the [`RadioLink`].
+pub trait RadioMessage: Sized {
+    /// Identifies the type of the message, such that types with the same encoding are told
+    /// apart. Should be unique among the types sent on a channel.
+    const TAG: u16;
+    fn encode(&self, writer: &mut Writer);
+    fn decode(reader: &mut Reader) -> Option<Self>;
+}
+
+/// Tag of a tuple, derived from the tags of its elements.
+const fn tuple_tag(tags: &[u16]) -> u16 {
+    let mut tag: u16 = 0x811c;
+    let mut i = 0;
+    while i < tags.len() {
+        tag = (tag ^ tags[i]).wrapping_mul(0x0193);
+        i += 1;
+    }
+    tag | RADIO_MESSAGE_TAG_TUPLE
+}
+
+macro_rules! numeric_message {
+    ($t:ty, $tag:expr, $put:ident, $get:ident) => {
+        impl RadioMessage for $t {
+            const TAG: u16 = $tag;
+            fn encode(&self, writer: &mut Writer) {
+                writer.$put(*self);
+            }
+            fn decode(reader: &mut Reader) -> Option<Self> {
+                reader.$get()
+            }
+        }
+    };
+}
+numeric_message!(u8, 1, put_u8, get_u8);
+numeric_message!(u16, 2, put_u16, get_u16);
+numeric_message!(u32, 3, put_u32, get_u32);
+numeric_message!(i32, 4, put_i32, get_i32);
+numeric_message!(f32, 5, put_f32, get_f32);
+
+impl RadioMessage for Vec<u8> {
+    const TAG: u16 = 6;
+    fn encode(&self, writer: &mut Writer) {
+        writer.put_bytes(self);
+    }
+    fn decode(reader: &mut Reader) -> Option<Self> {
+        reader.get_bytes().map(|v| v.to_vec())
+    }
+}
+
+impl RadioMessage for String {
+    const TAG: u16 = 7;
+    fn encode(&self, writer: &mut Writer) {
+        writer.put_bytes(self.as_bytes());
+    }
+    fn decode(reader: &mut Reader) -> Option<Self> {
+        String::from_utf8(reader.get_bytes()?.to_vec()).ok()
+    }
+}
+
+impl<A: RadioMessage, B: RadioMessage> RadioMessage for (A, B) {
+    const TAG: u16 = tuple_tag(&[A::TAG, B::TAG]);
+    fn encode(&self, writer: &mut Writer) {
+        self.0.encode(writer);
+        self.1.encode(writer);
+    }
+    fn decode(reader: &mut Reader) -> Option<Self> {
+        Some((A::decode(reader)?, B::decode(reader)?))
+    }
+}
+
+impl<A: RadioMessage, B: RadioMessage, C: RadioMessage> RadioMessage for (A, B, C) {
+    const TAG: u16 = tuple_tag(&[A::TAG, B::TAG, C::TAG]);
+    fn encode(&self, writer: &mut Writer) {
+        self.0.encode(writer);
+        self.1.encode(writer);
+        self.2.encode(writer);
+    }
+    fn decode(reader: &mut Reader) -> Option<Self> {
+        Some((A::decode(reader)?, B::decode(reader)?, C::decode(reader)?))
+    }
+}
+
+/// Configuration of the [`RadioLink`].
+#[derive(Debug, Clone, Copy)]
+pub struct RadioLinkConfig {
+    /// Module id of the radio transmitter.
+    pub transmitter: u32,
+    /// Module id of the radio receiver.
+    pub receiver: u32,
+    /// Channel on which messages are sent.
+    pub data_channel: usize,
+    /// Channel on which messages are acknowledged, messages are not acknowledged if `None`.
+    pub ack_channel: Option<usize>,
+    /// Time after which an unacknowledged message is sent again.
+    pub retry_interval: f32,
+    /// Number of times a message is sent again before it is considered lost.
+    pub retry_limit: usize,
+}
+
+impl Default for RadioLinkConfig {
+    fn default() -> Self {
+        RadioLinkConfig {
+            transmitter: common::MODULE_RADIO_TRANSMITTER,
+            receiver: common::MODULE_RADIO_RECEIVER,
+            data_channel: 0,
+            ack_channel: None,
+            retry_interval: 0.5,
+            retry_limit: 3,
+        }
+    }
+}
+
+/// A message that was received.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Received<M> {
+    /// Unit id of the sender.
+    pub sender: u32,
+    /// Sequence number assigned by the sender.
+    pub sequence: u16,
+    pub message: M,
+}
+
+#[derive(Debug, Clone)]
+struct Unacknowledged {
+    sequence: u16,
+    tag: u16,
+    data: Vec<u8>,
+    sent: f32,
+    retries: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Partial {
+    sender: u32,
+    sequence: u16,
+    tag: u16,
+    fragments: Vec<Option<Vec<u8>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Header {
+    kind: u8,
+    sender: u32,
+    sequence: u16,
+    tag: u16,
+    index: u8,
+    count: u8,
+}
+
+impl Header {
+    fn parse(fragment: &[u8]) -> Option<(Header, &[u8])> {
+        let mut reader = Reader::new(fragment);
+        let header = Header {
+            kind: reader.get_u8()?,
+            sender: reader.get_u32()?,
+            sequence: reader.get_u16()?,
+            tag: reader.get_u16()?,
+            index: reader.get_u8()?,
+            count: reader.get_u8()?,
+        };
+        Some((header, &fragment[FRAGMENT_HEADER_SIZE..]))
+    }
+
+    fn write(&self, payload: &[u8]) -> Vec<u8> {
+        let mut writer = Writer::new();
+        writer.put_u8(self.kind);
+        writer.put_u32(self.sender);
+        writer.put_u16(self.sequence);
+        writer.put_u16(self.tag);
+        writer.put_u8(self.index);
+        writer.put_u8(self.count);
+        let mut v = writer.into_bytes();
+        v.extend_from_slice(payload);
+        v
+    }
+}
+
+/// Split a message into fragments no larger than the size limit, `None` if it needs too many or
+/// the size limit leaves no room for a payload.
+fn fragment(
+    sender: u32,
+    sequence: u16,
+    tag: u16,
+    data: &[u8],
+    size_limit: usize,
+) -> Option<Vec<Vec<u8>>> {
+    let payload_size = size_limit
+        .checked_sub(FRAGMENT_HEADER_SIZE)
+        .filter(|v| *v > 0)?;
+    let count = data.len().div_ceil(payload_size).max(1);
+    if count > FRAGMENT_COUNT_MAX {
+        return None;
+    }
+    let fragments = (0..count)
+        .map(|index| {
+            let start = index * payload_size;
+            let end = (start + payload_size).min(data.len());
+            Header {
+                kind: FRAGMENT_KIND_DATA,
+                sender,
+                sequence,
+                tag,
+                index: index as u8,
+                count: count as u8,
+            }
+            .write(&data[start..end])
+        })
+        .collect();
+    Some(fragments)
+}
+
+/// Sends and receives typed messages using the radio transmitter and receiver, call
+/// [`RadioLink::update`] from the controller's update.
+#[derive(Debug, Clone)]
+pub struct RadioLink {
+    config: RadioLinkConfig,
+    unit_id: Option<u32>,
+    next_sequence: u16,
+    queued: VecDeque<(u16, u16, Vec<u8>)>,
+    outgoing: VecDeque<Vec<u8>>,
+    acks: VecDeque<Vec<u8>>,
+    unacknowledged: Vec<Unacknowledged>,
+    partial: VecDeque<Partial>,
+    recent: VecDeque<(u32, u16)>,
+    /// Received messages with their tag.
+    received: VecDeque<(u16, Received<Vec<u8>>)>,
+    failed: usize,
+}
+
+impl RadioLink {
+    pub fn new(config: RadioLinkConfig) -> Self {
+        RadioLink {
+            config,
+            unit_id: None,
+            next_sequence: 0,
+            queued: Default::default(),
+            outgoing: Default::default(),
+            acks: Default::default(),
+            unacknowledged: vec![],
+            partial: Default::default(),
+            recent: Default::default(),
+            received: Default::default(),
+            failed: 0,
+        }
+    }
+
+    pub fn config(&self) -> RadioLinkConfig {
+        self.config
+    }
+
+    /// Queue a message for sending, returns the sequence number assigned to it.
+    pub fn send<M: RadioMessage>(&mut self, message: &M) -> u16 {
+        let mut writer = Writer::new();
+        message.encode(&mut writer);
+        let sequence = self.next_sequence;
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        self.queued
+            .push_back((sequence, M::TAG, writer.into_bytes()));
+        sequence
+    }
+
+    /// Retrieve the oldest received message with the tag of `M` that decodes as `M` using all of
+    /// its data, other messages are kept for retrieval as another type.
+    pub fn receive<M: RadioMessage>(&mut self) -> Option<Received<M>> {
+        let (position, message) =
+            self.received
+                .iter()
+                .enumerate()
+                .find_map(|(i, (tag, received))| {
+                    if *tag != M::TAG {
+                        return None;
+                    }
+                    let mut reader = Reader::new(&received.message);
+                    let message = M::decode(&mut reader).filter(|_| reader.is_empty())?;
+                    Some((i, message))
+                })?;
+        let (_tag, received) = self.received.remove(position).unwrap();
+        Some(Received {
+            sender: received.sender,
+            sequence: received.sequence,
+            message,
+        })
+    }
+
+    /// Number of received messages that have not been retrieved.
+    pub fn pending(&self) -> usize {
+        self.received.len()
+    }
+
+    /// Discard the received messages that have not been retrieved.
+    pub fn clear_received(&mut self) {
+        self.received.clear();
+    }
+
+    /// Number of messages sent that are still awaiting acknowledgement.
+    pub fn unacknowledged(&self) -> usize {
+        self.unacknowledged.len()
+    }
+
+    /// Number of messages that were never acknowledged or were too large to send.
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Process received fragments, retransmit and transmit queued fragments.
+    pub fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<InterfaceError>> {
+        let unit_id = match self.unit_id {
+            Some(v) => v,
+            None => {
+                let v = interface.get_i32(common::MODULE_UNIT, REG_UNIT_UNIT_ID)? as u32;
+                self.unit_id = Some(v);
+                v
+            }
+        };
+        let time = interface.get_f32(common::MODULE_CLOCK, REG_CLOCK_ELAPSED)?;
+
+        // Receive all fragments and clear the receiver.
+        let receiver = self.config.receiver;
+        let count = interface.get_i32(receiver, REG_RADIO_RX_MSG_COUNT)?.max(0) as u32;
+        for i in 0..count {
+            let register =
+                REG_RADIO_RX_MSG_START + i * REG_RADIO_RX_MSG_STRIDE + REG_RADIO_RX_MSG_OFFSET_DATA;
+            let mut fragment = vec![0; interface.get_bytes_len(receiver, register)?];
+            interface.get_bytes(receiver, register, &mut fragment)?;
+            self.handle_fragment(unit_id, &fragment);
+        }
+        if count != 0 {
+            interface.set_i32(receiver, REG_RADIO_RX_MSG_COUNT, 0)?;
+        }
+
+        // Fragment the queued messages and retransmit unacknowledged ones.
+        let transmitter = self.config.transmitter;
+        let size_limit = interface
+            .get_i32(transmitter, REG_RADIO_TX_MSG_SIZE_LIMIT)?
+            .max(0);
+        self.prepare_outgoing(unit_id, time, size_limit as usize);
+
+        // Transmit, acknowledgements and data are on different channels so only append to the
+        // pending transmissions if these are on the same channel.
+        let (channel, from_acks) = match self.config.ack_channel {
+            Some(ack_channel) if !self.acks.is_empty() => (ack_channel, true),
+            _ => (self.config.data_channel, false),
+        };
+        let pending = interface
+            .get_i32(transmitter, REG_RADIO_TX_MSG_COUNT)?
+            .max(0) as u32;
+        let current = interface
+            .get_i32(transmitter, REG_RADIO_TX_CHANNEL_SELECT)?
+            .max(0) as usize;
+        if pending == 0 || current == channel {
+            let limit = interface
+                .get_i32(transmitter, REG_RADIO_TX_MSG_COUNT_LIMIT)?
+                .max(0) as u32;
+            let source = if from_acks {
+                &mut self.acks
+            } else {
+                &mut self.outgoing
+            };
+            let mut count = pending;
+            while count < limit {
+                if let Some(fragment) = source.pop_front() {
+                    interface.set_bytes(transmitter, REG_RADIO_TX_MSG_START + count, &fragment)?;
+                    count += 1;
+                } else {
+                    break;
+                }
+            }
+            if count != pending {
+                interface.set_i32(transmitter, REG_RADIO_TX_CHANNEL_SELECT, channel as i32)?;
+                interface.set_i32(transmitter, REG_RADIO_TX_MSG_COUNT, count as i32)?;
+            }
+        }
+
+        // Once all fragments are handed to the transmitter, listen for the acknowledgements
+        // during the first half of the retry interval.
+        let retry_interval = self.config.retry_interval;
+        let awaiting = self.outgoing.is_empty()
+            && self
+                .unacknowledged
+                .iter()
+                .any(|v| time - v.sent < retry_interval / 2.0);
+        let listen_channel = match self.config.ack_channel {
+            Some(ack_channel) if awaiting => ack_channel,
+            _ => self.config.data_channel,
+        };
+        interface.set_i32(receiver, REG_RADIO_RX_CHANNEL_SELECT, listen_channel as i32)?;
+        Ok(())
+    }
+
+    fn prepare_outgoing(&mut self, unit_id: u32, time: f32, size_limit: usize) {
+        let acknowledged = self.config.ack_channel.is_some();
+        while let Some((sequence, tag, data)) = self.queued.pop_front() {
+            if let Some(fragments) = fragment(unit_id, sequence, tag, &data, size_limit) {
+                self.outgoing.extend(fragments);
+                if acknowledged {
+                    self.unacknowledged.push(Unacknowledged {
+                        sequence,
+                        tag,
+                        data,
+                        sent: time,
+                        retries: 0,
+                    });
+                }
+            } else {
+                self.failed += 1;
+            }
+        }
+
+        let retry_interval = self.config.retry_interval;
+        let retry_limit = self.config.retry_limit;
+        let mut failed = 0;
+        let outgoing = &mut self.outgoing;
+        self.unacknowledged.retain_mut(|v| {
+            if time - v.sent < retry_interval {
+                return true;
+            }
+            if v.retries >= retry_limit {
+                failed += 1;
+                return false;
+            }
+            v.retries += 1;
+            v.sent = time;
+            if let Some(fragments) = fragment(unit_id, v.sequence, v.tag, &v.data, size_limit) {
+                outgoing.extend(fragments);
+            }
+            true
+        });
+        self.failed += failed;
+    }
+
+    fn handle_fragment(&mut self, unit_id: u32, fragment: &[u8]) {
+        let (header, payload) = if let Some(v) = Header::parse(fragment) {
+            v
+        } else {
+            return;
+        };
+
+        if header.kind == FRAGMENT_KIND_ACK {
+            if Reader::new(payload).get_u32() == Some(unit_id) {
+                self.unacknowledged
+                    .retain(|v| v.sequence != header.sequence);
+            }
+            return;
+        }
+        if header.kind != FRAGMENT_KIND_DATA || header.index >= header.count {
+            return;
+        }
+
+        let key = (header.sender, header.sequence);
+        if self.recent.contains(&key) {
+            // Already complete, the acknowledgement may have been lost, so send it again.
+            if header.index + 1 == header.count {
+                self.acknowledge(unit_id, header);
+            }
+            return;
+        }
+
+        let position = self
+            .partial
+            .iter()
+            .position(|v| v.sender == header.sender && v.sequence == header.sequence);
+        let position = match position {
+            Some(p)
+                if self.partial[p].fragments.len() == header.count as usize
+                    && self.partial[p].tag == header.tag =>
+            {
+                p
+            }
+            _ => {
+                if let Some(p) = position {
+                    // The fragments changed, the sequence number must have wrapped.
+                    self.partial.remove(p);
+                }
+                if self.partial.len() >= PARTIAL_LIMIT {
+                    self.partial.pop_front();
+                }
+                self.partial.push_back(Partial {
+                    sender: header.sender,
+                    sequence: header.sequence,
+                    tag: header.tag,
+                    fragments: vec![None; header.count as usize],
+                });
+                self.partial.len() - 1
+            }
+        };
+
+        let partial = &mut self.partial[position];
+        partial.fragments[header.index as usize] = Some(payload.to_vec());
+        if partial.fragments.iter().any(|v| v.is_none()) {
+            return;
+        }
+
+        let partial = self.partial.remove(position).unwrap();
+        let message = partial.fragments.into_iter().flatten().flatten().collect();
+        if self.received.len() >= RECEIVED_LIMIT {
+            self.received.pop_front();
+        }
+        self.received.push_back((
+            header.tag,
+            Received {
+                sender: header.sender,
+                sequence: header.sequence,
+                message,
+            },
+        ));
+        if self.recent.len() >= RECENT_LIMIT {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(key);
+        self.acknowledge(unit_id, header);
+    }
+
+    fn acknowledge(&mut self, unit_id: u32, header: Header) {
+        if self.config.ack_channel.is_none() {
+            return;
+        }
+        let ack = Header {
+            kind: FRAGMENT_KIND_ACK,
+            sender: unit_id,
+            sequence: header.sequence,
+            tag: 0,
+            index: 0,
+            count: 1,
+        };
+        self.acks.push_back(ack.write(&header.sender.to_le_bytes()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radio_message_encoding() {
+        let value = (3i32, 1.5f32, String::from("tank"));
+        let mut writer = Writer::new();
+        value.encode(&mut writer);
+        let data = writer.into_bytes();
+        let mut reader = Reader::new(&data);
+        assert_eq!(<(i32, f32, String)>::decode(&mut reader), Some(value));
+        assert!(reader.is_empty());
+
+        // Running out of data fails to decode.
+        let mut reader = Reader::new(&data[..6]);
+        assert_eq!(<(i32, f32, String)>::decode(&mut reader), None);
+    }
+
+    #[test]
+    fn test_radio_link_fragments_and_acknowledges() {
+        let config = RadioLinkConfig {
+            ack_channel: Some(1),
+            ..Default::default()
+        };
+        let mut sender = RadioLink::new(config);
+        let mut receiver = RadioLink::new(config);
+
+        let message = (0..100u8).collect::<Vec<u8>>();
+        let sequence = sender.send(&message);
+        sender.prepare_outgoing(7, 0.0, 32);
+        assert_eq!(sender.unacknowledged(), 1);
+        let fragments = sender.outgoing.drain(..).collect::<Vec<_>>();
+        assert_eq!(fragments.len(), 5);
+        assert!(fragments.iter().all(|v| v.len() <= 32));
+
+        // Fragments may arrive out of order, and duplicates are discarded.
+        for fragment in fragments.iter().rev().chain(fragments.iter()) {
+            receiver.handle_fragment(8, fragment);
+        }
+        let received = receiver.receive::<Vec<u8>>().unwrap();
+        assert_eq!(received.sender, 7);
+        assert_eq!(received.sequence, sequence);
+        assert_eq!(received.message, message);
+        assert!(receiver.receive::<Vec<u8>>().is_none());
+
+        // The receiver acknowledged the message, and again for the duplicate last fragment.
+        assert_eq!(receiver.acks.len(), 2);
+        let ack = receiver.acks.pop_front().unwrap();
+        sender.handle_fragment(7, &ack);
+        assert_eq!(sender.unacknowledged(), 0);
+        assert_eq!(sender.failed(), 0);
+    }
+
+    #[test]
+    fn test_radio_link_retry() {
+        let config = RadioLinkConfig {
+            ack_channel: Some(1),
+            retry_interval: 0.5,
+            retry_limit: 2,
+            ..Default::default()
+        };
+        let mut sender = RadioLink::new(config);
+        sender.send(&5i32);
+        sender.prepare_outgoing(7, 0.0, 32);
+        assert_eq!(sender.outgoing.len(), 1);
+        sender.prepare_outgoing(7, 0.25, 32);
+        assert_eq!(sender.outgoing.len(), 1);
+        sender.prepare_outgoing(7, 0.5, 32);
+        sender.prepare_outgoing(7, 1.0, 32);
+        assert_eq!(sender.outgoing.len(), 3);
+        sender.prepare_outgoing(7, 1.5, 32);
+        assert_eq!(sender.unacknowledged(), 0);
+        assert_eq!(sender.failed(), 1);
+    }
+
+    #[test]
+    fn test_radio_link_receive_by_type() {
+        let mut sender = RadioLink::new(Default::default());
+        let mut receiver = RadioLink::new(Default::default());
+        sender.send(&String::from("tank"));
+        sender.send(&5i32);
+        sender.prepare_outgoing(7, 0.0, 32);
+        for fragment in sender.outgoing.drain(..) {
+            receiver.handle_fragment(8, &fragment);
+        }
+        assert_eq!(receiver.pending(), 2);
+
+        // Messages of another type are kept.
+        assert_eq!(receiver.receive::<i32>().unwrap().message, 5);
+        assert!(receiver.receive::<i32>().is_none());
+        assert_eq!(receiver.receive::<String>().unwrap().message, "tank");
+        assert_eq!(receiver.pending(), 0);
+
+        // Types with the same encoding are told apart by their tag.
+        sender.send(&5i32);
+        sender.send(&1.5f32);
+        sender.prepare_outgoing(7, 0.0, 32);
+        for fragment in sender.outgoing.drain(..) {
+            receiver.handle_fragment(8, &fragment);
+        }
+        assert!(receiver.receive::<u32>().is_none());
+        assert_eq!(receiver.receive::<f32>().unwrap().message, 1.5);
+        assert!(receiver.receive::<f32>().is_none());
+        assert_eq!(receiver.receive::<i32>().unwrap().message, 5);
+
+        // Tuples of differently ordered elements differ too.
+        assert_ne!(<(i32, f32)>::TAG, <(f32, i32)>::TAG);
+        assert_ne!(<(i32, f32)>::TAG, <(i32, f32, u8)>::TAG);
+    }
+
+    #[test]
+    fn test_radio_link_fragment_size() {
+        assert!(fragment(7, 0, 1, &[1, 2, 3], FRAGMENT_HEADER_SIZE).is_none());
+        assert!(fragment(7, 0, 1, &[1, 2, 3], FRAGMENT_HEADER_SIZE - 1).is_none());
+        let fragments = fragment(7, 0, 1, &[1, 2, 3], FRAGMENT_HEADER_SIZE + 1).unwrap();
+        assert_eq!(fragments.len(), 3);
+
+        // Messages that can't be fragmented count as failed.
+        let mut sender = RadioLink::new(Default::default());
+        sender.send(&5i32);
+        sender.prepare_outgoing(7, 0.0, FRAGMENT_HEADER_SIZE);
+        assert!(sender.outgoing.is_empty());
+        assert_eq!(sender.failed(), 1);
+    }
+}
@@ -1,7 +1,7 @@
 use engine::World;
 
 type BoxedError = Box<crate::interface::InterfaceError>;
-use crate::interface::InterfaceErrorType;
+use crate::interface::{InterfaceErrorType, RegisterAccess, RegisterMetadata};
 
 /// A register value record.
 #[derive(Debug)]
@@ -15,11 +15,12 @@ pub enum Value {
     },
 }
 
-/// A register, with a name and a value.
+/// A register, with a name, a value and metadata describing it.
 #[derive(Debug)]
 pub struct Register {
     name: String,
     value: Value,
+    metadata: RegisterMetadata,
 }
 
 impl Register {
@@ -60,6 +61,7 @@ impl Register {
         Register {
             name: name.to_owned(),
             value: Value::F32(value),
+            metadata: Default::default(),
         }
     }
 
@@ -68,6 +70,7 @@ impl Register {
         Register {
             name: name.to_owned(),
             value: Value::I32(value),
+            metadata: Default::default(),
         }
     }
 
@@ -80,6 +83,7 @@ impl Register {
                 min_len: 0,
                 max_len: std::usize::MAX,
             },
+            metadata: Default::default(),
         }
     }
 
@@ -92,8 +96,38 @@ impl Register {
                 min_len: 0,
                 max_len,
             },
+            metadata: Default::default(),
         }
     }
+
+    /// Mark the register as read only, writes by the controller fail.
+    pub fn read_only(mut self) -> Self {
+        self.metadata.access = RegisterAccess::ReadOnly;
+        self
+    }
+
+    /// Set the physical unit of the value.
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.metadata.unit = unit.into();
+        self
+    }
+
+    /// Set the valid range of the value, both bounds inclusive.
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.metadata.range = Some((min, max));
+        self
+    }
+
+    /// Set the description of the register.
+    pub fn with_description(mut self, description: &'static str) -> Self {
+        self.metadata.description = description.into();
+        self
+    }
+
+    /// Retrieve the metadata of this register.
+    pub fn metadata(&self) -> &RegisterMetadata {
+        &self.metadata
+    }
 }
 
 /// Type that the unit modules will populate and read from.
@@ -124,7 +158,7 @@ impl Module {
     ) -> Result<(), BoxedError> {
         for reg_id in interface.registers(module)? {
             let name = interface.register_name(module, reg_id)?;
-            let reg = match interface.register_type(module, reg_id)? {
+            let mut reg = match interface.register_type(module, reg_id)? {
                 crate::RegisterType::I32 => {
                     Register::new_i32(&name, interface.get_i32(module, reg_id)?)
                 }
@@ -141,6 +175,7 @@ impl Module {
                 }
             };
 
+            reg.metadata = interface.register_metadata(module, reg_id)?;
             self.registers.insert(reg_id, reg);
         }
         Ok(())
@@ -152,6 +187,9 @@ impl Module {
     ) -> Result<(), BoxedError> {
         for (k, v) in self.registers.iter() {
             let register = *k;
+            if v.metadata.access == RegisterAccess::ReadOnly {
+                continue;
+            }
             match v.value {
                 Value::I32(v) => {
                     interface.set_i32(module, register, v)?;
@@ -310,6 +348,23 @@ impl RegisterInterface {
         }
     }
 
+    /// Retrieve a register for a write by the controller, fails if the register is read only.
+    fn get_register_writable(
+        &mut self,
+        module: ModuleId,
+        register_index: RegisterId,
+    ) -> Result<&mut Register, BoxedError> {
+        let reg = self.get_register_mut(module, register_index)?;
+        if reg.metadata.access == RegisterAccess::ReadOnly {
+            return Err(Self::interface_error(
+                module,
+                register_index,
+                InterfaceErrorType::ReadOnly,
+            ));
+        }
+        Ok(reg)
+    }
+
     fn interface_error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
         Box::new(InterfaceError {
             module,
@@ -357,6 +412,15 @@ impl crate::interface::Interface for RegisterInterface {
         })
     }
 
+    /// Retrieve the metadata of a register.
+    fn register_metadata(
+        &self,
+        module: u32,
+        register: u32,
+    ) -> Result<RegisterMetadata, BoxedError> {
+        Ok(self.get_register(module, register)?.metadata.clone())
+    }
+
     /// Get an f32 register.
     fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
         let r = self.get_register(module, register)?;
@@ -384,7 +448,7 @@ impl crate::interface::Interface for RegisterInterface {
 
     /// Set an f32 register.
     fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
-        let r = self.get_register_mut(module, register)?;
+        let r = self.get_register_writable(module, register)?;
         match &mut r.value {
             Value::F32(v) => {
                 let old = *v;
@@ -401,7 +465,7 @@ impl crate::interface::Interface for RegisterInterface {
 
     /// Set an i32 register.
     fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
-        let r = self.get_register_mut(module, register)?;
+        let r = self.get_register_writable(module, register)?;
         match &mut r.value {
             Value::I32(v) => {
                 let old = *v;
@@ -466,7 +530,7 @@ impl crate::interface::Interface for RegisterInterface {
         register: u32,
         input_values: &[u8],
     ) -> Result<(), BoxedError> {
-        let r = self.get_register_mut(module, register)?;
+        let r = self.get_register_writable(module, register)?;
         match &mut r.value {
             Value::Bytes {
                 ref mut values,
@@ -500,3 +564,70 @@ impl crate::interface::Interface for RegisterInterface {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Interface;
+
+    struct TestModule {}
+    impl UnitModule for TestModule {
+        fn get_registers(&self, _world: &World, registers: &mut RegisterMap) {
+            registers.insert(0, Register::new_f32("f32", 1.0).read_only());
+            registers.insert(1, Register::new_i32("i32", 2).read_only());
+            registers.insert(2, Register::new_bytes("bytes").read_only());
+            registers.insert(3, Register::new_f32("cmd", 0.0));
+        }
+    }
+
+    fn interface() -> RegisterInterface {
+        let mut interface = RegisterInterface::new();
+        interface.add_module("test", 5, TestModule {});
+        interface.get_registers(&mut World::new());
+        interface
+    }
+
+    #[test]
+    fn test_read_only_registers() {
+        let mut interface = interface();
+        let is_read_only = |e: BoxedError| e.error_type == InterfaceErrorType::ReadOnly;
+        assert!(is_read_only(interface.set_f32(5, 0, 3.0).unwrap_err()));
+        assert!(is_read_only(interface.set_i32(5, 1, 3).unwrap_err()));
+        assert!(is_read_only(interface.set_bytes(5, 2, &[1]).unwrap_err()));
+        assert_eq!(interface.get_f32(5, 0).unwrap(), 1.0);
+        assert_eq!(interface.get_i32(5, 1).unwrap(), 2);
+        assert_eq!(interface.get_bytes_len(5, 2).unwrap(), 0);
+        assert_eq!(interface.set_f32(5, 3, 3.0).unwrap(), 0.0);
+        assert_eq!(
+            interface.register_metadata(5, 0).unwrap().access,
+            RegisterAccess::ReadOnly
+        );
+        assert_eq!(
+            interface.register_metadata(5, 3).unwrap().access,
+            RegisterAccess::ReadWrite
+        );
+    }
+
+    #[test]
+    fn test_write_interface_skips_read_only() {
+        // The controller side copy, with all values changed.
+        let mut controller = RegisterInterface::new();
+        controller.read_interface(&interface()).unwrap();
+        let module = controller.modules.get_mut(&5).unwrap();
+        for register in module.registers.values_mut() {
+            match &mut register.value {
+                Value::F32(v) => *v = 7.0,
+                Value::I32(v) => *v = 7,
+                Value::Bytes { values, .. } => values.push(7),
+            }
+        }
+
+        // Writing back must not touch the read only registers, which would fail.
+        let mut unit = interface();
+        controller.write_interface(&mut unit).unwrap();
+        assert_eq!(unit.get_f32(5, 0).unwrap(), 1.0);
+        assert_eq!(unit.get_i32(5, 1).unwrap(), 2);
+        assert_eq!(unit.get_bytes_len(5, 2).unwrap(), 0);
+        assert_eq!(unit.get_f32(5, 3).unwrap(), 7.0);
+    }
+}
@@ -0,0 +1,781 @@
+//! Typed handles over the register interface.
+//!
+//! Instead of computing register indices by hand, a controller wraps the [`Interface`] in a
+//! [`Tank`] or [`Artillery`] and retrieves handles to its modules:
+//! ```no_run
+//! use battleground_unit_control::sdk::{Tank, Unit};
+//! use battleground_unit_control::{Interface, UnitControl};
+//!
+//! struct Hunter {
+//!     validated: bool,
+//! }
+//! impl UnitControl for Hunter {
+//!     fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+//!         // The modules don't change, so they only need to be verified on the first update.
+//!         if !self.validated {
+//!             Tank::validate(interface)?;
+//!             self.validated = true;
+//!         }
+//!         let mut tank = Tank::new_unchecked(interface);
+//!         tank.radar_rotation().set_velocity(1.0)?;
+//!         if let Some(closest) = tank.radar().reflections()?.first() {
+//!             tank.turret().set_velocity(closest.yaw)?;
+//!         }
+//!         let [x, y, z] = tank.gps().position()?;
+//!         tank.draw().line([0.0, 0.0, z], [x, y, z], 0.05, [255, 0, 0, 255])?;
+//!         Ok(())
+//!     }
+//! }
+//! ```
+//!
+//! Each handle lists the registers it accesses, [`Tank::validate`] and [`Artillery::validate`]
+//! verify these against the unit's modules, so a mismatch between the handles and the module
+//! constants shows up as an error on the first update instead of wrong values later on. This reads
+//! the type of every register, so it is done once and the interface is wrapped with
+//! [`Tank::new_unchecked`] afterwards, [`Tank::new`] validates on every call. All handles only use
+//! the [`Interface`], so they work for both wasm and native controllers.
+
+use crate::interface::{Interface, InterfaceError, InterfaceErrorType};
+use crate::modules::*;
+use crate::units::{artillery, common, tank, UnitType};
+use crate::RegisterType;
+
+type BoxedError = Box<InterfaceError>;
+
+use RegisterType::{Bytes, F32, I32};
+
+fn error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
+    Box::new(InterfaceError {
+        module,
+        register,
+        error_type,
+    })
+}
+
+fn read_bytes(
+    interface: &dyn Interface,
+    module: u32,
+    register: u32,
+) -> Result<Vec<u8>, BoxedError> {
+    let mut v = vec![0; interface.get_bytes_len(module, register)?];
+    let len = interface.get_bytes(module, register, &mut v)?;
+    v.truncate(len);
+    Ok(v)
+}
+
+/// Check that the registers of a module exist with the expected types.
+fn validate(
+    interface: &dyn Interface,
+    module: u32,
+    registers: &[(u32, RegisterType)],
+) -> Result<(), BoxedError> {
+    for (register, register_type) in registers.iter() {
+        if interface.register_type(module, *register)? != *register_type {
+            return Err(error(module, *register, InterfaceErrorType::WrongType));
+        }
+    }
+    Ok(())
+}
+
+macro_rules! handle {
+    ($(#[$doc:meta])* $name:ident, $registers:expr) => {
+        $(#[$doc])*
+        pub struct $name<'a> {
+            interface: &'a mut dyn Interface,
+            module: u32,
+        }
+
+        impl<'a> $name<'a> {
+            /// The registers accessed by this handle, with their types.
+            pub const REGISTERS: &'static [(u32, RegisterType)] = $registers;
+
+            /// Create a handle for the module with the provided id.
+            pub fn new(interface: &'a mut dyn Interface, module: u32) -> Self {
+                $name { interface, module }
+            }
+
+            /// Check that the module provides the registers this handle accesses.
+            pub fn validate(interface: &dyn Interface, module: u32) -> Result<(), BoxedError> {
+                validate(interface, module, Self::REGISTERS)
+            }
+
+            /// The id of the module this handle accesses.
+            pub fn module(&self) -> u32 {
+                self.module
+            }
+        }
+    };
+}
+
+handle!(
+    /// The [`clock`] module.
+    Clock,
+    &[(clock::REG_CLOCK_ELAPSED, F32)]
+);
+
+impl Clock<'_> {
+    /// The elapsed time in seconds.
+    pub fn elapsed(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, clock::REG_CLOCK_ELAPSED)
+    }
+}
+
+handle!(
+    /// The [`gps`] module.
+    Gps,
+    &[
+        (gps::REG_GPS_X, F32),
+        (gps::REG_GPS_Y, F32),
+        (gps::REG_GPS_Z, F32),
+        (gps::REG_GPS_ROLL, F32),
+        (gps::REG_GPS_PITCH, F32),
+        (gps::REG_GPS_YAW, F32),
+        (gps::REG_GPS_VALID, I32),
+    ]
+);
+
+impl Gps<'_> {
+    /// The position in the world, as `[x, y, z]`.
+    pub fn position(&self) -> Result<[f32; 3], BoxedError> {
+        Ok([
+            self.interface.get_f32(self.module, gps::REG_GPS_X)?,
+            self.interface.get_f32(self.module, gps::REG_GPS_Y)?,
+            self.interface.get_f32(self.module, gps::REG_GPS_Z)?,
+        ])
+    }
+
+    /// The orientation in the world, as `[roll, pitch, yaw]` in radians.
+    pub fn orientation(&self) -> Result<[f32; 3], BoxedError> {
+        Ok([
+            self.interface.get_f32(self.module, gps::REG_GPS_ROLL)?,
+            self.interface.get_f32(self.module, gps::REG_GPS_PITCH)?,
+            self.interface.get_f32(self.module, gps::REG_GPS_YAW)?,
+        ])
+    }
+
+    /// The yaw in the world, radians.
+    pub fn yaw(&self) -> Result<f32, BoxedError> {
+        self.interface.get_f32(self.module, gps::REG_GPS_YAW)
+    }
+
+    /// Whether the fix is current, false inside gps denied zones.
+    pub fn is_valid(&self) -> Result<bool, BoxedError> {
+        Ok(self.interface.get_i32(self.module, gps::REG_GPS_VALID)? != 0)
+    }
+}
+
+handle!(
+    /// The [`differential_drive`] module.
+    DifferentialDrive,
+    &[
+        (differential_drive::REG_DIFF_DRIVE_LEFT_VEL, F32),
+        (differential_drive::REG_DIFF_DRIVE_RIGHT_VEL, F32),
+        (differential_drive::REG_DIFF_DRIVE_LEFT_CMD, F32),
+        (differential_drive::REG_DIFF_DRIVE_RIGHT_CMD, F32),
+        (differential_drive::REG_DIFF_DRIVE_TRACK_WIDTH, F32),
+        (differential_drive::REG_DIFF_DRIVE_VELOCITY_LOWER, F32),
+        (differential_drive::REG_DIFF_DRIVE_VELOCITY_UPPER, F32),
+    ]
+);
+
+impl DifferentialDrive<'_> {
+    /// The current velocities of the tracks, as `[left, right]`.
+    pub fn velocities(&self) -> Result<[f32; 2], BoxedError> {
+        use differential_drive::*;
+        Ok([
+            self.interface
+                .get_f32(self.module, REG_DIFF_DRIVE_LEFT_VEL)?,
+            self.interface
+                .get_f32(self.module, REG_DIFF_DRIVE_RIGHT_VEL)?,
+        ])
+    }
+
+    /// Command the velocities of the left and right track.
+    pub fn set_velocities(&mut self, left: f32, right: f32) -> Result<(), BoxedError> {
+        use differential_drive::*;
+        self.interface
+            .set_f32(self.module, REG_DIFF_DRIVE_LEFT_CMD, left)?;
+        self.interface
+            .set_f32(self.module, REG_DIFF_DRIVE_RIGHT_CMD, right)?;
+        Ok(())
+    }
+
+    /// The bounds on the track velocities, as `[lower, upper]`.
+    pub fn velocity_bounds(&self) -> Result<[f32; 2], BoxedError> {
+        use differential_drive::*;
+        Ok([
+            self.interface
+                .get_f32(self.module, REG_DIFF_DRIVE_VELOCITY_LOWER)?,
+            self.interface
+                .get_f32(self.module, REG_DIFF_DRIVE_VELOCITY_UPPER)?,
+        ])
+    }
+
+    /// The distance between the tracks.
+    pub fn track_width(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, differential_drive::REG_DIFF_DRIVE_TRACK_WIDTH)
+    }
+}
+
+handle!(
+    /// The [`revolute`] module.
+    Revolute,
+    &[
+        (revolute::REG_REVOLUTE_POSITION, F32),
+        (revolute::REG_REVOLUTE_VELOCITY, F32),
+        (revolute::REG_REVOLUTE_VELOCITY_CMD, F32),
+    ]
+);
+
+impl Revolute<'_> {
+    /// The current rotation of the joint, radians.
+    pub fn position(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, revolute::REG_REVOLUTE_POSITION)
+    }
+
+    /// The current rotational velocity of the joint, radians per second.
+    pub fn velocity(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, revolute::REG_REVOLUTE_VELOCITY)
+    }
+
+    /// Command the rotational velocity of the joint, radians per second.
+    pub fn set_velocity(&mut self, velocity: f32) -> Result<(), BoxedError> {
+        self.interface
+            .set_f32(self.module, revolute::REG_REVOLUTE_VELOCITY_CMD, velocity)?;
+        Ok(())
+    }
+}
+
+/// A radar reflection, expressed in the radar's frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reflection {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub strength: f32,
+}
+
+handle!(
+    /// The [`radar`] module.
+    Radar,
+    &[
+        (radar::REG_RADAR_RANGE_MAX, F32),
+        (radar::REG_RADAR_REFLECTION_COUNT, I32),
+    ]
+);
+
+impl Radar<'_> {
+    /// The maximum detection range.
+    pub fn range_max(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, radar::REG_RADAR_RANGE_MAX)
+    }
+
+    /// The current reflections.
+    pub fn reflections(&self) -> Result<Vec<Reflection>, BoxedError> {
+        use radar::*;
+        let count = self
+            .interface
+            .get_i32(self.module, REG_RADAR_REFLECTION_COUNT)?;
+        let mut reflections = Vec::with_capacity(count.max(0) as usize);
+        for i in 0..count.max(0) as u32 {
+            let base = REG_RADAR_REFLECTION_START + i * REG_RADAR_REFLECTION_STRIDE;
+            let get = |offset| self.interface.get_f32(self.module, base + offset);
+            reflections.push(Reflection {
+                yaw: get(REG_RADAR_REFLECTION_OFFSET_YAW)?,
+                pitch: get(REG_RADAR_REFLECTION_OFFSET_PITCH)?,
+                distance: get(REG_RADAR_REFLECTION_OFFSET_DISTANCE)?,
+                strength: get(REG_RADAR_REFLECTION_OFFSET_STRENGTH)?,
+            });
+        }
+        Ok(reflections)
+    }
+}
+
+handle!(
+    /// The [`lidar`] module.
+    Lidar,
+    &[
+        (lidar::REG_LIDAR_RANGE_MAX, F32),
+        (lidar::REG_LIDAR_FAN_ANGLE, F32),
+        (lidar::REG_LIDAR_RANGES, Bytes),
+    ]
+);
+
+impl Lidar<'_> {
+    /// The measured ranges, ordered from the ray at `-fan_angle / 2` to `fan_angle / 2`.
+    pub fn ranges(&self) -> Result<Vec<f32>, BoxedError> {
+        let bytes = read_bytes(self.interface, self.module, lidar::REG_LIDAR_RANGES)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|v| f32::from_le_bytes(v.try_into().unwrap()))
+            .collect())
+    }
+
+    /// The total angle spanned by the rays, radians.
+    pub fn fan_angle(&self) -> Result<f32, BoxedError> {
+        self.interface
+            .get_f32(self.module, lidar::REG_LIDAR_FAN_ANGLE)
+    }
+}
+
+handle!(
+    /// The [`cannon`] module.
+    Cannon,
+    &[
+        (cannon::REG_CANNON_TRIGGER, I32),
+        (cannon::REG_CANNON_READY, I32),
+    ]
+);
+
+impl Cannon<'_> {
+    /// Trigger the cannon, it fires as soon as it is ready.
+    pub fn fire(&mut self) -> Result<(), BoxedError> {
+        self.interface
+            .set_i32(self.module, cannon::REG_CANNON_TRIGGER, 1)?;
+        Ok(())
+    }
+
+    /// Whether the cannon is ready to fire.
+    pub fn is_ready(&self) -> Result<bool, BoxedError> {
+        Ok(self
+            .interface
+            .get_i32(self.module, cannon::REG_CANNON_READY)?
+            != 0)
+    }
+}
+
+handle!(
+    /// The [`gun_battery`] module.
+    GunBattery,
+    &[
+        (gun_battery::REG_GUN_BATTERY_FIRING, I32),
+        (gun_battery::REG_GUN_BATTERY_READY, I32),
+        (gun_battery::REG_GUN_BATTERY_COUNT, I32),
+    ]
+);
+
+impl GunBattery<'_> {
+    /// Set whether the battery keeps firing.
+    pub fn set_firing(&mut self, firing: bool) -> Result<(), BoxedError> {
+        self.interface.set_i32(
+            self.module,
+            gun_battery::REG_GUN_BATTERY_FIRING,
+            firing as i32,
+        )?;
+        Ok(())
+    }
+
+    /// Whether the battery is ready to fire.
+    pub fn is_ready(&self) -> Result<bool, BoxedError> {
+        Ok(self
+            .interface
+            .get_i32(self.module, gun_battery::REG_GUN_BATTERY_READY)?
+            != 0)
+    }
+
+    /// The number of guns in the battery.
+    pub fn gun_count(&self) -> Result<usize, BoxedError> {
+        Ok(self
+            .interface
+            .get_i32(self.module, gun_battery::REG_GUN_BATTERY_COUNT)?
+            .max(0) as usize)
+    }
+}
+
+/// A message received by the radio.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioReceived {
+    pub strength: f32,
+    /// Only provided if the receiver is capable of direction finding.
+    pub bearing: Option<f32>,
+    pub data: Vec<u8>,
+}
+
+/// The [`radio_transmitter`] and [`radio_receiver`] modules.
+pub struct Radio<'a> {
+    interface: &'a mut dyn Interface,
+}
+
+impl<'a> Radio<'a> {
+    /// The registers accessed on the transmitter.
+    pub const TRANSMITTER_REGISTERS: &'static [(u32, RegisterType)] = &[
+        (radio_transmitter::REG_RADIO_TX_MSG_COUNT_LIMIT, I32),
+        (radio_transmitter::REG_RADIO_TX_CHANNEL_SELECT, I32),
+        (radio_transmitter::REG_RADIO_TX_MSG_COUNT, I32),
+    ];
+    /// The registers accessed on the receiver.
+    pub const RECEIVER_REGISTERS: &'static [(u32, RegisterType)] = &[
+        (radio_receiver::REG_RADIO_RX_CHANNEL_SELECT, I32),
+        (radio_receiver::REG_RADIO_RX_MSG_COUNT, I32),
+    ];
+
+    pub fn new(interface: &'a mut dyn Interface) -> Self {
+        Radio { interface }
+    }
+
+    /// Check that the radio modules provide the registers this handle accesses.
+    pub fn validate(interface: &dyn Interface) -> Result<(), BoxedError> {
+        validate(
+            interface,
+            common::MODULE_RADIO_TRANSMITTER,
+            Self::TRANSMITTER_REGISTERS,
+        )?;
+        validate(
+            interface,
+            common::MODULE_RADIO_RECEIVER,
+            Self::RECEIVER_REGISTERS,
+        )
+    }
+
+    /// Select the channel for both the transmitter and the receiver.
+    pub fn set_channel(&mut self, channel: i32) -> Result<(), BoxedError> {
+        self.interface.set_i32(
+            common::MODULE_RADIO_TRANSMITTER,
+            radio_transmitter::REG_RADIO_TX_CHANNEL_SELECT,
+            channel,
+        )?;
+        self.interface.set_i32(
+            common::MODULE_RADIO_RECEIVER,
+            radio_receiver::REG_RADIO_RX_CHANNEL_SELECT,
+            channel,
+        )?;
+        Ok(())
+    }
+
+    /// Queue a message for transmission, fails with a write overflow if the queue is full.
+    pub fn send(&mut self, data: &[u8]) -> Result<(), BoxedError> {
+        use radio_transmitter::*;
+        let module = common::MODULE_RADIO_TRANSMITTER;
+        let count = self.interface.get_i32(module, REG_RADIO_TX_MSG_COUNT)?;
+        let limit = self
+            .interface
+            .get_i32(module, REG_RADIO_TX_MSG_COUNT_LIMIT)?;
+        if count >= limit {
+            return Err(error(
+                module,
+                REG_RADIO_TX_MSG_COUNT,
+                InterfaceErrorType::WriteOverflow,
+            ));
+        }
+        self.interface
+            .set_bytes(module, REG_RADIO_TX_MSG_START + count as u32, data)?;
+        self.interface
+            .set_i32(module, REG_RADIO_TX_MSG_COUNT, count + 1)?;
+        Ok(())
+    }
+
+    /// The messages in the receive buffer, these stay in the buffer until [`Radio::clear`].
+    pub fn messages(&self) -> Result<Vec<RadioReceived>, BoxedError> {
+        use radio_receiver::*;
+        let module = common::MODULE_RADIO_RECEIVER;
+        let count = self.interface.get_i32(module, REG_RADIO_RX_MSG_COUNT)?;
+        let mut messages = Vec::with_capacity(count.max(0) as usize);
+        // Bearings are only present with direction finding.
+        let registers = self.interface.registers(module)?;
+        for i in 0..count.max(0) as u32 {
+            let base = REG_RADIO_RX_MSG_START + i * REG_RADIO_RX_MSG_STRIDE;
+            let bearing_register = REG_RADIO_RX_BEARING_START + i;
+            let bearing = if registers.contains(&bearing_register) {
+                Some(self.interface.get_f32(module, bearing_register)?)
+            } else {
+                None
+            };
+            messages.push(RadioReceived {
+                strength: self
+                    .interface
+                    .get_f32(module, base + REG_RADIO_RX_MSG_OFFSET_STRENGTH)?,
+                bearing,
+                data: read_bytes(self.interface, module, base + REG_RADIO_RX_MSG_OFFSET_DATA)?,
+            });
+        }
+        Ok(messages)
+    }
+
+    /// Clear the receive buffer.
+    pub fn clear(&mut self) -> Result<(), BoxedError> {
+        self.interface.set_i32(
+            common::MODULE_RADIO_RECEIVER,
+            radio_receiver::REG_RADIO_RX_MSG_COUNT,
+            0,
+        )?;
+        Ok(())
+    }
+}
+
+/// Ownership of a capture point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapturePointOwner {
+    Unowned,
+    /// The point is not observed by the team.
+    Unknown,
+    Team(i32),
+}
+
+/// A capture point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CapturePoint {
+    pub position: [f32; 2],
+    pub radius: f32,
+    pub owner: CapturePointOwner,
+}
+
+handle!(
+    /// The [`objectives`] module.
+    Objectives,
+    &[(objectives::REG_OBJECTIVES_CAPTURE_POINT_COUNT, I32)]
+);
+
+impl Objectives<'_> {
+    /// The capture points in the match.
+    pub fn capture_points(&self) -> Result<Vec<CapturePoint>, BoxedError> {
+        use objectives::*;
+        let count = self
+            .interface
+            .get_i32(self.module, REG_OBJECTIVES_CAPTURE_POINT_COUNT)?;
+        let mut points = Vec::with_capacity(count.max(0) as usize);
+        for i in 0..count.max(0) as u32 {
+            let base = REG_OBJECTIVES_CAPTURE_POINT_START + i * REG_OBJECTIVES_CAPTURE_POINT_STRIDE;
+            let get = |offset| self.interface.get_f32(self.module, base + offset);
+            let owner = match self.interface.get_i32(
+                self.module,
+                base + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_OWNER,
+            )? {
+                OBJECTIVES_CAPTURE_POINT_UNOWNED => CapturePointOwner::Unowned,
+                OBJECTIVES_CAPTURE_POINT_UNKNOWN => CapturePointOwner::Unknown,
+                team => CapturePointOwner::Team(team),
+            };
+            points.push(CapturePoint {
+                position: [
+                    get(REG_OBJECTIVES_CAPTURE_POINT_OFFSET_X)?,
+                    get(REG_OBJECTIVES_CAPTURE_POINT_OFFSET_Y)?,
+                ],
+                radius: get(REG_OBJECTIVES_CAPTURE_POINT_OFFSET_RADIUS)?,
+                owner,
+            });
+        }
+        Ok(points)
+    }
+}
+
+handle!(
+    /// The [`draw`] module, lines are shown until the next update.
+    Draw,
+    &[(draw::REG_DRAW_LINES, Bytes)]
+);
+
+impl Draw<'_> {
+    /// Append a line segment to the lines drawn this update.
+    pub fn line(
+        &mut self,
+        p0: [f32; 3],
+        p1: [f32; 3],
+        width: f32,
+        color: [u8; 4],
+    ) -> Result<(), BoxedError> {
+        self.segment(draw::LineSegment {
+            p0,
+            p1,
+            width,
+            color,
+        })
+    }
+
+    /// Append a [`draw::LineSegment`] to the lines drawn this update.
+    pub fn segment(&mut self, segment: draw::LineSegment) -> Result<(), BoxedError> {
+        self.lines(&[segment])
+    }
+
+    /// Append multiple [`draw::LineSegment`]s to the lines drawn this update. Each append reads
+    /// and writes all lines drawn so far, so use this to draw many lines at once.
+    pub fn lines(&mut self, segments: &[draw::LineSegment]) -> Result<(), BoxedError> {
+        let mut lines = read_bytes(self.interface, self.module, draw::REG_DRAW_LINES)?;
+        lines.extend(segments.iter().flat_map(|v| v.clone().into_le_bytes()));
+        self.interface
+            .set_bytes(self.module, draw::REG_DRAW_LINES, &lines)
+    }
+}
+
+/// Access to the modules shared by all units.
+pub trait Unit {
+    /// The interface of the unit.
+    fn interface(&mut self) -> &mut dyn Interface;
+
+    fn clock(&mut self) -> Clock<'_> {
+        Clock::new(self.interface(), common::MODULE_CLOCK)
+    }
+
+    fn gps(&mut self) -> Gps<'_> {
+        Gps::new(self.interface(), common::MODULE_GPS)
+    }
+
+    fn radio(&mut self) -> Radio<'_> {
+        Radio::new(self.interface())
+    }
+
+    fn objectives(&mut self) -> Objectives<'_> {
+        Objectives::new(self.interface(), common::MODULE_OBJECTIVES)
+    }
+
+    fn draw(&mut self) -> Draw<'_> {
+        Draw::new(self.interface(), common::MODULE_DRAW)
+    }
+
+    /// The id of this unit.
+    fn unit_id(&mut self) -> Result<i32, BoxedError> {
+        self.interface()
+            .get_i32(common::MODULE_UNIT, unit::REG_UNIT_UNIT_ID)
+    }
+
+    /// The team of this unit, [`team::TEAM_NO_TEAM`] if it doesn't belong to a team.
+    fn team(&mut self) -> Result<i32, BoxedError> {
+        self.interface()
+            .get_i32(common::MODULE_TEAM, team::REG_TEAM_TEAMID)
+    }
+}
+
+/// Check the unit type and the modules shared by all units.
+fn validate_unit(interface: &dyn Interface, unit_type: UnitType) -> Result<(), BoxedError> {
+    let (module, register) = (common::MODULE_UNIT, unit::REG_UNIT_UNIT_TYPE);
+    if interface.get_i32(module, register)? != unit_type as i32 {
+        return Err(error(module, register, InterfaceErrorType::WrongType));
+    }
+    Clock::validate(interface, common::MODULE_CLOCK)?;
+    Gps::validate(interface, common::MODULE_GPS)?;
+    Radio::validate(interface)?;
+    Objectives::validate(interface, common::MODULE_OBJECTIVES)?;
+    Draw::validate(interface, common::MODULE_DRAW)
+}
+
+/// Handles to the modules of a tank.
+pub struct Tank<'a> {
+    interface: &'a mut dyn Interface,
+}
+
+impl<'a> Tank<'a> {
+    /// Wrap the interface, fails if the unit is not a tank or its modules don't match the handles.
+    pub fn new(interface: &'a mut dyn Interface) -> Result<Self, BoxedError> {
+        Self::validate(interface)?;
+        Ok(Self::new_unchecked(interface))
+    }
+
+    /// Wrap the interface without verifying it, use after a successful [`Tank::validate`].
+    pub fn new_unchecked(interface: &'a mut dyn Interface) -> Self {
+        Tank { interface }
+    }
+
+    /// Check that the unit is a tank and its modules match the handles.
+    pub fn validate(interface: &dyn Interface) -> Result<(), BoxedError> {
+        validate_unit(interface, UnitType::Tank)?;
+        DifferentialDrive::validate(interface, tank::MODULE_TANK_DIFF_DRIVE)?;
+        Revolute::validate(interface, tank::MODULE_TANK_REVOLUTE_TURRET)?;
+        Revolute::validate(interface, tank::MODULE_TANK_REVOLUTE_BARREL)?;
+        Revolute::validate(interface, tank::MODULE_TANK_REVOLUTE_RADAR)?;
+        Radar::validate(interface, tank::MODULE_TANK_RADAR)?;
+        Lidar::validate(interface, tank::MODULE_TANK_LIDAR)?;
+        Cannon::validate(interface, tank::MODULE_TANK_CANNON)
+    }
+
+    pub fn base(&mut self) -> DifferentialDrive<'_> {
+        DifferentialDrive::new(self.interface, tank::MODULE_TANK_DIFF_DRIVE)
+    }
+
+    pub fn turret(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, tank::MODULE_TANK_REVOLUTE_TURRET)
+    }
+
+    pub fn barrel(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, tank::MODULE_TANK_REVOLUTE_BARREL)
+    }
+
+    pub fn radar_rotation(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, tank::MODULE_TANK_REVOLUTE_RADAR)
+    }
+
+    pub fn radar(&mut self) -> Radar<'_> {
+        Radar::new(self.interface, tank::MODULE_TANK_RADAR)
+    }
+
+    pub fn lidar(&mut self) -> Lidar<'_> {
+        Lidar::new(self.interface, tank::MODULE_TANK_LIDAR)
+    }
+
+    pub fn cannon(&mut self) -> Cannon<'_> {
+        Cannon::new(self.interface, tank::MODULE_TANK_CANNON)
+    }
+}
+
+impl Unit for Tank<'_> {
+    fn interface(&mut self) -> &mut dyn Interface {
+        self.interface
+    }
+}
+
+/// Handles to the modules of an artillery unit.
+pub struct Artillery<'a> {
+    interface: &'a mut dyn Interface,
+}
+
+impl<'a> Artillery<'a> {
+    /// Wrap the interface, fails if the unit is not an artillery unit or its modules don't match
+    /// the handles.
+    pub fn new(interface: &'a mut dyn Interface) -> Result<Self, BoxedError> {
+        Self::validate(interface)?;
+        Ok(Self::new_unchecked(interface))
+    }
+
+    /// Wrap the interface without verifying it, use after a successful [`Artillery::validate`].
+    pub fn new_unchecked(interface: &'a mut dyn Interface) -> Self {
+        Artillery { interface }
+    }
+
+    /// Check that the unit is an artillery unit and its modules match the handles.
+    pub fn validate(interface: &dyn Interface) -> Result<(), BoxedError> {
+        validate_unit(interface, UnitType::Artillery)?;
+        DifferentialDrive::validate(interface, artillery::MODULE_ARTILLERY_DIFF_DRIVE)?;
+        Revolute::validate(interface, artillery::MODULE_ARTILLERY_REVOLUTE_TURRET)?;
+        Revolute::validate(interface, artillery::MODULE_ARTILLERY_REVOLUTE_BARREL)?;
+        Revolute::validate(interface, artillery::MODULE_ARTILLERY_REVOLUTE_RADAR)?;
+        Radar::validate(interface, artillery::MODULE_ARTILLERY_RADAR)?;
+        Lidar::validate(interface, artillery::MODULE_ARTILLERY_LIDAR)?;
+        GunBattery::validate(interface, artillery::MODULE_ARTILLERY_GUN_BATTERY)
+    }
+
+    pub fn base(&mut self) -> DifferentialDrive<'_> {
+        DifferentialDrive::new(self.interface, artillery::MODULE_ARTILLERY_DIFF_DRIVE)
+    }
+
+    pub fn turret(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, artillery::MODULE_ARTILLERY_REVOLUTE_TURRET)
+    }
+
+    pub fn barrel(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, artillery::MODULE_ARTILLERY_REVOLUTE_BARREL)
+    }
+
+    pub fn radar_rotation(&mut self) -> Revolute<'_> {
+        Revolute::new(self.interface, artillery::MODULE_ARTILLERY_REVOLUTE_RADAR)
+    }
+
+    pub fn radar(&mut self) -> Radar<'_> {
+        Radar::new(self.interface, artillery::MODULE_ARTILLERY_RADAR)
+    }
+
+    pub fn lidar(&mut self) -> Lidar<'_> {
+        Lidar::new(self.interface, artillery::MODULE_ARTILLERY_LIDAR)
+    }
+
+    pub fn gun_battery(&mut self) -> GunBattery<'_> {
+        GunBattery::new(self.interface, artillery::MODULE_ARTILLERY_GUN_BATTERY)
+    }
+}
+
+impl Unit for Artillery<'_> {
+    fn interface(&mut self) -> &mut dyn Interface {
+        self.interface
+    }
+}
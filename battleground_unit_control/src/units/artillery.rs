@@ -4,6 +4,7 @@ pub const MODULE_ARTILLERY_REVOLUTE_BARREL: u32 = 0x1200;
 pub const MODULE_ARTILLERY_GUN_BATTERY: u32 = 0x1300;
 pub const MODULE_ARTILLERY_REVOLUTE_RADAR: u32 = 0x1500;
 pub const MODULE_ARTILLERY_RADAR: u32 = 0x1600;
+pub const MODULE_ARTILLERY_LIDAR: u32 = 0x1A00;
 
 /// Distance in z between the floor and the body center.
 pub const ARTILLERY_DIM_FLOOR_TO_BODY_Z: f32 = 0.25;
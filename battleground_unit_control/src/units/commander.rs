@@ -0,0 +1,6 @@
+//! The team commander is not a unit, it coordinates the units of its team. It has the
+//! [`MODULE_CLOCK`](super::common::MODULE_CLOCK) and [`MODULE_TEAM`](super::common::MODULE_TEAM)
+//! modules, and the following.
+
+/// The units of the team, and their orders.
+pub const MODULE_COMMANDER: u32 = 0x1000;
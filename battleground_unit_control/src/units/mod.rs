@@ -1,4 +1,5 @@
 pub mod artillery;
+pub mod commander;
 pub mod tank;
 
 pub mod common {
@@ -8,11 +9,24 @@ pub mod common {
     pub const MODULE_UNIT: u32 = 0x0400;
     pub const MODULE_RADIO_TRANSMITTER: u32 = 0x0500;
     pub const MODULE_RADIO_RECEIVER: u32 = 0x0600;
+    pub const MODULE_WIND: u32 = 0x0700;
+    /// Only present if the unit carries a jammer.
+    pub const MODULE_RADIO_JAMMER: u32 = 0x0800;
 
     /// Gps is located in the body origin.
     pub const MODULE_GPS: u32 = 0x1700;
     /// Drawing happens in world frame.
     pub const MODULE_DRAW: u32 = 0x1800;
+    /// Mines are deployed at the body origin.
+    pub const MODULE_MINE_LAYER: u32 = 0x1900;
+    /// Odometry is derived from the velocity of the base.
+    pub const MODULE_ODOMETRY: u32 = 0x1B00;
+    /// The imu is located in the base origin.
+    pub const MODULE_IMU: u32 = 0x1C00;
+    /// Hit directions are expressed in the body frame.
+    pub const MODULE_DAMAGE: u32 = 0x1D00;
+    /// Orders are posted by the team commander.
+    pub const MODULE_ORDERS: u32 = 0x1E00;
 }
 
 /// Unit type enum to denote the unit type.
@@ -2,8 +2,10 @@ pub const MODULE_TANK_DIFF_DRIVE: u32 = 0x1000;
 pub const MODULE_TANK_REVOLUTE_TURRET: u32 = 0x1100;
 pub const MODULE_TANK_REVOLUTE_BARREL: u32 = 0x1200;
 pub const MODULE_TANK_CANNON: u32 = 0x1300;
+pub const MODULE_TANK_MISSILE_LAUNCHER: u32 = 0x1400;
 pub const MODULE_TANK_REVOLUTE_RADAR: u32 = 0x1500;
 pub const MODULE_TANK_RADAR: u32 = 0x1600;
+pub const MODULE_TANK_LIDAR: u32 = 0x1A00;
 
 /// Distance in z between the floor and the body center.
 pub const TANK_DIM_FLOOR_TO_BODY_Z: f32 = 0.25;
@@ -18,3 +20,7 @@ pub const TANK_DIM_BARREL_TO_MUZZLE_X: f32 = 1.0;
 
 /// Velocity at which cannon bullets exit the tank's muzzle.
 pub const TANK_PARAM_MUZZLE_VELOCITY: f32 = 10.0;
+/// Distance between the turret center and the missile launcher in y, the launcher is on the left.
+pub const TANK_DIM_TURRET_TO_MISSILE_LAUNCHER_Y: f32 = 0.32;
+/// Pitch of the missile launcher, missiles are launched upwards at this angle, radians.
+pub const TANK_DIM_MISSILE_LAUNCHER_PITCH: f32 = 0.5;
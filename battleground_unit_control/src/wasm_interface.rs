@@ -81,6 +81,7 @@ mod interface {
         fn wasm_interface_module_name(module: u32) -> u32;
         fn wasm_interface_register_name(module: u32, register: u32) -> u32;
         fn wasm_interface_register_type(module: u32, register: u32) -> u32;
+        fn wasm_interface_register_metadata(module: u32, register: u32) -> u32;
 
         fn wasm_interface_get_i32(module: u32, register: u32) -> i32;
         fn wasm_interface_get_f32(module: u32, register: u32) -> f32;
@@ -91,6 +92,10 @@ mod interface {
         fn wasm_interface_get_bytes(module: u32, register: u32, dest: u32, len: u32) -> u32;
         // return type of set bytes is just for uniform handling on the wasm wrapper side.
         fn wasm_interface_set_bytes(module: u32, register: u32, src: u32, len: u32) -> u32;
+
+        // On failure these return the index of the module or value that failed.
+        fn wasm_interface_get_values(src: u32, len: u32) -> u32;
+        fn wasm_interface_set_values(src: u32, len: u32) -> u32;
     }
 
     static BUFFER: Mutex<Vec<u8>> = Mutex::new(Vec::new());
@@ -153,6 +158,11 @@ mod interface {
         }
     }
 
+    fn read_from_buffer_bytes(length: u32) -> Vec<u8> {
+        let buffer = BUFFER.lock().expect("cannot be poisoned");
+        buffer[0..length as usize].to_vec()
+    }
+
     pub struct StaticInterface;
 
     impl crate::Interface for StaticInterface {
@@ -207,6 +217,21 @@ mod interface {
             }
         }
 
+        /// Retrieve the metadata of a register.
+        fn register_metadata(
+            &self,
+            module: u32,
+            register: u32,
+        ) -> Result<RegisterMetadata, Box<InterfaceError>> {
+            clear_error();
+            let length = unsafe { wasm_interface_register_metadata(module, register) };
+            get_error(module, register)?;
+            match RegisterMetadata::from_le_bytes(&read_from_buffer_bytes(length)) {
+                Some(v) => Ok(v),
+                None => panic!("Could not convert register metadata"),
+            }
+        }
+
         /// Get an i32 register.
         fn get_i32(&self, module: u32, register: u32) -> Result<i32, Box<InterfaceError>> {
             clear_error();
@@ -296,6 +321,42 @@ mod interface {
             get_error(module, register)?;
             Ok(())
         }
+
+        /// Get the values of all registers in the provided modules, in a single call to the host.
+        fn get_values(
+            &self,
+            modules: &[u32],
+        ) -> Result<Vec<(u32, u32, RegisterValue)>, Box<InterfaceError>> {
+            clear_error();
+            let request = modules
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<u8>>();
+            let length =
+                unsafe { wasm_interface_get_values(request.as_ptr() as u32, request.len() as u32) };
+            get_error(modules.get(length as usize).copied().unwrap_or(0), 0)?;
+            match crate::interface::values_from_le_bytes(&read_from_buffer_bytes(length)) {
+                Some(v) => Ok(v),
+                None => panic!("Could not convert register values"),
+            }
+        }
+
+        /// Set the values of several registers, in a single call to the host.
+        fn set_values(
+            &mut self,
+            values: &[(u32, u32, RegisterValue)],
+        ) -> Result<(), Box<InterfaceError>> {
+            clear_error();
+            let request = crate::interface::values_to_le_bytes(values);
+            let applied =
+                unsafe { wasm_interface_set_values(request.as_ptr() as u32, request.len() as u32) };
+            let (module, register) = values
+                .get(applied as usize)
+                .map(|v| (v.0, v.1))
+                .unwrap_or((0, 0));
+            get_error(module, register)?;
+            Ok(())
+        }
     }
 }
 
@@ -5,6 +5,7 @@ use super::render::{
     BatchProperties, GeometryRef, MeshGeometry, PrimitiveGeometry, RenderPass, RenderableGeometry,
 };
 
+use battleground_construct::components::team::TeamId;
 use battleground_construct::components::unit::UnitId;
 use battleground_construct::display;
 use battleground_construct::display::primitives::{Drawable, Primitive};
@@ -277,12 +278,64 @@ impl ConstructRender {
             .collect()
     }
 
+    /// Whether an entity is visible from the perspective of a team, everything is visible if no
+    /// perspective is set.
+    fn is_visible(construct: &Construct, perspective: Option<TeamId>, entity: EntityId) -> bool {
+        use battleground_construct::components::carriable_flag::CarriableFlag;
+        use battleground_construct::components::mine::Mine;
+        use battleground_construct::components::unit_member::UnitMember;
+        use battleground_construct::components::visibility::Visibility;
+        let team = if let Some(team) = perspective {
+            team
+        } else {
+            return true;
+        };
+        let world = construct.world();
+        let visibility = if let Some((_e, visibility)) = world.component_iter::<Visibility>().next()
+        {
+            visibility
+        } else {
+            return true;
+        };
+        if let Some(member) = world.component::<UnitMember>(entity) {
+            return visibility.can_see_unit(team, member.unit());
+        }
+        if world.component::<CarriableFlag>(entity).is_some() {
+            return visibility.can_see_entity(team, entity);
+        }
+        // Mines are only shown to the team that deployed them.
+        if let Some(mine) = world.component::<Mine>(entity) {
+            return mine.team().map(|t| t == team).unwrap_or(true);
+        }
+        true
+    }
+
+    /// Whether the owner of a capturable is known from the perspective of a team, unobserved
+    /// capturables are drawn in a neutral color.
+    fn is_owner_visible(
+        construct: &Construct,
+        perspective: Option<TeamId>,
+        entity: EntityId,
+    ) -> bool {
+        use battleground_construct::components::capturable::Capturable;
+        use battleground_construct::components::visibility::Visibility;
+        let world = construct.world();
+        if world.component::<Capturable>(entity).is_none() {
+            return true;
+        }
+        match (perspective, world.component_iter::<Visibility>().next()) {
+            (Some(team), Some((_e, visibility))) => visibility.can_see_entity(team, entity),
+            _ => true,
+        }
+    }
+
     pub fn render(
         &mut self,
         camera: &Camera,
         context: &Context,
         construct: &Construct,
         selected: &std::collections::HashSet<EntityId>,
+        perspective: Option<TeamId>,
     ) {
         // a new cycle, clear the previous instances.
         self.prepare_scene(context);
@@ -299,21 +352,42 @@ impl ConstructRender {
 
         // Iterate through all displayables to collect meshes
 
+        // Only draw what the perspective's team observes.
+        let visible = |e: EntityId| Self::is_visible(construct, perspective, e);
+
         // Specific to artillery
-        self.component_to_meshes::<display::artillery_turret::ArtilleryTurret>(construct);
-        self.component_to_meshes::<display::artillery_barrel::ArtilleryBarrel>(construct);
-        self.component_to_meshes::<display::artillery_body::ArtilleryBody>(construct);
+        self.component_to_meshes_filtered::<display::artillery_turret::ArtilleryTurret, _>(
+            construct, &visible,
+        );
+        self.component_to_meshes_filtered::<display::artillery_barrel::ArtilleryBarrel, _>(
+            construct, &visible,
+        );
+        self.component_to_meshes_filtered::<display::artillery_body::ArtilleryBody, _>(
+            construct, &visible,
+        );
 
         // Tank
-        self.component_to_meshes::<display::tank_body::TankBody>(construct);
-        self.component_to_meshes::<display::tank_turret::TankTurret>(construct);
-        self.component_to_meshes::<display::tank_barrel::TankBarrel>(construct);
+        self.component_to_meshes_filtered::<display::tank_body::TankBody, _>(construct, &visible);
+        self.component_to_meshes_filtered::<display::tank_turret::TankTurret, _>(
+            construct, &visible,
+        );
+        self.component_to_meshes_filtered::<display::tank_barrel::TankBarrel, _>(
+            construct, &visible,
+        );
 
         // Common
-        self.component_to_meshes::<display::tank_bullet::TankBullet>(construct);
-        self.component_to_meshes::<display::tracks_side::TracksSide>(construct);
-        self.component_to_meshes::<display::health_bar::HealthBar>(construct);
-        self.component_to_meshes::<display::radar_model::RadarModel>(construct);
+        self.component_to_meshes_filtered::<display::tank_bullet::TankBullet, _>(
+            construct, &visible,
+        );
+        self.component_to_meshes_filtered::<display::missile::Missile, _>(construct, &visible);
+        self.component_to_meshes_filtered::<display::mine_model::MineModel, _>(construct, &visible);
+        self.component_to_meshes_filtered::<display::tracks_side::TracksSide, _>(
+            construct, &visible,
+        );
+        self.component_to_meshes_filtered::<display::health_bar::HealthBar, _>(construct, &visible);
+        self.component_to_meshes_filtered::<display::radar_model::RadarModel, _>(
+            construct, &visible,
+        );
 
         // We could also pre-calculate all entities that have the correct unit members, and then
         // filter based on that...
@@ -335,8 +409,34 @@ impl ConstructRender {
         self.component_to_meshes::<display::debug_elements::DebugElements>(construct);
         self.component_to_meshes::<display::debug_hit_collection::DebugHitCollection>(construct);
 
-        self.component_to_meshes::<display::flag::Flag>(construct);
-        self.component_to_meshes::<display::display_control_point::DisplayControlPoint>(construct);
+        // Capture points are always drawn, but their owner's color only if it is observed.
+        let neutral = display::primitives::Color::rgb(30, 30, 30);
+        for (entity, flag) in construct.world().component_iter::<display::flag::Flag>() {
+            if !visible(entity) {
+                continue;
+            }
+            let mut flag = *flag;
+            if !Self::is_owner_visible(construct, perspective, entity) {
+                flag.set_color(neutral);
+            }
+            let world_pose = construct.entity_pose(entity);
+            for el in flag.drawables() {
+                self.add_primitive_element(&el, world_pose.transform())
+            }
+        }
+        for (entity, point) in construct
+            .world()
+            .component_iter::<display::display_control_point::DisplayControlPoint>()
+        {
+            let mut point = *point;
+            if !Self::is_owner_visible(construct, perspective, entity) {
+                point.set_color(neutral);
+            }
+            let world_pose = construct.entity_pose(entity);
+            for el in point.drawables() {
+                self.add_primitive_element(&el, world_pose.transform())
+            }
+        }
 
         // Get the current effect keys.
         let mut start_keys = self
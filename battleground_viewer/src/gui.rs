@@ -78,8 +78,12 @@ pub fn window_match(ctx: &egui::Context, construct: &crate::Construct, state: &m
         .open(&mut open)
         .show(ctx, |ui| {
             use components::capturable::Capturable;
+            use components::match_attack_defend::MatchAttackDefend;
+            use components::match_capture_the_flag::MatchCaptureTheFlag;
             use components::match_finished::MatchFinished;
             use components::match_king_of_the_hill::MatchKingOfTheHill;
+            use components::match_payload::MatchPayload;
+            use components::match_scoring::MatchScoring;
             use components::match_team_deathmatch::MatchTeamDeathmatch;
             use components::match_time_limit::MatchTimeLimit;
             let progress_width = 200.0;
@@ -112,16 +116,71 @@ pub fn window_match(ctx: &egui::Context, construct: &crate::Construct, state: &m
                 });
             }
 
+            for (_e, attack_defend) in construct.world.component_iter::<MatchAttackDefend>() {
+                let color = state.get_team_color(Some(attack_defend.attacker()));
+                let count = attack_defend.point_count();
+                let captured = attack_defend.captured();
+                let ratio = attack_defend.progress() / (count.max(1) as f32);
+                ui.scope(|ui| {
+                    ui.visuals_mut().selection.bg_fill = color;
+                    ui.add(
+                        ProgressBar::new(ratio)
+                            .desired_width(progress_width)
+                            .text(format!("Attack: {captured}/{count}")),
+                    );
+                });
+            }
+
+            for (_e, match_payload) in construct.world.component_iter::<MatchPayload>() {
+                let color = state.get_team_color(Some(match_payload.attacker()));
+                let distance = match_payload.distance();
+                let path_length = match_payload.path_length();
+                let ratio = distance / path_length.max(f32::EPSILON);
+                ui.scope(|ui| {
+                    ui.visuals_mut().selection.bg_fill = color;
+                    ui.add(
+                        ProgressBar::new(ratio)
+                            .desired_width(progress_width)
+                            .text(format!("Payload: {distance:.1}/{path_length:.1}")),
+                    );
+                });
+            }
+
             if let Some((_e, match_finished)) =
                 construct.world.component_iter::<MatchFinished>().next()
             {
                 if let Some(report) = match_finished.report() {
-                    if let Some(winner) = report.winner {
-                        let team_name = state.get_team_name(winner);
-                        ui.label(format!(
-                            "Result: {team_name:} won by {:?} in {:.1}s",
-                            report.conclusion, report.duration
-                        ));
+                    use components::match_finished::MatchOutcome;
+                    let team_names = |teams: &[TeamId]| {
+                        teams
+                            .iter()
+                            .map(|t| state.get_team_name(*t))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    match &report.outcome {
+                        MatchOutcome::Win(winner) => {
+                            let team_name = state.get_team_name(*winner);
+                            ui.label(format!(
+                                "Result: {team_name:} won by {:?} in {:.1}s",
+                                report.conclusion, report.duration
+                            ));
+                        }
+                        MatchOutcome::Draw(teams) => {
+                            ui.label(format!(
+                                "Result: Draw between {} by {:?} in {:.1}s",
+                                team_names(teams),
+                                report.conclusion,
+                                report.duration
+                            ));
+                        }
+                        MatchOutcome::NoContest(teams) => {
+                            ui.label(format!(
+                                "Result: No contest, controller failure of {} in {:.1}s",
+                                team_names(teams),
+                                report.duration
+                            ));
+                        }
                     }
                 } else {
                     ui.label("Result: Ongoing");
@@ -174,9 +233,26 @@ pub fn window_match(ctx: &egui::Context, construct: &crate::Construct, state: &m
                 .next()
                 .map(|v| v.1.clone());
 
+            let capture_the_flag_report = construct
+                .world
+                .component_iter::<MatchCaptureTheFlag>()
+                .next()
+                .map(|v| v.1.clone());
+
+            let scoring_report = construct
+                .world
+                .component_iter::<MatchScoring>()
+                .next()
+                .map(|v| v.1.clone());
+
             for (team_id, team) in state.teams.iter() {
                 ui.heading(format!("Team - {}", team.name()));
 
+                if let Some(ref scoring_report) = scoring_report {
+                    let score = scoring_report.score(*team_id);
+                    ui.label(format!("Score: {score:.1}"));
+                }
+
                 if let Some(comment) = team.comment() {
                     ui.label(format!("Comment: {comment}",));
                 }
@@ -235,6 +311,33 @@ pub fn window_match(ctx: &egui::Context, construct: &crate::Construct, state: &m
                     }
                 }
 
+                if let Some(ref ctf_report) = capture_the_flag_report {
+                    let limit = ctf_report.point_limit();
+
+                    let points = ctf_report
+                        .points()
+                        .iter()
+                        .filter(|x| x.0 == *team_id)
+                        .map(|x| x.1)
+                        .last()
+                        .unwrap_or(0);
+
+                    if let Some(ref max) = limit {
+                        ui.scope(|ui| {
+                            ui.visuals_mut().selection.bg_fill = State::ui_team_color(team.color()); // Temporary change
+                            let ratio = points as f32 / *max as f32;
+                            ui.add(
+                                ProgressBar::new(ratio)
+                                    .desired_width(progress_width)
+                                    .text(format!("Captures: {points}/{max}")),
+                            );
+                        });
+                    } else {
+                        // No limit, lets just make some text.
+                        ui.label(format!("Captures: {points}"));
+                    }
+                }
+
                 // Show the units.
                 if let Some(entry) = team_info.get(team_id) {
                     let mut sorted_units = entry.units.iter().collect::<Vec<_>>();
@@ -302,6 +405,29 @@ pub fn window_play(
                     });
                 ui.end_row();
 
+                ui.label("Perspective:");
+                let perspective_name = |team: Option<TeamId>| {
+                    team.map(|t| state.gui.get_team_name(t))
+                        .unwrap_or("All".to_owned())
+                };
+                let mut teams = state.gui.teams.keys().copied().collect::<Vec<_>>();
+                teams.sort();
+                let mut perspective = state.perspective;
+                egui::ComboBox::from_id_source("perspective")
+                    .selected_text(perspective_name(perspective))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut perspective, None, perspective_name(None));
+                        for team in teams {
+                            ui.selectable_value(
+                                &mut perspective,
+                                Some(team),
+                                perspective_name(Some(team)),
+                            );
+                        }
+                    });
+                state.perspective = perspective;
+                ui.end_row();
+
                 if let Some(v) = construct.recording_max_time() {
                     ui.label("Seek:");
                     // https://github.com/emilk/egui/issues/1850
@@ -30,6 +30,7 @@ pub struct ViewerState {
     previous_playback: f32,
     playback: f32,
     desired_speed: f32,
+    perspective: Option<battleground_construct::components::team::TeamId>,
     selected: std::collections::HashSet<EntityId>,
     gui: gui::State,
 }
@@ -41,6 +42,7 @@ impl Default for ViewerState {
             previous_playback: 0.0,
             playback: 0.0,
             desired_speed: 1.0,
+            perspective: None,
             selected: Default::default(),
             gui: Default::default(),
         }
@@ -273,6 +275,7 @@ impl ConstructViewer {
                 &self.context,
                 &self.construct,
                 &viewer_state.selected,
+                viewer_state.perspective,
             );
 
             if PRINT_DURATIONS {
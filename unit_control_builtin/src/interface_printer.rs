@@ -1,5 +1,5 @@
 use crate::UnitControlResult;
-use battleground_unit_control::{Interface, RegisterType, UnitControl};
+use battleground_unit_control::{Interface, RegisterAccess, RegisterType, UnitControl};
 pub struct InterfacePrinter {}
 impl UnitControl for InterfacePrinter {
     fn update(&mut self, interface: &mut dyn Interface) -> UnitControlResult {
@@ -12,6 +12,13 @@ impl UnitControl for InterfacePrinter {
                 print!("  ({})", interface.register_name(m_index, r_index).unwrap());
                 let register_type = interface.register_type(m_index, r_index).unwrap();
                 print!("[{:?}]", register_type);
+                let metadata = interface.register_metadata(m_index, r_index).unwrap();
+                if metadata.access == RegisterAccess::ReadOnly {
+                    print!("[ro]");
+                }
+                if !metadata.unit.is_empty() {
+                    print!("[{}]", metadata.unit);
+                }
                 match register_type {
                     RegisterType::I32 => {
                         let v = interface.get_i32(m_index, r_index).unwrap();
@@ -29,6 +36,9 @@ impl UnitControl for InterfacePrinter {
                         print!(" {read_v:?}");
                     }
                 }
+                if !metadata.description.is_empty() {
+                    print!(" // {}", metadata.description);
+                }
                 println!();
             }
             println!();
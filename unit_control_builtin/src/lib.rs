@@ -7,7 +7,13 @@ pub mod dynamic_load_control;
 
 pub mod idle;
 pub mod interface_printer;
+#[cfg(not(target_arch = "wasm32"))]
+mod line_protocol;
 pub mod naive_shoot;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod process_control;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod socket_control;
 pub mod tank_swivel_shoot;
 
 pub mod radio_position;
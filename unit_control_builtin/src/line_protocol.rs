@@ -0,0 +1,192 @@
+//! The line protocol used by the process and socket controllers, see
+//! [`process_control`](crate::process_control) for its description.
+
+use battleground_unit_control::{Interface, RegisterAccess, RegisterType};
+use std::time::Duration;
+
+/// Convert a configured timeout in seconds, rejecting zero, negative and non-finite values.
+pub fn timeout(seconds: f32) -> Result<Duration, String> {
+    Duration::try_from_secs_f32(seconds)
+        .ok()
+        .filter(|v| !v.is_zero())
+        .ok_or_else(|| format!("invalid timeout {seconds}"))
+}
+
+/// Respond to a single request line.
+pub fn respond(line: &str, interface: &mut dyn Interface) -> String {
+    match handle_request(line, interface) {
+        Some(Ok(v)) if v.is_empty() => "ok".to_owned(),
+        Some(Ok(v)) => format!("ok {v}"),
+        Some(Err(e)) => format!("err {:?}", e.error_type),
+        None => "err malformed".to_owned(),
+    }
+}
+
+/// Handle a single request, `None` if it could not be parsed.
+fn handle_request(
+    line: &str,
+    interface: &mut dyn Interface,
+) -> Option<Result<String, Box<battleground_unit_control::InterfaceError>>> {
+    let mut tokens = line.split_whitespace();
+    let command = tokens.next()?;
+    let mut next_u32 = || -> Option<u32> { tokens.next()?.parse().ok() };
+    let join = |v: Vec<u32>| {
+        v.iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    Some(match command {
+        "modules" => interface.modules().map(join),
+        "module_name" => interface.module_name(next_u32()?),
+        "registers" => interface.registers(next_u32()?).map(join),
+        "register_name" => interface.register_name(next_u32()?, next_u32()?),
+        "register_type" => interface
+            .register_type(next_u32()?, next_u32()?)
+            .map(|v| match v {
+                RegisterType::I32 => "i32".to_owned(),
+                RegisterType::F32 => "f32".to_owned(),
+                RegisterType::Bytes => "bytes".to_owned(),
+            }),
+        "register_metadata" => interface
+            .register_metadata(next_u32()?, next_u32()?)
+            .map(|v| {
+                let access = match v.access {
+                    RegisterAccess::ReadOnly => "ro",
+                    RegisterAccess::ReadWrite => "rw",
+                };
+                let unit = if v.unit.is_empty() { "-" } else { &v.unit };
+                let (min, max) = v
+                    .range
+                    .map(|(min, max)| (min.to_string(), max.to_string()))
+                    .unwrap_or(("-".to_owned(), "-".to_owned()));
+                format!("{access} {unit} {min} {max} {}", v.description)
+                    .trim_end()
+                    .to_owned()
+            }),
+        "get_i32" => interface
+            .get_i32(next_u32()?, next_u32()?)
+            .map(|v| v.to_string()),
+        "get_f32" => interface
+            .get_f32(next_u32()?, next_u32()?)
+            .map(|v| v.to_string()),
+        "set_i32" | "set_f32" | "set_bytes" => {
+            let (module, register) = (next_u32()?, next_u32()?);
+            let value = tokens.next().unwrap_or("");
+            match command {
+                "set_i32" => interface
+                    .set_i32(module, register, value.parse().ok()?)
+                    .map(|v| v.to_string()),
+                "set_f32" => interface
+                    .set_f32(module, register, value.parse().ok()?)
+                    .map(|v| v.to_string()),
+                _ => interface
+                    .set_bytes(module, register, &from_hex(value)?)
+                    .map(|_| String::new()),
+            }
+        }
+        "get_bytes" => {
+            let (module, register) = (next_u32()?, next_u32()?);
+            interface.get_bytes_len(module, register).and_then(|len| {
+                let mut v = vec![0; len];
+                interface
+                    .get_bytes(module, register, &mut v)
+                    .map(|len| to_hex(&v[..len]))
+            })
+        }
+        _ => return None,
+    })
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|v| format!("{v:02x}")).collect()
+}
+
+fn from_hex(data: &str) -> Option<Vec<u8>> {
+    if !data.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..data.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(data.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use battleground_unit_control::modules::{clock::*, differential_drive::*, draw::*, gps::*};
+    use battleground_unit_control::units::{common, tank};
+    use unit_control_mock::MockInterface;
+
+    #[test]
+    fn test_line_protocol_respond() {
+        let mut mock = MockInterface::tank();
+        mock.set_gps([1.5, 0.0, 0.25], 0.0);
+        let gps = common::MODULE_GPS;
+        let drive = tank::MODULE_TANK_DIFF_DRIVE;
+        let draw = common::MODULE_DRAW;
+        let clock = common::MODULE_CLOCK;
+        let cases = [
+            (format!("module_name {gps}"), "ok localization".to_owned()),
+            (
+                format!("registers {clock}"),
+                format!("ok {REG_CLOCK_ELAPSED}"),
+            ),
+            (
+                format!("register_name {gps} {REG_GPS_X}"),
+                "ok x".to_owned(),
+            ),
+            (
+                format!("register_type {gps} {REG_GPS_X}"),
+                "ok f32".to_owned(),
+            ),
+            (
+                format!("register_type {gps} {REG_GPS_VALID}"),
+                "ok i32".to_owned(),
+            ),
+            (format!("get_f32 {gps} {REG_GPS_X}"), "ok 1.5".to_owned()),
+            (format!("get_i32 {gps} {REG_GPS_VALID}"), "ok 1".to_owned()),
+            (
+                format!("set_f32 {drive} {REG_DIFF_DRIVE_LEFT_CMD} 0.5"),
+                "ok 0".to_owned(),
+            ),
+            (
+                format!("get_f32 {drive} {REG_DIFF_DRIVE_LEFT_CMD}"),
+                "ok 0.5".to_owned(),
+            ),
+            (
+                format!("set_bytes {draw} {REG_DRAW_LINES} 00ff"),
+                "ok".to_owned(),
+            ),
+            (
+                format!("get_bytes {draw} {REG_DRAW_LINES}"),
+                "ok 00ff".to_owned(),
+            ),
+            // Failing requests.
+            (
+                format!("get_i32 {gps} {REG_GPS_X}"),
+                "err WrongType".to_owned(),
+            ),
+            ("get_f32 12345 0".to_owned(), "err NoSuchModule".to_owned()),
+            (
+                format!("get_f32 {gps} 12345"),
+                "err NoSuchRegister".to_owned(),
+            ),
+            (
+                format!("set_bytes {draw} {REG_DRAW_LINES} 0"),
+                "err malformed".to_owned(),
+            ),
+            (
+                format!("set_f32 {drive} {REG_DIFF_DRIVE_LEFT_CMD} x"),
+                "err malformed".to_owned(),
+            ),
+            (format!("get_f32 {gps}"), "err malformed".to_owned()),
+            ("no_such_request".to_owned(), "err malformed".to_owned()),
+            ("".to_owned(), "err malformed".to_owned()),
+        ];
+        for (request, response) in cases {
+            assert_eq!(respond(&request, &mut mock), response, "for {request}");
+        }
+    }
+}
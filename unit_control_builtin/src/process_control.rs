@@ -0,0 +1,218 @@
+//! Controller that runs an external process, allowing controllers in any language.
+//!
+//! The process is started once, it communicates over its stdin and stdout with lines of text,
+//! stderr is passed through. Each update, the following happens:
+//! - The construct writes `update` to the process' stdin.
+//! - The process writes requests to its stdout, one per line, each request is answered with a
+//!   single line on its stdin.
+//! - The process writes `done`, the update is complete and the construct continues.
+//!
+//! The requests mirror the [`Interface`]:
+//! - `modules`, answered with `ok` followed by the module ids.
+//! - `module_name <module>`, answered with `ok <name>`.
+//! - `registers <module>`, answered with `ok` followed by the register ids.
+//! - `register_name <module> <register>`, answered with `ok <name>`.
+//! - `register_type <module> <register>`, answered with `ok i32`, `ok f32` or `ok bytes`.
+//! - `register_metadata <module> <register>`, answered with `ok <access> <unit> <min> <max>
+//!   <description>`, access is `ro` or `rw`, a missing unit or range is given as `-` and the
+//!   description takes the remainder of the line.
+//! - `get_i32 <module> <register>` and `get_f32 <module> <register>`, answered with `ok <value>`.
+//! - `set_i32 <module> <register> <value>` and `set_f32 <module> <register> <value>`, answered
+//!   with `ok <value>`.
+//! - `get_bytes <module> <register>`, answered with `ok <hex>`, the bytes in hexadecimal.
+//! - `set_bytes <module> <register> <hex>`, answered with `ok`.
+//!
+//! Module and register ids are decimal. Requests that fail are answered with `err <reason>`, where
+//! reason is the [`InterfaceErrorType`](battleground_unit_control::interface::InterfaceErrorType)
+//! or `malformed` if the request could not be parsed. If the process doesn't complete an update
+//! within the timeout, or exits, the controller fails.
+//!
+//! A minimal controller in Python that drives a tank forward:
+//! ```text
+//! import sys
+//! for line in sys.stdin:
+//!     print("set_f32 4096 2 1.0", flush=True)
+//!     sys.stdin.readline()
+//!     print("set_f32 4096 3 1.0", flush=True)
+//!     sys.stdin.readline()
+//!     print("done", flush=True)
+//! ```
+
+use crate::line_protocol;
+use crate::UnitControlResult;
+use battleground_unit_control::{Interface, UnitControl};
+use std::io::{BufRead, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// Default duration within which the process must complete an update.
+pub const PROCESS_CONTROL_DEFAULT_TIMEOUT: f32 = 1.0;
+
+#[derive(Debug, Clone)]
+pub struct ProcessControlConfig {
+    /// The command to run.
+    pub command: String,
+    /// Arguments passed to the command.
+    pub args: Vec<String>,
+    /// Duration within which the process must complete an update, seconds.
+    pub timeout: f32,
+}
+
+pub struct ProcessControl {
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+    timeout: Duration,
+}
+
+impl ProcessControl {
+    pub fn new(
+        config: ProcessControlConfig,
+    ) -> Result<Box<ProcessControl>, Box<dyn std::error::Error>> {
+        let timeout = line_protocol::timeout(config.timeout)?;
+        let mut child = Command::new(&config.command)
+            .args(&config.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to start {}: {}", config.command, e))?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        // Read the lines on a thread, such that we can wait for them with a timeout.
+        let (sender, lines) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for line in std::io::BufReader::new(stdout).lines() {
+                match line {
+                    Ok(line) => {
+                        if sender.send(line).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(Box::new(ProcessControl {
+            child,
+            stdin,
+            lines,
+            timeout,
+        }))
+    }
+}
+
+impl Drop for ProcessControl {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl UnitControl for ProcessControl {
+    fn update(&mut self, interface: &mut dyn Interface) -> UnitControlResult {
+        let deadline = Instant::now() + self.timeout;
+        writeln!(self.stdin, "update")?;
+        self.stdin.flush()?;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let line = match self.lines.recv_timeout(remaining) {
+                Ok(line) => line,
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(
+                        format!("process did not complete update in {:?}", self.timeout).into(),
+                    )
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err("process closed its output".into())
+                }
+            };
+            let line = line.trim();
+            if line == "done" {
+                return Ok(());
+            }
+            writeln!(self.stdin, "{}", line_protocol::respond(line, interface))?;
+            self.stdin.flush()?;
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod test {
+    use super::*;
+    use battleground_unit_control::modules::{differential_drive::*, gps::*};
+    use battleground_unit_control::units::{common, tank};
+    use unit_control_mock::MockInterface;
+
+    fn shell(script: &str, timeout: f32) -> Box<ProcessControl> {
+        ProcessControl::new(ProcessControlConfig {
+            command: "sh".to_owned(),
+            args: vec!["-c".to_owned(), script.to_owned()],
+            timeout,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_process_control() {
+        // Drives the left track with the gps x position.
+        let script = format!(
+            "while read update; do
+                echo 'get_f32 {gps} {x}'
+                read status x
+                echo \"set_f32 {drive} {left} $x\"
+                read reply
+                echo 'set_f32 {drive} {right} 1.0'
+                read reply
+                echo done
+            done",
+            gps = common::MODULE_GPS,
+            x = REG_GPS_X,
+            drive = tank::MODULE_TANK_DIFF_DRIVE,
+            left = REG_DIFF_DRIVE_LEFT_CMD,
+            right = REG_DIFF_DRIVE_RIGHT_CMD,
+        );
+        let mut control = shell(&script, 5.0);
+        let mut mock = MockInterface::tank();
+        for x in [2.5, 3.5] {
+            mock.set_gps([x, 0.0, 0.25], 0.0);
+            mock.update(control.as_mut()).unwrap();
+            let drive = tank::MODULE_TANK_DIFF_DRIVE;
+            assert_eq!(mock.f32(drive, REG_DIFF_DRIVE_LEFT_CMD), x);
+            assert_eq!(mock.f32(drive, REG_DIFF_DRIVE_RIGHT_CMD), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_process_control_failures() {
+        let mut mock = MockInterface::tank();
+
+        // Never completes the update.
+        let mut control = shell("while read update; do :; done", 0.1);
+        assert!(mock.update(control.as_mut()).is_err());
+
+        // Exits right away.
+        let mut control = shell("exit 0", 5.0);
+        assert!(mock.update(control.as_mut()).is_err());
+
+        assert!(ProcessControl::new(ProcessControlConfig {
+            command: "/nonexistent/controller".to_owned(),
+            args: vec![],
+            timeout: 1.0,
+        })
+        .is_err());
+
+        // Timeouts that can't be waited for are rejected.
+        for timeout in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(ProcessControl::new(ProcessControlConfig {
+                command: "sh".to_owned(),
+                args: vec!["-c".to_owned(), "exit 0".to_owned()],
+                timeout,
+            })
+            .is_err());
+        }
+    }
+}
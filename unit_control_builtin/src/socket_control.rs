@@ -0,0 +1,304 @@
+//! Controller that connects to a socket, such that the controller can run in a separate program.
+//!
+//! The construct connects to the endpoint, which the remote controller listens on, and uses the
+//! same line protocol as the [`process_control`](crate::process_control). The address is either
+//! `tcp:<host>:<port>` or `unix:<path>`, addresses without prefix are treated as tcp.
+//!
+//! If no connection can be made, or the connection is lost, the unit is not updated and the
+//! connection is attempted again on the next update. This allows restarting the remote controller
+//! while the simulation keeps running. The first failed attempt and a lost connection are printed.
+//! A tcp connection attempt waits at most the timeout, also in lockstep.
+//!
+//! In lockstep the simulation waits for the remote controller to complete each update, this
+//! allows pausing it in a debugger. Otherwise the simulation proceeds if the update is not
+//! complete within the timeout, the remaining requests are served during the next update.
+
+use crate::line_protocol;
+use crate::UnitControlResult;
+use battleground_unit_control::{Interface, UnitControl};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::time::{Duration, Instant};
+
+/// Default duration the simulation waits for an update if not in lockstep.
+pub const SOCKET_CONTROL_DEFAULT_TIMEOUT: f32 = 0.1;
+
+#[derive(Debug, Clone)]
+pub struct SocketControlConfig {
+    /// The endpoint to connect to, `tcp:<host>:<port>` or `unix:<path>`.
+    pub address: String,
+    /// Wait for each update to complete.
+    pub lockstep: bool,
+    /// Duration after which the simulation proceeds if not in lockstep, also bounds connection
+    /// attempts, seconds.
+    pub timeout: f32,
+}
+
+enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Stream {
+    fn connect(address: &str, timeout: Duration) -> std::io::Result<Stream> {
+        if let Some(path) = address.strip_prefix("unix:") {
+            #[cfg(unix)]
+            return Ok(Stream::Unix(UnixStream::connect(path)?));
+            #[cfg(not(unix))]
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!("unix sockets are not supported, can't connect to {path}"),
+            ));
+        }
+        let address = address.strip_prefix("tcp:").unwrap_or(address);
+        // Bound each attempt, an unreachable host would otherwise stall the simulation.
+        let mut error = std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("{address} did not resolve to any address"),
+        );
+        for address in address.to_socket_addrs()? {
+            match TcpStream::connect_timeout(&address, timeout) {
+                Ok(stream) => {
+                    stream.set_nodelay(true)?;
+                    return Ok(Stream::Tcp(stream));
+                }
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    fn try_clone(&self) -> std::io::Result<Stream> {
+        Ok(match self {
+            Stream::Tcp(v) => Stream::Tcp(v.try_clone()?),
+            #[cfg(unix)]
+            Stream::Unix(v) => Stream::Unix(v.try_clone()?),
+        })
+    }
+
+    fn set_read_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        match self {
+            Stream::Tcp(v) => v.set_read_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(v) => v.set_read_timeout(timeout),
+        }
+    }
+}
+
+impl std::io::Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Tcp(v) => v.read(buf),
+            #[cfg(unix)]
+            Stream::Unix(v) => v.read(buf),
+        }
+    }
+}
+
+impl std::io::Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Tcp(v) => v.write(buf),
+            #[cfg(unix)]
+            Stream::Unix(v) => v.write(buf),
+        }
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Stream::Tcp(v) => v.flush(),
+            #[cfg(unix)]
+            Stream::Unix(v) => v.flush(),
+        }
+    }
+}
+
+struct Connection {
+    reader: BufReader<Stream>,
+    writer: Stream,
+    /// Partially received line, retained if reading times out.
+    line: String,
+    /// The remote controller has not completed the previous update yet.
+    in_update: bool,
+}
+
+pub struct SocketControl {
+    config: SocketControlConfig,
+    timeout: Duration,
+    connection: Option<Connection>,
+    /// The previous connection attempt failed, to only report the first of repeated failures.
+    connect_failed: bool,
+}
+
+impl SocketControl {
+    pub fn new(
+        config: SocketControlConfig,
+    ) -> Result<Box<SocketControl>, Box<dyn std::error::Error>> {
+        let timeout = line_protocol::timeout(config.timeout)?;
+        Ok(Box::new(SocketControl {
+            config,
+            timeout,
+            connection: None,
+            connect_failed: false,
+        }))
+    }
+
+    fn connect(&self) -> std::io::Result<Connection> {
+        let stream = Stream::connect(&self.config.address, self.timeout)?;
+        Ok(Connection {
+            writer: stream.try_clone()?,
+            reader: BufReader::new(stream),
+            line: String::new(),
+            in_update: false,
+        })
+    }
+
+    /// Serve requests until the update is done, returns false if the timeout was reached.
+    fn serve(
+        &mut self,
+        connection: &mut Connection,
+        interface: &mut dyn Interface,
+    ) -> std::io::Result<bool> {
+        let deadline = Instant::now() + self.timeout;
+        if !connection.in_update {
+            writeln!(connection.writer, "update")?;
+            connection.writer.flush()?;
+            connection.in_update = true;
+        }
+        loop {
+            let timeout = if self.config.lockstep {
+                None
+            } else {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Ok(false);
+                }
+                Some(remaining)
+            };
+            connection.reader.get_ref().set_read_timeout(timeout)?;
+            match connection.reader.read_line(&mut connection.line) {
+                Ok(0) => {
+                    return Err(std::io::ErrorKind::UnexpectedEof.into());
+                }
+                Ok(_) => {}
+                Err(e)
+                    if e.kind() == std::io::ErrorKind::WouldBlock
+                        || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    return Ok(false);
+                }
+                Err(e) => return Err(e),
+            }
+            let line = std::mem::take(&mut connection.line);
+            let line = line.trim();
+            if line == "done" {
+                connection.in_update = false;
+                return Ok(true);
+            }
+            writeln!(
+                connection.writer,
+                "{}",
+                line_protocol::respond(line, interface)
+            )?;
+            connection.writer.flush()?;
+        }
+    }
+}
+
+impl UnitControl for SocketControl {
+    fn update(&mut self, interface: &mut dyn Interface) -> UnitControlResult {
+        let mut connection = match self.connection.take() {
+            Some(connection) => connection,
+            None => match self.connect() {
+                Ok(connection) => {
+                    self.connect_failed = false;
+                    connection
+                }
+                // Not available (yet), try again next update.
+                Err(e) => {
+                    if !self.connect_failed {
+                        println!(
+                            "Failed to connect to {}: {}, retrying every update",
+                            self.config.address, e
+                        );
+                        self.connect_failed = true;
+                    }
+                    return Ok(());
+                }
+            },
+        };
+        // Keep the connection unless it failed, the remote controller may reconnect later.
+        match self.serve(&mut connection, interface) {
+            Ok(_) => self.connection = Some(connection),
+            Err(e) => println!("Lost connection to {}: {}", self.config.address, e),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use battleground_unit_control::modules::{differential_drive::*, gps::*};
+    use battleground_unit_control::units::{common, tank};
+    use std::net::TcpListener;
+    use unit_control_mock::MockInterface;
+
+    #[test]
+    fn test_socket_control_loopback() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("tcp:{}", listener.local_addr().unwrap());
+
+        // Remote controller that drives the left track with the gps x position, for two updates.
+        let remote = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut lines = BufReader::new(stream).lines();
+            for _ in 0..2 {
+                assert_eq!(lines.next().unwrap().unwrap(), "update");
+                writeln!(writer, "get_f32 {} {}", common::MODULE_GPS, REG_GPS_X).unwrap();
+                let reply = lines.next().unwrap().unwrap();
+                let x = reply.strip_prefix("ok ").unwrap();
+                let drive = tank::MODULE_TANK_DIFF_DRIVE;
+                writeln!(writer, "set_f32 {drive} {REG_DIFF_DRIVE_LEFT_CMD} {x}").unwrap();
+                assert!(lines.next().unwrap().unwrap().starts_with("ok"));
+                writeln!(writer, "done").unwrap();
+            }
+        });
+
+        let mut control = SocketControl::new(SocketControlConfig {
+            address,
+            lockstep: true,
+            timeout: SOCKET_CONTROL_DEFAULT_TIMEOUT,
+        })
+        .unwrap();
+        let mut mock = MockInterface::tank();
+        for x in [2.5, 3.5] {
+            mock.set_gps([x, 0.0, 0.25], 0.0);
+            mock.update(control.as_mut()).unwrap();
+            let drive = tank::MODULE_TANK_DIFF_DRIVE;
+            assert_eq!(mock.f32(drive, REG_DIFF_DRIVE_LEFT_CMD), x);
+        }
+        remote.join().unwrap();
+
+        // The remote controller is gone, the unit is no longer updated but doesn't fail.
+        mock.update(control.as_mut()).unwrap();
+        assert!(control.connection.is_none());
+        mock.update(control.as_mut()).unwrap();
+        assert!(control.connect_failed);
+    }
+
+    #[test]
+    fn test_socket_control_invalid_timeout() {
+        for timeout in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert!(SocketControl::new(SocketControlConfig {
+                address: "tcp:127.0.0.1:1".to_owned(),
+                lockstep: false,
+                timeout,
+            })
+            .is_err());
+        }
+    }
+}
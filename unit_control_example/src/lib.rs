@@ -164,3 +164,23 @@ fn draw_stuff(interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::E
 pub fn create_unit_control() -> Box<dyn UnitControl> {
     Box::new(UnitControlExample::default())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use unit_control_mock::MockInterface;
+
+    #[test]
+    fn test_tank_rotates_in_place() {
+        let mut mock = MockInterface::tank();
+        let mut control = UnitControlExample::default();
+        mock.run(&mut control, 10, |mock, i| {
+            mock.set_gps([i as f32, 0.0, 0.25], 0.0)
+        })
+        .unwrap();
+        let drive = tank::MODULE_TANK_DIFF_DRIVE;
+        assert_eq!(mock.f32(drive, REG_DIFF_DRIVE_LEFT_CMD), 0.1);
+        assert_eq!(mock.f32(drive, REG_DIFF_DRIVE_RIGHT_CMD), -0.1);
+        assert!(!mock.bytes(common::MODULE_DRAW, REG_DRAW_LINES).is_empty());
+    }
+}
@@ -0,0 +1,844 @@
+//! This crate provides a mock of the [`Interface`], to unit test controllers without the construct.
+//!
+//! The [`MockInterface`] holds the modules and registers of a unit as they are right after it is
+//! spawned, [`MockInterface::tank`] and [`MockInterface::artillery`] provide the layouts of the
+//! respective units. A test sets sensor values on the mock, runs a number of updates of the
+//! controller and asserts on the registers the controller wrote:
+//! ```
+//! use battleground_unit_control::modules::{differential_drive::*, gps::*};
+//! use battleground_unit_control::units::{common, tank};
+//! use battleground_unit_control::{Interface, UnitControl};
+//! use unit_control_mock::MockInterface;
+//!
+//! /// Drives forward until it passes x = 5.
+//! struct Forward;
+//! impl UnitControl for Forward {
+//!     fn update(&mut self, interface: &mut dyn Interface) -> Result<(), Box<dyn std::error::Error>> {
+//!         let x = interface.get_f32(common::MODULE_GPS, REG_GPS_X)?;
+//!         let cmd = if x < 5.0 { 1.0 } else { 0.0 };
+//!         interface.set_f32(tank::MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD, cmd)?;
+//!         interface.set_f32(tank::MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_RIGHT_CMD, cmd)?;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut mock = MockInterface::tank();
+//! mock.update(&mut Forward).unwrap();
+//! assert_eq!(mock.f32(tank::MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD), 1.0);
+//!
+//! mock.set_gps([6.0, 0.0, 0.25], 0.0);
+//! mock.update(&mut Forward).unwrap();
+//! assert_eq!(mock.f32(tank::MODULE_TANK_DIFF_DRIVE, REG_DIFF_DRIVE_LEFT_CMD), 0.0);
+//! ```
+//!
+//! The mock only depends on [`battleground_unit_control`], so it can be used as a dev-dependency
+//! of controllers that are compiled to wasm.
+
+use battleground_unit_control::control_log::{LogModule, LogRegister, LogValue};
+use battleground_unit_control::interface::{InterfaceError, InterfaceErrorType};
+use battleground_unit_control::modules::clock::REG_CLOCK_ELAPSED;
+use battleground_unit_control::units::common::MODULE_CLOCK;
+use battleground_unit_control::{
+    Interface, RegisterAccess, RegisterMetadata, RegisterType, UnitControl,
+};
+use std::collections::BTreeMap;
+
+/// Helpers to set sensor values and retrieve actuator values.
+pub mod sensors;
+pub use sensors::Reflection;
+
+/// The layouts of the units.
+mod units;
+
+type BoxedError = Box<InterfaceError>;
+
+/// Default duration between updates, this is the same as the construct's update rate.
+pub const MOCK_UPDATE_INTERVAL: f32 = 0.01;
+
+struct MockRegister {
+    name: String,
+    value: LogValue,
+    max_len: usize,
+    metadata: RegisterMetadata,
+}
+
+struct MockModule {
+    name: String,
+    registers: BTreeMap<u32, MockRegister>,
+}
+
+/// A scriptable [`Interface`] implementation, holding the registers of a unit.
+pub struct MockInterface {
+    modules: BTreeMap<u32, MockModule>,
+    writes: Vec<(u32, u32, LogValue)>,
+    update_interval: f32,
+}
+
+impl Default for MockInterface {
+    fn default() -> Self {
+        MockInterface {
+            modules: Default::default(),
+            writes: vec![],
+            update_interval: MOCK_UPDATE_INTERVAL,
+        }
+    }
+}
+
+impl MockInterface {
+    /// Create a mock without any modules.
+    pub fn new() -> Self {
+        MockInterface::default()
+    }
+
+    /// Create a mock with the modules and registers of a freshly spawned tank.
+    pub fn tank() -> Self {
+        units::tank()
+    }
+
+    /// Create a mock with the modules and registers of a freshly spawned artillery unit.
+    pub fn artillery() -> Self {
+        units::artillery()
+    }
+
+    /// Set the duration the clock advances with each update.
+    pub fn set_update_interval(&mut self, interval: f32) {
+        self.update_interval = interval;
+    }
+
+    /// Advance the clock and run an update of the controller.
+    pub fn update(
+        &mut self,
+        control: &mut dyn UnitControl,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.value(MODULE_CLOCK, REG_CLOCK_ELAPSED).is_some() {
+            let time = self.f32(MODULE_CLOCK, REG_CLOCK_ELAPSED) + self.update_interval;
+            self.put_f32(MODULE_CLOCK, REG_CLOCK_ELAPSED, time);
+        }
+        self.writes.clear();
+        control.update(self)
+    }
+
+    /// Run a number of updates, calling `before` ahead of each update to script the sensors.
+    pub fn run(
+        &mut self,
+        control: &mut dyn UnitControl,
+        updates: usize,
+        mut before: impl FnMut(&mut MockInterface, usize),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for i in 0..updates {
+            before(self, i);
+            self.update(control)?;
+        }
+        Ok(())
+    }
+
+    /// The writes made by the controller during the last update, in order.
+    pub fn writes(&self) -> &[(u32, u32, LogValue)] {
+        &self.writes
+    }
+
+    /// The modules and registers of this mock.
+    pub fn layout(&self) -> Vec<LogModule> {
+        self.modules
+            .iter()
+            .map(|(id, module)| LogModule {
+                id: *id,
+                name: module.name.clone(),
+                registers: module
+                    .registers
+                    .iter()
+                    .map(|(id, register)| LogRegister {
+                        id: *id,
+                        name: register.name.clone(),
+                        register_type: register.value.register_type(),
+                        access: register.metadata.access,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Add a module, replacing any existing module with this id.
+    pub fn add_module(&mut self, module: u32, name: &str) {
+        self.modules.insert(
+            module,
+            MockModule {
+                name: name.to_owned(),
+                registers: Default::default(),
+            },
+        );
+    }
+
+    /// Remove a module.
+    pub fn remove_module(&mut self, module: u32) {
+        self.modules.remove(&module);
+    }
+
+    /// Add a register to a module, replacing any existing register with this id.
+    pub fn add_register(&mut self, module: u32, register: u32, name: &str, value: LogValue) {
+        self.add_register_max(module, register, name, value, usize::MAX);
+    }
+
+    /// Add a register, where byte values written by the controller are limited to `max_len`.
+    pub fn add_register_max(
+        &mut self,
+        module: u32,
+        register: u32,
+        name: &str,
+        value: LogValue,
+        max_len: usize,
+    ) {
+        self.modules
+            .get_mut(&module)
+            .unwrap_or_else(|| panic!("module {module:#x} does not exist"))
+            .registers
+            .insert(
+                register,
+                MockRegister {
+                    name: name.to_owned(),
+                    value,
+                    max_len,
+                    metadata: Default::default(),
+                },
+            );
+    }
+
+    /// Set the metadata of an existing register, panics if it doesn't exist.
+    pub fn set_metadata(&mut self, module: u32, register: u32, metadata: RegisterMetadata) {
+        self.modules
+            .get_mut(&module)
+            .and_then(|m| m.registers.get_mut(&register))
+            .unwrap_or_else(|| panic!("register {module:#x}:{register:#x} does not exist"))
+            .metadata = metadata;
+    }
+
+    /// Remove a register from a module.
+    pub fn remove_register(&mut self, module: u32, register: u32) {
+        if let Some(m) = self.modules.get_mut(&module) {
+            m.registers.remove(&register);
+        }
+    }
+
+    /// Retrieve the value of a register, if it exists.
+    pub fn value(&self, module: u32, register: u32) -> Option<&LogValue> {
+        self.modules
+            .get(&module)
+            .and_then(|m| m.registers.get(&register))
+            .map(|r| &r.value)
+    }
+
+    /// Set the value of an existing register, panics if it doesn't exist or has a different type.
+    pub fn put(&mut self, module: u32, register: u32, value: LogValue) {
+        let r = self
+            .modules
+            .get_mut(&module)
+            .and_then(|m| m.registers.get_mut(&register))
+            .unwrap_or_else(|| panic!("register {module:#x}:{register:#x} does not exist"));
+        assert_eq!(
+            r.value.register_type(),
+            value.register_type(),
+            "register {module:#x}:{register:#x} has a different type"
+        );
+        r.value = value;
+    }
+
+    /// Set an i32 register.
+    pub fn put_i32(&mut self, module: u32, register: u32, value: i32) {
+        self.put(module, register, LogValue::I32(value));
+    }
+
+    /// Set an f32 register.
+    pub fn put_f32(&mut self, module: u32, register: u32, value: f32) {
+        self.put(module, register, LogValue::F32(value));
+    }
+
+    /// Set a bytes register.
+    pub fn put_bytes(&mut self, module: u32, register: u32, value: &[u8]) {
+        self.put(module, register, LogValue::Bytes(value.to_vec()));
+    }
+
+    /// Retrieve an i32 register, panics if it doesn't exist or has a different type.
+    pub fn i32(&self, module: u32, register: u32) -> i32 {
+        match self.value(module, register) {
+            Some(LogValue::I32(v)) => *v,
+            v => panic!("register {module:#x}:{register:#x} is not an i32: {v:?}"),
+        }
+    }
+
+    /// Retrieve an f32 register, panics if it doesn't exist or has a different type.
+    pub fn f32(&self, module: u32, register: u32) -> f32 {
+        match self.value(module, register) {
+            Some(LogValue::F32(v)) => *v,
+            v => panic!("register {module:#x}:{register:#x} is not an f32: {v:?}"),
+        }
+    }
+
+    /// Retrieve a bytes register, panics if it doesn't exist or has a different type.
+    pub fn bytes(&self, module: u32, register: u32) -> &[u8] {
+        match self.value(module, register) {
+            Some(LogValue::Bytes(v)) => v,
+            v => panic!("register {module:#x}:{register:#x} is not bytes: {v:?}"),
+        }
+    }
+
+    fn interface_error(module: u32, register: u32, error_type: InterfaceErrorType) -> BoxedError {
+        Box::new(InterfaceError {
+            module,
+            register,
+            error_type,
+        })
+    }
+
+    fn get_module(&self, module: u32) -> Result<&MockModule, BoxedError> {
+        self.modules
+            .get(&module)
+            .ok_or_else(|| Self::interface_error(module, 0, InterfaceErrorType::NoSuchModule))
+    }
+
+    fn get_register(&self, module: u32, register: u32) -> Result<&MockRegister, BoxedError> {
+        self.get_module(module)?
+            .registers
+            .get(&register)
+            .ok_or_else(|| {
+                Self::interface_error(module, register, InterfaceErrorType::NoSuchRegister)
+            })
+    }
+
+    fn get_register_mut(
+        &mut self,
+        module: u32,
+        register: u32,
+    ) -> Result<&mut MockRegister, BoxedError> {
+        self.modules
+            .get_mut(&module)
+            .ok_or_else(|| Self::interface_error(module, 0, InterfaceErrorType::NoSuchModule))?
+            .registers
+            .get_mut(&register)
+            .ok_or_else(|| {
+                Self::interface_error(module, register, InterfaceErrorType::NoSuchRegister)
+            })
+    }
+
+    /// Write a value from the controller, returning the old value.
+    fn write(
+        &mut self,
+        module: u32,
+        register: u32,
+        value: LogValue,
+    ) -> Result<LogValue, BoxedError> {
+        let r = self.get_register_mut(module, register)?;
+        if r.metadata.access == RegisterAccess::ReadOnly {
+            return Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::ReadOnly,
+            ));
+        }
+        if r.value.register_type() != value.register_type() {
+            return Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            ));
+        }
+        if let LogValue::Bytes(v) = &value {
+            if v.len() > r.max_len {
+                return Err(Self::interface_error(
+                    module,
+                    register,
+                    InterfaceErrorType::WriteOverflow,
+                ));
+            }
+        }
+        let old = std::mem::replace(&mut r.value, value.clone());
+        self.writes.push((module, register, value));
+        Ok(old)
+    }
+}
+
+impl Interface for MockInterface {
+    fn modules(&self) -> Result<Vec<u32>, BoxedError> {
+        Ok(self.modules.keys().copied().collect())
+    }
+
+    fn module_name(&self, module: u32) -> Result<String, BoxedError> {
+        Ok(self.get_module(module)?.name.clone())
+    }
+
+    fn registers(&self, module: u32) -> Result<Vec<u32>, BoxedError> {
+        Ok(self.get_module(module)?.registers.keys().copied().collect())
+    }
+
+    fn register_name(&self, module: u32, register: u32) -> Result<String, BoxedError> {
+        Ok(self.get_register(module, register)?.name.clone())
+    }
+
+    fn register_type(&self, module: u32, register: u32) -> Result<RegisterType, BoxedError> {
+        Ok(self.get_register(module, register)?.value.register_type())
+    }
+
+    fn register_metadata(
+        &self,
+        module: u32,
+        register: u32,
+    ) -> Result<RegisterMetadata, BoxedError> {
+        Ok(self.get_register(module, register)?.metadata.clone())
+    }
+
+    fn get_i32(&self, module: u32, register: u32) -> Result<i32, BoxedError> {
+        match self.get_register(module, register)?.value {
+            LogValue::I32(v) => Ok(v),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_i32(&mut self, module: u32, register: u32, value: i32) -> Result<i32, BoxedError> {
+        match self.write(module, register, LogValue::I32(value))? {
+            LogValue::I32(v) => Ok(v),
+            _ => unreachable!("type is checked on write"),
+        }
+    }
+
+    fn get_f32(&self, module: u32, register: u32) -> Result<f32, BoxedError> {
+        match self.get_register(module, register)?.value {
+            LogValue::F32(v) => Ok(v),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_f32(&mut self, module: u32, register: u32, value: f32) -> Result<f32, BoxedError> {
+        match self.write(module, register, LogValue::F32(value))? {
+            LogValue::F32(v) => Ok(v),
+            _ => unreachable!("type is checked on write"),
+        }
+    }
+
+    fn get_bytes_len(&self, module: u32, register: u32) -> Result<usize, BoxedError> {
+        match &self.get_register(module, register)?.value {
+            LogValue::Bytes(v) => Ok(v.len()),
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn get_bytes(
+        &self,
+        module: u32,
+        register: u32,
+        destination: &mut [u8],
+    ) -> Result<usize, BoxedError> {
+        match &self.get_register(module, register)?.value {
+            LogValue::Bytes(v) => {
+                if destination.len() < v.len() {
+                    return Err(Self::interface_error(
+                        module,
+                        register,
+                        InterfaceErrorType::ReadOverflow,
+                    ));
+                }
+                destination[..v.len()].copy_from_slice(v);
+                Ok(v.len())
+            }
+            _ => Err(Self::interface_error(
+                module,
+                register,
+                InterfaceErrorType::WrongType,
+            )),
+        }
+    }
+
+    fn set_bytes(&mut self, module: u32, register: u32, values: &[u8]) -> Result<(), BoxedError> {
+        self.write(module, register, LogValue::Bytes(values.to_vec()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use battleground_unit_control::modules::{
+        differential_drive::*, radar::*, radio_receiver::*, radio_transmitter::*,
+    };
+    use battleground_unit_control::units::{common, tank};
+
+    /// Sends the distance of the first radar reflection over the radio, clears received messages.
+    struct Relay;
+    impl UnitControl for Relay {
+        fn update(
+            &mut self,
+            interface: &mut dyn Interface,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let radar = tank::MODULE_TANK_RADAR;
+            if interface.get_i32(radar, REG_RADAR_REFLECTION_COUNT)? > 0 {
+                let reg = REG_RADAR_REFLECTION_START + REG_RADAR_REFLECTION_OFFSET_DISTANCE;
+                let distance = interface.get_f32(radar, reg)?;
+                let tx = common::MODULE_RADIO_TRANSMITTER;
+                interface.set_bytes(tx, REG_RADIO_TX_MSG_START, &distance.to_le_bytes())?;
+                interface.set_i32(tx, REG_RADIO_TX_MSG_COUNT, 1)?;
+            }
+            interface.set_i32(common::MODULE_RADIO_RECEIVER, REG_RADIO_RX_MSG_COUNT, 0)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mock_sensors_and_outputs() {
+        let mut mock = MockInterface::tank();
+        let mut relay = Relay;
+        mock.run(&mut relay, 3, |mock, i| {
+            mock.set_radar_reflections(
+                tank::MODULE_TANK_RADAR,
+                &[Reflection {
+                    distance: i as f32,
+                    ..Default::default()
+                }],
+            );
+            mock.receive_radio(1.0, None, &[i as u8]);
+        })
+        .unwrap();
+        assert_eq!(
+            mock.radio_transmitted(),
+            vec![2.0f32.to_le_bytes().to_vec()]
+        );
+        assert_eq!(
+            mock.i32(common::MODULE_RADIO_RECEIVER, REG_RADIO_RX_MSG_COUNT),
+            0
+        );
+        assert_eq!(
+            mock.f32(MODULE_CLOCK, REG_CLOCK_ELAPSED),
+            3.0 * MOCK_UPDATE_INTERVAL
+        );
+        assert_eq!(mock.writes().len(), 3);
+
+        // Fewer reflections remove the stale registers.
+        mock.set_radar_reflections(tank::MODULE_TANK_RADAR, &[]);
+        assert!(mock
+            .value(tank::MODULE_TANK_RADAR, REG_RADAR_REFLECTION_START)
+            .is_none());
+    }
+
+    #[test]
+    fn test_mock_control_log_layout_changes() {
+        use battleground_unit_control::control_log::{replay, ControlLog};
+        let mut mock = MockInterface::tank();
+        let mut log = ControlLog::default();
+        // The reflection registers only exist from the second update onwards.
+        for i in 0..3 {
+            if i == 1 {
+                mock.set_radar_reflections(
+                    tank::MODULE_TANK_RADAR,
+                    &[Reflection {
+                        distance: 4.0,
+                        ..Default::default()
+                    }],
+                );
+            }
+            log.record(i as f32, &mut mock, &mut Relay).unwrap();
+        }
+        assert!(log.updates[0].modules.is_none());
+        assert!(log.updates[1].modules.is_some());
+        assert!(log.updates[2].modules.is_none());
+        assert_eq!(
+            log.modules_at(2),
+            log.updates[1].modules.as_deref().unwrap()
+        );
+
+        // The reflection is replayed, so the same distance is sent.
+        assert!(replay(&log, &mut Relay).is_empty());
+    }
+
+    #[test]
+    fn test_mock_interface_errors() {
+        let mut mock = MockInterface::tank();
+        let error_type = |e: Box<InterfaceError>| e.error_type;
+        assert_eq!(
+            mock.get_i32(0xFFFF, 0).map_err(error_type),
+            Err(InterfaceErrorType::NoSuchModule)
+        );
+        assert_eq!(
+            mock.get_i32(MODULE_CLOCK, 0xFFFF).map_err(error_type),
+            Err(InterfaceErrorType::NoSuchRegister)
+        );
+        let drive = tank::MODULE_TANK_DIFF_DRIVE;
+        assert_eq!(
+            mock.set_i32(drive, REG_DIFF_DRIVE_LEFT_CMD, 1)
+                .map_err(error_type),
+            Err(InterfaceErrorType::WrongType)
+        );
+        assert_eq!(
+            mock.set_f32(MODULE_CLOCK, REG_CLOCK_ELAPSED, 1.0)
+                .map_err(error_type),
+            Err(InterfaceErrorType::ReadOnly)
+        );
+        let tx = common::MODULE_RADIO_TRANSMITTER;
+        assert_eq!(
+            mock.set_bytes(tx, REG_RADIO_TX_MSG_START, &[0; 33])
+                .map_err(error_type),
+            Err(InterfaceErrorType::WriteOverflow)
+        );
+        assert!(mock.writes().is_empty());
+        assert_eq!(
+            mock.set_f32(drive, REG_DIFF_DRIVE_LEFT_CMD, 1.0).ok(),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_mock_batched_values() {
+        use battleground_unit_control::modules::gps::*;
+        let mut mock = MockInterface::tank();
+        mock.set_gps([1.0, 2.0, 0.25], 0.5);
+        let values = mock.get_values(&[common::MODULE_GPS]).unwrap();
+        assert!(values.contains(&(common::MODULE_GPS, REG_GPS_Y, LogValue::F32(2.0))));
+        assert!(values.iter().all(|(m, _, _)| *m == common::MODULE_GPS));
+        let all = mock.get_values(&mock.modules().unwrap()).unwrap();
+        assert!(all.len() > values.len());
+
+        let tx = common::MODULE_RADIO_TRANSMITTER;
+        mock.set_values(&[
+            (tx, REG_RADIO_TX_MSG_START, LogValue::Bytes(vec![3])),
+            (tx, REG_RADIO_TX_MSG_COUNT, LogValue::I32(1)),
+            (tx, REG_RADIO_TX_MSG_COUNT, LogValue::F32(1.0)),
+            (tx, REG_RADIO_TX_MSG_COUNT, LogValue::I32(0)),
+        ])
+        .map_err(|e| assert_eq!(e.error_type, InterfaceErrorType::WrongType))
+        .unwrap_err();
+        // Writes before the failing one remain applied, later ones are not.
+        assert_eq!(mock.radio_transmitted(), vec![vec![3]]);
+    }
+
+    #[test]
+    fn test_mock_batched_requests() {
+        use battleground_unit_control::interface::{
+            get_values_request, set_values_request, values_from_le_bytes, values_to_le_bytes,
+        };
+        use battleground_unit_control::modules::gps::*;
+        let mut mock = MockInterface::tank();
+        mock.set_gps([1.0, 2.0, 0.25], 0.5);
+        let error = |(e, i): (Box<InterfaceError>, u32)| (e.error_type, i);
+
+        // The host side of the wasm calls, a request of module ids.
+        let modules = [common::MODULE_CLOCK, common::MODULE_GPS];
+        let request: Vec<u8> = modules.iter().flat_map(|m| m.to_le_bytes()).collect();
+        let response = get_values_request(&mock, &request).unwrap();
+        assert_eq!(
+            values_from_le_bytes(&response).unwrap(),
+            mock.get_values(&modules).unwrap()
+        );
+        assert_eq!(
+            get_values_request(&mock, &request[..6]).map_err(error),
+            Err((InterfaceErrorType::WrongType, 1))
+        );
+        let mut request = request;
+        request.extend(0xFFFFu32.to_le_bytes());
+        assert_eq!(
+            get_values_request(&mock, &request).map_err(error),
+            Err((InterfaceErrorType::NoSuchModule, 2))
+        );
+
+        let tx = common::MODULE_RADIO_TRANSMITTER;
+        let request = values_to_le_bytes(&[
+            (tx, REG_RADIO_TX_MSG_START, LogValue::Bytes(vec![3])),
+            (tx, REG_RADIO_TX_MSG_COUNT, LogValue::I32(1)),
+        ]);
+        assert_eq!(set_values_request(&mut mock, &request).ok(), Some(2));
+        assert_eq!(mock.radio_transmitted(), vec![vec![3]]);
+        assert_eq!(
+            set_values_request(&mut mock, &request[..request.len() - 1]).map_err(error),
+            Err((InterfaceErrorType::WrongType, 0))
+        );
+        let request = values_to_le_bytes(&[
+            (tx, REG_RADIO_TX_MSG_COUNT, LogValue::I32(0)),
+            (common::MODULE_GPS, REG_GPS_X, LogValue::F32(5.0)),
+        ]);
+        assert_eq!(
+            set_values_request(&mut mock, &request).map_err(error),
+            Err((InterfaceErrorType::ReadOnly, 1))
+        );
+        assert_eq!(mock.i32(tx, REG_RADIO_TX_MSG_COUNT), 0);
+    }
+
+    #[test]
+    fn test_mock_radio_link() {
+        use battleground_unit_control::modules::unit::REG_UNIT_UNIT_ID;
+        use battleground_unit_control::radio::{RadioLink, RadioLinkConfig};
+        let config = RadioLinkConfig {
+            ack_channel: Some(1),
+            ..Default::default()
+        };
+        let (tx, rx) = (
+            common::MODULE_RADIO_TRANSMITTER,
+            common::MODULE_RADIO_RECEIVER,
+        );
+        let mut mock_a = MockInterface::tank();
+        let mut mock_b = MockInterface::tank();
+        mock_b.put_i32(common::MODULE_UNIT, REG_UNIT_UNIT_ID, 2);
+        let mut link_a = RadioLink::new(config);
+        let mut link_b = RadioLink::new(config);
+
+        // The message doesn't fit in a single transmission.
+        let message = (3i32, String::from("enemy spotted near the north ridge"));
+        link_a.send(&message);
+        link_a.update(&mut mock_a).unwrap();
+        let fragments = mock_a.radio_transmitted();
+        assert_eq!(fragments.len(), 2);
+        assert_eq!(mock_a.i32(tx, REG_RADIO_TX_CHANNEL_SELECT), 0);
+        // Listening for the acknowledgement.
+        assert_eq!(mock_a.i32(rx, REG_RADIO_RX_CHANNEL_SELECT), 1);
+
+        for fragment in fragments.iter() {
+            mock_b.receive_radio(1.0, None, fragment);
+        }
+        link_b.update(&mut mock_b).unwrap();
+        assert_eq!(mock_b.i32(rx, REG_RADIO_RX_MSG_COUNT), 0);
+        let received = link_b.receive::<(i32, String)>().unwrap();
+        assert_eq!(received.sender, 1);
+        assert_eq!(received.message, message);
+
+        // The acknowledgement is sent on its own channel.
+        let acks = mock_b.radio_transmitted();
+        assert_eq!(acks.len(), 1);
+        assert_eq!(mock_b.i32(tx, REG_RADIO_TX_CHANNEL_SELECT), 1);
+        mock_a.put_i32(tx, REG_RADIO_TX_MSG_COUNT, 0);
+        mock_a.receive_radio(1.0, None, &acks[0]);
+        link_a.update(&mut mock_a).unwrap();
+        assert_eq!(link_a.unacknowledged(), 0);
+        assert_eq!(mock_a.i32(rx, REG_RADIO_RX_CHANNEL_SELECT), 0);
+    }
+
+    #[test]
+    fn test_mock_sdk() {
+        use battleground_unit_control::modules::objectives::*;
+        use battleground_unit_control::sdk::{self, Artillery, Tank, Unit};
+
+        let mut mock = MockInterface::tank();
+        mock.set_gps([1.0, 2.0, 0.25], 0.5);
+        mock.set_radar_reflections(
+            tank::MODULE_TANK_RADAR,
+            &[Reflection {
+                yaw: 0.1,
+                pitch: 0.2,
+                distance: 3.0,
+                strength: 1.0,
+            }],
+        );
+        mock.receive_radio(0.5, Some(1.0), &[7]);
+        mock.add_register(
+            common::MODULE_OBJECTIVES,
+            REG_OBJECTIVES_CAPTURE_POINT_START + REG_OBJECTIVES_CAPTURE_POINT_OFFSET_OWNER,
+            "owner",
+            LogValue::I32(OBJECTIVES_CAPTURE_POINT_UNKNOWN),
+        );
+        for (offset, value) in [
+            (REG_OBJECTIVES_CAPTURE_POINT_OFFSET_X, 4.0),
+            (REG_OBJECTIVES_CAPTURE_POINT_OFFSET_Y, 5.0),
+            (REG_OBJECTIVES_CAPTURE_POINT_OFFSET_RADIUS, 2.0),
+        ] {
+            let register = REG_OBJECTIVES_CAPTURE_POINT_START + offset;
+            mock.add_register(
+                common::MODULE_OBJECTIVES,
+                register,
+                "",
+                LogValue::F32(value),
+            );
+        }
+        mock.put_i32(
+            common::MODULE_OBJECTIVES,
+            REG_OBJECTIVES_CAPTURE_POINT_COUNT,
+            1,
+        );
+
+        assert!(Artillery::new(&mut mock).is_err());
+        let mut tank = Tank::new(&mut mock).unwrap();
+        assert_eq!(tank.gps().position().unwrap(), [1.0, 2.0, 0.25]);
+        assert_eq!(
+            tank.radar().reflections().unwrap(),
+            vec![sdk::Reflection {
+                yaw: 0.1,
+                pitch: 0.2,
+                distance: 3.0,
+                strength: 1.0
+            }]
+        );
+        assert_eq!(
+            tank.objectives().capture_points().unwrap(),
+            vec![sdk::CapturePoint {
+                position: [4.0, 5.0],
+                radius: 2.0,
+                owner: sdk::CapturePointOwner::Unknown,
+            }]
+        );
+        assert_eq!(tank.lidar().ranges().unwrap(), vec![20.0; 32]);
+        assert_eq!(
+            tank.radio().messages().unwrap(),
+            vec![sdk::RadioReceived {
+                strength: 0.5,
+                bearing: Some(1.0),
+                data: vec![7]
+            }]
+        );
+        tank.turret().set_velocity(0.3).unwrap();
+        tank.base().set_velocities(1.0, -1.0).unwrap();
+        tank.cannon().fire().unwrap();
+        for i in 0..16 {
+            tank.radio().send(&[i]).unwrap();
+        }
+        assert!(tank.radio().send(&[16]).is_err());
+        tank.draw().line([0.0; 3], [1.0; 3], 0.1, [0; 4]).unwrap();
+        tank.draw().line([0.0; 3], [1.0; 3], 0.1, [0; 4]).unwrap();
+
+        use battleground_unit_control::modules::{cannon::*, draw::*, revolute::*};
+        let segment = |x| LineSegment {
+            p0: [x; 3],
+            p1: [1.0; 3],
+            width: 0.1,
+            color: [0; 4],
+        };
+        tank.draw().lines(&[segment(2.0), segment(3.0)]).unwrap();
+        let turret = tank::MODULE_TANK_REVOLUTE_TURRET;
+        assert_eq!(mock.f32(turret, REG_REVOLUTE_VELOCITY_CMD), 0.3);
+        assert_eq!(mock.i32(tank::MODULE_TANK_CANNON, REG_CANNON_TRIGGER), 1);
+        assert_eq!(mock.radio_transmitted().len(), 16);
+        let line_len = std::mem::size_of::<LineSegment>();
+        let lines = mock.bytes(common::MODULE_DRAW, REG_DRAW_LINES);
+        assert_eq!(lines.len(), 4 * line_len);
+        let last: [u8; std::mem::size_of::<LineSegment>()] =
+            lines[3 * line_len..].try_into().unwrap();
+        assert_eq!(LineSegment::from(last), segment(3.0));
+
+        // Changing a register type is caught on validation.
+        mock.remove_register(tank::MODULE_TANK_RADAR, REG_RADAR_RANGE_MAX);
+        mock.add_register(
+            tank::MODULE_TANK_RADAR,
+            REG_RADAR_RANGE_MAX,
+            "",
+            LogValue::I32(0),
+        );
+        let e = Tank::new(&mut mock).err().unwrap();
+        assert_eq!(e.error_type, InterfaceErrorType::WrongType);
+        assert_eq!(
+            Tank::validate(&mock).unwrap_err().error_type,
+            InterfaceErrorType::WrongType
+        );
+        assert!(Artillery::new(&mut MockInterface::artillery()).is_ok());
+
+        // Validated once, the interface is wrapped without checks afterwards.
+        let mut artillery = MockInterface::artillery();
+        Artillery::validate(&artillery).unwrap();
+        Artillery::new_unchecked(&mut artillery)
+            .base()
+            .set_velocities(0.5, 0.5)
+            .unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use crate::MockInterface;
+use battleground_unit_control::control_log::LogValue;
+use battleground_unit_control::modules::clock::REG_CLOCK_ELAPSED;
+use battleground_unit_control::modules::{gps::*, lidar::*, radar::*};
+use battleground_unit_control::modules::{radio_receiver::*, radio_transmitter::*};
+use battleground_unit_control::units::common;
+
+/// A radar reflection, as seen in the radar's frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Reflection {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub strength: f32,
+}
+
+impl MockInterface {
+    /// Set the position and yaw reported by the gps.
+    pub fn set_gps(&mut self, position: [f32; 3], yaw: f32) {
+        self.put_f32(common::MODULE_GPS, REG_GPS_X, position[0]);
+        self.put_f32(common::MODULE_GPS, REG_GPS_Y, position[1]);
+        self.put_f32(common::MODULE_GPS, REG_GPS_Z, position[2]);
+        self.put_f32(common::MODULE_GPS, REG_GPS_YAW, yaw);
+        let time = self.f32(common::MODULE_CLOCK, REG_CLOCK_ELAPSED);
+        self.put_f32(common::MODULE_GPS, REG_GPS_FIX_TIME, time);
+    }
+
+    /// Replace the reflections reported by the radar module.
+    pub fn set_radar_reflections(&mut self, module: u32, reflections: &[Reflection]) {
+        let previous = self.i32(module, REG_RADAR_REFLECTION_COUNT).max(0) as u32;
+        for i in 0..previous {
+            let base = REG_RADAR_REFLECTION_START + i * REG_RADAR_REFLECTION_STRIDE;
+            for offset in 0..REG_RADAR_REFLECTION_STRIDE {
+                self.remove_register(module, base + offset);
+            }
+        }
+        for (i, reflection) in reflections.iter().enumerate() {
+            let base = REG_RADAR_REFLECTION_START + i as u32 * REG_RADAR_REFLECTION_STRIDE;
+            for (offset, name, value) in [
+                (REG_RADAR_REFLECTION_OFFSET_YAW, "yaw", reflection.yaw),
+                (REG_RADAR_REFLECTION_OFFSET_PITCH, "pitch", reflection.pitch),
+                (
+                    REG_RADAR_REFLECTION_OFFSET_DISTANCE,
+                    "distance",
+                    reflection.distance,
+                ),
+                (
+                    REG_RADAR_REFLECTION_OFFSET_STRENGTH,
+                    "strength",
+                    reflection.strength,
+                ),
+            ] {
+                self.add_register(module, base + offset, name, LogValue::F32(value));
+            }
+        }
+        self.put_i32(module, REG_RADAR_REFLECTION_COUNT, reflections.len() as i32);
+    }
+
+    /// Set the ranges reported by the lidar module, one per ray.
+    pub fn set_lidar_ranges(&mut self, module: u32, ranges: &[f32]) {
+        let bytes = ranges
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+        self.put_bytes(module, REG_LIDAR_RANGES, &bytes);
+        self.put_i32(module, REG_LIDAR_RAY_COUNT, ranges.len() as i32);
+    }
+
+    /// Append a message to the radio receiver's buffer, the bearing is only reported if provided.
+    pub fn receive_radio(&mut self, strength: f32, bearing: Option<f32>, data: &[u8]) {
+        let module = common::MODULE_RADIO_RECEIVER;
+        let index = self.i32(module, REG_RADIO_RX_MSG_COUNT).max(0) as u32;
+        let base = REG_RADIO_RX_MSG_START + index * REG_RADIO_RX_MSG_STRIDE;
+        self.add_register(
+            module,
+            base + REG_RADIO_RX_MSG_OFFSET_STRENGTH,
+            "message_strength",
+            LogValue::F32(strength),
+        );
+        self.add_register(
+            module,
+            base + REG_RADIO_RX_MSG_OFFSET_DATA,
+            "message_data",
+            LogValue::Bytes(data.to_vec()),
+        );
+        if let Some(bearing) = bearing {
+            self.add_register(
+                module,
+                REG_RADIO_RX_BEARING_START + index,
+                "message_bearing",
+                LogValue::F32(bearing),
+            );
+        } else {
+            self.remove_register(module, REG_RADIO_RX_BEARING_START + index);
+        }
+        self.put_i32(module, REG_RADIO_RX_MSG_COUNT, index as i32 + 1);
+    }
+
+    /// The messages the controller queued on the radio transmitter.
+    pub fn radio_transmitted(&self) -> Vec<Vec<u8>> {
+        let module = common::MODULE_RADIO_TRANSMITTER;
+        let count = self.i32(module, REG_RADIO_TX_MSG_COUNT).max(0) as u32;
+        let limit = self.i32(module, REG_RADIO_TX_MSG_COUNT_LIMIT).max(0) as u32;
+        (0..count.min(limit))
+            .map(|i| self.bytes(module, REG_RADIO_TX_MSG_START + i).to_vec())
+            .collect()
+    }
+}
@@ -0,0 +1,571 @@
+//! The modules and registers of each unit as they are right after spawning, this mirrors the
+//! modules the construct adds in its `units` module.
+
+use crate::MockInterface;
+use battleground_unit_control::control_log::LogValue;
+use battleground_unit_control::modules::*;
+use battleground_unit_control::units::{artillery, common, tank, UnitType};
+use battleground_unit_control::{RegisterAccess, RegisterMetadata};
+
+use LogValue::{F32, I32};
+
+/// Add a module with the provided registers, these are read-only like in the construct.
+fn add(mock: &mut MockInterface, module: u32, name: &str, registers: &[(u32, &str, LogValue)]) {
+    mock.add_module(module, name);
+    for (register, register_name, value) in registers {
+        add_read_only(mock, module, *register, register_name, value.clone());
+    }
+}
+
+fn add_read_only(
+    mock: &mut MockInterface,
+    module: u32,
+    register: u32,
+    name: &str,
+    value: LogValue,
+) {
+    mock.add_register(module, register, name, value);
+    mock.set_metadata(
+        module,
+        register,
+        RegisterMetadata {
+            access: RegisterAccess::ReadOnly,
+            ..Default::default()
+        },
+    );
+}
+
+/// Mark the registers the controller writes to as writable.
+fn writable(mock: &mut MockInterface, module: u32, registers: &[u32]) {
+    for register in registers {
+        mock.set_metadata(module, *register, Default::default());
+    }
+}
+
+fn bytes() -> LogValue {
+    LogValue::Bytes(vec![])
+}
+
+/// The modules shared by all units.
+fn add_common(mock: &mut MockInterface, unit_type: UnitType) {
+    use clock::*;
+    add(
+        mock,
+        common::MODULE_CLOCK,
+        "clock",
+        &[(REG_CLOCK_ELAPSED, "elapsed", F32(0.0))],
+    );
+
+    use objectives::*;
+    add(
+        mock,
+        common::MODULE_OBJECTIVES,
+        "objectives",
+        &[
+            (REG_OBJECTIVES_FOG_OF_WAR, "fog_of_war", I32(0)),
+            (
+                REG_OBJECTIVES_CAPTURE_POINT_COUNT,
+                "capture_point_count",
+                I32(0),
+            ),
+            (REG_OBJECTIVES_FLAG_COUNT, "flag_count", I32(0)),
+        ],
+    );
+
+    use team::*;
+    add(
+        mock,
+        common::MODULE_TEAM,
+        "team",
+        &[(REG_TEAM_TEAMID, "team", I32(TEAM_NO_TEAM))],
+    );
+
+    use unit::*;
+    add(
+        mock,
+        common::MODULE_UNIT,
+        "unit",
+        &[
+            (REG_UNIT_UNIT_ID, "unit_id", I32(1)),
+            (REG_UNIT_UNIT_TYPE, "unit_type", I32(unit_type as i32)),
+        ],
+    );
+
+    use radio_transmitter::*;
+    const MESSAGE_SIZE_LIMIT: usize = 32;
+    const MESSAGE_COUNT_LIMIT: u32 = 16;
+    add(
+        mock,
+        common::MODULE_RADIO_TRANSMITTER,
+        "radio_transmitter",
+        &[
+            (REG_RADIO_TX_RANGE_MAX, "transmit_range_max", F32(30.0)),
+            (REG_RADIO_TX_INTERVAL, "transmit_interval", F32(0.01)),
+            (REG_RADIO_TX_CHANNEL_MIN, "channel_min", I32(0)),
+            (REG_RADIO_TX_CHANNEL_MAX, "channel_max", I32(0)),
+            (
+                REG_RADIO_TX_MSG_SIZE_LIMIT,
+                "message_size_limit",
+                I32(MESSAGE_SIZE_LIMIT as i32),
+            ),
+            (
+                REG_RADIO_TX_MSG_COUNT_LIMIT,
+                "message_count_limit",
+                I32(MESSAGE_COUNT_LIMIT as i32),
+            ),
+            (REG_RADIO_TX_CHANNEL_SELECT, "channel_select", I32(0)),
+            (REG_RADIO_TX_MSG_COUNT, "payload_count", I32(0)),
+        ],
+    );
+    writable(
+        mock,
+        common::MODULE_RADIO_TRANSMITTER,
+        &[REG_RADIO_TX_CHANNEL_SELECT, REG_RADIO_TX_MSG_COUNT],
+    );
+    for i in 0..MESSAGE_COUNT_LIMIT {
+        mock.add_register_max(
+            common::MODULE_RADIO_TRANSMITTER,
+            REG_RADIO_TX_MSG_START + i,
+            "message",
+            bytes(),
+            MESSAGE_SIZE_LIMIT,
+        );
+    }
+
+    use radio_receiver::*;
+    add(
+        mock,
+        common::MODULE_RADIO_RECEIVER,
+        "radio_receiver",
+        &[
+            (REG_RADIO_RX_CHANNEL_MIN, "channel_min", I32(0)),
+            (REG_RADIO_RX_CHANNEL_MAX, "channel_max", I32(0)),
+            (REG_RADIO_RX_MSG_COUNT_LIMIT, "message_count_limit", I32(64)),
+            (REG_RADIO_RX_DIRECTION_FINDING, "direction_finding", I32(0)),
+            (REG_RADIO_RX_CHANNEL_SELECT, "channel_select", I32(0)),
+            (REG_RADIO_RX_MSG_OVERFLOW, "message_overflow", I32(0)),
+            (REG_RADIO_RX_MSG_COUNT, "message_count", I32(0)),
+        ],
+    );
+    writable(
+        mock,
+        common::MODULE_RADIO_RECEIVER,
+        &[
+            REG_RADIO_RX_CHANNEL_SELECT,
+            REG_RADIO_RX_MSG_OVERFLOW,
+            REG_RADIO_RX_MSG_COUNT,
+        ],
+    );
+
+    use wind::*;
+    add(
+        mock,
+        common::MODULE_WIND,
+        "wind",
+        &[
+            (REG_WIND_X, "x", F32(0.0)),
+            (REG_WIND_Y, "y", F32(0.0)),
+            (REG_WIND_DRAG_COEFFICIENT, "drag_coefficient", F32(0.0)),
+        ],
+    );
+
+    use gps::*;
+    add(
+        mock,
+        common::MODULE_GPS,
+        "localization",
+        &[
+            (REG_GPS_X, "x", F32(0.0)),
+            (REG_GPS_Y, "y", F32(0.0)),
+            (REG_GPS_Z, "z", F32(0.25)),
+            (REG_GPS_ROLL, "r", F32(0.0)),
+            (REG_GPS_PITCH, "p", F32(0.0)),
+            (REG_GPS_YAW, "y", F32(0.0)),
+            (REG_GPS_VALID, "valid", I32(1)),
+            (REG_GPS_FIX_TIME, "fix_time", F32(0.0)),
+        ],
+    );
+
+    use draw::*;
+    add(
+        mock,
+        common::MODULE_DRAW,
+        "draw",
+        &[(REG_DRAW_LINES, "instructions", bytes())],
+    );
+    writable(mock, common::MODULE_DRAW, &[REG_DRAW_LINES]);
+
+    use mine_layer::*;
+    add(
+        mock,
+        common::MODULE_MINE_LAYER,
+        "mine_layer",
+        &[
+            (REG_MINE_LAYER_DEPLOY, "deploy", I32(0)),
+            (REG_MINE_LAYER_IS_TRIGGERED, "is_triggered", I32(0)),
+            (REG_MINE_LAYER_READY, "ready", I32(1)),
+            (REG_MINE_LAYER_REMAINING, "remaining", I32(3)),
+            (REG_MINE_LAYER_ARMING_DELAY, "arming_delay", F32(3.0)),
+            (REG_MINE_LAYER_TRIGGER_RADIUS, "trigger_radius", F32(0.75)),
+            (REG_MINE_LAYER_DEPLOY_INTERVAL, "deploy_interval", F32(1.0)),
+        ],
+    );
+    writable(mock, common::MODULE_MINE_LAYER, &[REG_MINE_LAYER_DEPLOY]);
+
+    use odometry::*;
+    add(
+        mock,
+        common::MODULE_ODOMETRY,
+        "odometry",
+        &[
+            (REG_ODOMETRY_X, "x", F32(0.0)),
+            (REG_ODOMETRY_Y, "y", F32(0.0)),
+            (REG_ODOMETRY_YAW, "yaw", F32(0.0)),
+            (REG_ODOMETRY_VELOCITY, "velocity", F32(0.0)),
+            (REG_ODOMETRY_YAW_RATE, "yaw_rate", F32(0.0)),
+        ],
+    );
+
+    use imu::*;
+    add(
+        mock,
+        common::MODULE_IMU,
+        "imu",
+        &[
+            (REG_IMU_ANGULAR_RATE_X, "angular_rate_x", F32(0.0)),
+            (REG_IMU_ANGULAR_RATE_Y, "angular_rate_y", F32(0.0)),
+            (REG_IMU_ANGULAR_RATE_Z, "angular_rate_z", F32(0.0)),
+            (REG_IMU_ACCELERATION_X, "acceleration_x", F32(0.0)),
+            (REG_IMU_ACCELERATION_Y, "acceleration_y", F32(0.0)),
+            (REG_IMU_ACCELERATION_Z, "acceleration_z", F32(0.0)),
+        ],
+    );
+
+    use damage::*;
+    add(
+        mock,
+        common::MODULE_DAMAGE,
+        "damage",
+        &[
+            (REG_DAMAGE_HEALTH, "health", F32(1.0)),
+            (REG_DAMAGE_TOTAL_HITS, "total_hits", I32(0)),
+            (REG_DAMAGE_HIT_COUNT, "hits", I32(0)),
+        ],
+    );
+
+    use orders::*;
+    add(
+        mock,
+        common::MODULE_ORDERS,
+        "orders",
+        &[
+            (REG_ORDERS_SEQUENCE, "sequence", I32(0)),
+            (REG_ORDERS_TIME, "time", F32(0.0)),
+            (REG_ORDERS_ORDER, "order", bytes()),
+        ],
+    );
+}
+
+fn add_diff_drive(mock: &mut MockInterface, module: u32, track_width: f32, velocity: f32) {
+    use differential_drive::*;
+    add(
+        mock,
+        module,
+        "diff_drive",
+        &[
+            (REG_DIFF_DRIVE_LEFT_VEL, "left_wheel_vel", F32(0.0)),
+            (REG_DIFF_DRIVE_RIGHT_VEL, "right_wheel_vel", F32(0.0)),
+            (REG_DIFF_DRIVE_LEFT_CMD, "left_wheel_cmd", F32(0.0)),
+            (REG_DIFF_DRIVE_RIGHT_CMD, "right_wheel_cmd", F32(0.0)),
+            (REG_DIFF_DRIVE_TRACK_WIDTH, "track_width", F32(track_width)),
+            (
+                REG_DIFF_DRIVE_ACCELERATION_LOWER,
+                "acceleration_lower",
+                F32(-0.5),
+            ),
+            (
+                REG_DIFF_DRIVE_ACCELERATION_UPPER,
+                "acceleration_upper",
+                F32(0.5),
+            ),
+            (
+                REG_DIFF_DRIVE_VELOCITY_LOWER,
+                "velocity_lower",
+                F32(-velocity),
+            ),
+            (
+                REG_DIFF_DRIVE_VELOCITY_UPPER,
+                "velocity_upper",
+                F32(velocity),
+            ),
+            (REG_DIFF_DRIVE_INTEGRITY, "integrity", F32(1.0)),
+        ],
+    );
+    writable(
+        mock,
+        module,
+        &[REG_DIFF_DRIVE_LEFT_CMD, REG_DIFF_DRIVE_RIGHT_CMD],
+    );
+}
+
+fn add_revolute(
+    mock: &mut MockInterface,
+    module: u32,
+    name: &str,
+    position: f32,
+    acceleration: f32,
+) {
+    use revolute::*;
+    add(
+        mock,
+        module,
+        name,
+        &[
+            (REG_REVOLUTE_POSITION, "position", F32(position)),
+            (REG_REVOLUTE_VELOCITY, "velocity", F32(0.0)),
+            (
+                REG_REVOLUTE_ACCELERATION_LOWER,
+                "acceleration_lower",
+                F32(-acceleration),
+            ),
+            (
+                REG_REVOLUTE_ACCELERATION_UPPER,
+                "acceleration_upper",
+                F32(acceleration),
+            ),
+            (REG_REVOLUTE_VELOCITY_CMD, "velocity_cmd", F32(0.0)),
+            (REG_REVOLUTE_INTEGRITY, "integrity", F32(1.0)),
+        ],
+    );
+    writable(mock, module, &[REG_REVOLUTE_VELOCITY_CMD]);
+}
+
+fn add_radar(mock: &mut MockInterface, module: u32, range_max: f32) {
+    use radar::*;
+    add(
+        mock,
+        module,
+        "radar",
+        &[
+            (REG_RADAR_RANGE_MAX, "range_max", F32(range_max)),
+            (
+                REG_RADAR_DETECTION_ANGLE_YAW,
+                "detection_angle_yaw",
+                F32(10.0f32.to_radians()),
+            ),
+            (
+                REG_RADAR_DETECTION_ANGLE_PITCH,
+                "detection_angle_pitch",
+                F32(std::f32::consts::PI),
+            ),
+            (REG_RADAR_INTEGRITY, "integrity", F32(1.0)),
+            (REG_RADAR_REFLECTION_COUNT, "reflections", I32(0)),
+        ],
+    );
+}
+
+fn add_lidar(mock: &mut MockInterface, module: u32) {
+    use lidar::*;
+    const RAY_COUNT: usize = 32;
+    const LIDAR_RANGE_MAX: f32 = 20.0;
+    let ranges = LIDAR_RANGE_MAX.to_le_bytes().repeat(RAY_COUNT);
+    add(
+        mock,
+        module,
+        "lidar",
+        &[
+            (REG_LIDAR_RANGE_MAX, "range_max", F32(LIDAR_RANGE_MAX)),
+            (
+                REG_LIDAR_FAN_ANGLE,
+                "fan_angle",
+                F32(std::f32::consts::FRAC_PI_2),
+            ),
+            (REG_LIDAR_RAY_COUNT, "ray_count", I32(RAY_COUNT as i32)),
+            (REG_LIDAR_INTEGRITY, "integrity", F32(1.0)),
+            (REG_LIDAR_RANGES, "ranges", LogValue::Bytes(ranges)),
+        ],
+    );
+}
+
+pub fn tank() -> MockInterface {
+    let mut mock = MockInterface::new();
+    add_common(&mut mock, UnitType::Tank);
+    add_diff_drive(&mut mock, tank::MODULE_TANK_DIFF_DRIVE, 1.0, 1.0);
+    add_revolute(
+        &mut mock,
+        tank::MODULE_TANK_REVOLUTE_TURRET,
+        "turret",
+        0.0,
+        1.0,
+    );
+    add_revolute(
+        &mut mock,
+        tank::MODULE_TANK_REVOLUTE_BARREL,
+        "barrel",
+        0.0,
+        2.0,
+    );
+    add_revolute(
+        &mut mock,
+        tank::MODULE_TANK_REVOLUTE_RADAR,
+        "radar_rotation",
+        0.0,
+        std::f32::consts::PI,
+    );
+    add_radar(&mut mock, tank::MODULE_TANK_RADAR, 20.0);
+    add_lidar(&mut mock, tank::MODULE_TANK_LIDAR);
+
+    use cannon::*;
+    add(
+        &mut mock,
+        tank::MODULE_TANK_CANNON,
+        "cannon",
+        &[
+            (REG_CANNON_TRIGGER, "trigger", I32(0)),
+            (REG_CANNON_IS_TRIGGERED, "is_triggered", I32(0)),
+            (REG_CANNON_READY, "ready", I32(1)),
+            (REG_CANNON_RELOAD_TIME, "reload_time", F32(2.0)),
+            (REG_CANNON_INTEGRITY, "integrity", F32(1.0)),
+        ],
+    );
+    writable(&mut mock, tank::MODULE_TANK_CANNON, &[REG_CANNON_TRIGGER]);
+
+    use missile_launcher::*;
+    add(
+        &mut mock,
+        tank::MODULE_TANK_MISSILE_LAUNCHER,
+        "missile_launcher",
+        &[
+            (REG_MISSILE_LAUNCHER_TRIGGER, "trigger", I32(0)),
+            (REG_MISSILE_LAUNCHER_IS_TRIGGERED, "is_triggered", I32(0)),
+            (REG_MISSILE_LAUNCHER_READY, "ready", I32(1)),
+            (REG_MISSILE_LAUNCHER_RELOAD_TIME, "reload_time", F32(10.0)),
+            (
+                REG_MISSILE_LAUNCHER_SEEKER_MODE,
+                "seeker_mode",
+                I32(MISSILE_SEEKER_MODE_NONE),
+            ),
+            (REG_MISSILE_LAUNCHER_TARGET_X, "target_x", F32(0.0)),
+            (REG_MISSILE_LAUNCHER_TARGET_Y, "target_y", F32(0.0)),
+            (REG_MISSILE_LAUNCHER_TARGET_Z, "target_z", F32(0.0)),
+            (
+                REG_MISSILE_LAUNCHER_MISSILE_SPEED,
+                "missile_speed",
+                F32(6.0),
+            ),
+            (
+                REG_MISSILE_LAUNCHER_MISSILE_TURN_RATE,
+                "missile_turn_rate",
+                F32(1.5),
+            ),
+            (REG_MISSILE_LAUNCHER_MISSILE_FUEL, "missile_fuel", F32(5.0)),
+            (REG_MISSILE_LAUNCHER_SEEKER_RANGE, "seeker_range", F32(15.0)),
+            (
+                REG_MISSILE_LAUNCHER_SEEKER_ANGLE,
+                "seeker_angle",
+                F32(30.0f32.to_radians()),
+            ),
+        ],
+    );
+    writable(
+        &mut mock,
+        tank::MODULE_TANK_MISSILE_LAUNCHER,
+        &[
+            REG_MISSILE_LAUNCHER_TRIGGER,
+            REG_MISSILE_LAUNCHER_SEEKER_MODE,
+            REG_MISSILE_LAUNCHER_TARGET_X,
+            REG_MISSILE_LAUNCHER_TARGET_Y,
+            REG_MISSILE_LAUNCHER_TARGET_Z,
+        ],
+    );
+    mock
+}
+
+pub fn artillery() -> MockInterface {
+    let mut mock = MockInterface::new();
+    add_common(&mut mock, UnitType::Artillery);
+    add_diff_drive(&mut mock, artillery::MODULE_ARTILLERY_DIFF_DRIVE, 1.75, 0.5);
+    add_revolute(
+        &mut mock,
+        artillery::MODULE_ARTILLERY_REVOLUTE_TURRET,
+        "turret",
+        0.0,
+        1.0,
+    );
+    // The barrel starts pitched upwards.
+    add_revolute(
+        &mut mock,
+        artillery::MODULE_ARTILLERY_REVOLUTE_BARREL,
+        "barrel",
+        std::f32::consts::TAU - 0.3,
+        2.0,
+    );
+    add_revolute(
+        &mut mock,
+        artillery::MODULE_ARTILLERY_REVOLUTE_RADAR,
+        "radar_rotation",
+        0.0,
+        std::f32::consts::PI,
+    );
+    add_radar(&mut mock, artillery::MODULE_ARTILLERY_RADAR, 10.0);
+    add_lidar(&mut mock, artillery::MODULE_ARTILLERY_LIDAR);
+
+    use gun_battery::*;
+    const GUN_OFFSET: f32 = 0.15;
+    add(
+        &mut mock,
+        artillery::MODULE_ARTILLERY_GUN_BATTERY,
+        "gun_battery",
+        &[
+            (REG_GUN_BATTERY_FIRING, "firing", I32(0)),
+            (REG_GUN_BATTERY_IS_TRIGGERED, "is_triggered", I32(0)),
+            (REG_GUN_BATTERY_READY, "ready", I32(1)),
+            (REG_GUN_BATTERY_GUN_RELOAD, "gun_reload", F32(0.0)),
+            (
+                REG_GUN_BATTERY_INTER_GUN_DURATION,
+                "inter_gun_duration",
+                F32(0.3),
+            ),
+            (REG_GUN_BATTERY_RELOAD, "battery_reload", F32(5.0)),
+            (REG_GUN_BATTERY_FIRE_INDEX, "fire_index", I32(0)),
+            (REG_GUN_BATTERY_INTEGRITY, "integrity", F32(1.0)),
+            (REG_GUN_BATTERY_COUNT, "gun_count", I32(16)),
+        ],
+    );
+    writable(
+        &mut mock,
+        artillery::MODULE_ARTILLERY_GUN_BATTERY,
+        &[REG_GUN_BATTERY_FIRING],
+    );
+    // Ordered top left to bottom right.
+    let mut index = 0;
+    for z in [1.5, 0.5, -0.5, -1.5] {
+        for y in [-1.5, -0.5, 0.5, 1.5] {
+            let base = REG_GUN_BATTERY_START + index * REG_GUN_BATTERY_STRIDE;
+            for (offset, name, value) in [
+                (REG_GUN_BATTERY_OFFSET_X, "x", F32(0.0)),
+                (REG_GUN_BATTERY_OFFSET_Y, "y", F32(y * GUN_OFFSET)),
+                (REG_GUN_BATTERY_OFFSET_Z, "z", F32(z * GUN_OFFSET)),
+                (REG_GUN_BATTERY_OFFSET_ROLL, "roll", F32(0.0)),
+                (REG_GUN_BATTERY_OFFSET_PITCH, "pitch", F32(0.0)),
+                (REG_GUN_BATTERY_OFFSET_YAW, "yaw", F32(0.0)),
+                (
+                    REG_GUN_BATTERY_OFFSET_LAST_FIRE_TIME,
+                    "last_fire_time",
+                    F32(0.0),
+                ),
+                (REG_GUN_BATTERY_OFFSET_READY, "ready", I32(1)),
+            ] {
+                add_read_only(
+                    &mut mock,
+                    artillery::MODULE_ARTILLERY_GUN_BATTERY,
+                    base + offset,
+                    name,
+                    value,
+                );
+            }
+            index += 1;
+        }
+    }
+    mock
+}
@@ -1,3 +1,4 @@
+use battleground_unit_control::interface::{get_values_request, set_values_request};
 use battleground_unit_control::register_interface::RegisterInterface;
 use battleground_unit_control::{Interface, InterfaceError, UnitControl};
 use std::time::SystemTime;
@@ -167,14 +168,20 @@ impl UnitControlWasm {
         }
 
         fn send_string_result(
-            mut caller: Caller<'_, State>,
+            caller: Caller<'_, State>,
             res: Result<String, Box<InterfaceError>>,
+        ) -> usize {
+            send_bytes_result(caller, res.map(|v| v.into_bytes()))
+        }
+
+        fn send_bytes_result(
+            mut caller: Caller<'_, State>,
+            res: Result<Vec<u8>, Box<InterfaceError>>,
         ) -> usize {
             let wasm_transmission_buffer = get_wasm_transmission_buffer(&mut caller);
             let wasm_set_error = get_wasm_set_error(&mut caller);
             match res {
-                Ok(v) => {
-                    let data = v.into_bytes();
+                Ok(data) => {
                     let data_width = data.len();
                     let p = wasm_transmission_buffer
                         .call(&mut caller, data_width as u32)
@@ -199,6 +206,16 @@ impl UnitControlWasm {
             }
         }
 
+        fn receive_bytes(caller: &mut Caller<'_, State>, src: u32, len: u32) -> Vec<u8> {
+            let mem = caller
+                .get_export("memory")
+                .expect("memory should exist")
+                .into_memory()
+                .expect("was not memory");
+            let (bytes, _storage) = mem.data_and_store_mut(caller);
+            bytes[src as usize..(src as usize + len as usize)].to_vec()
+        }
+
         fn send_pod_result<C>(
             mut caller: Caller<'_, State>,
             res: Result<C, Box<InterfaceError>>,
@@ -274,6 +291,19 @@ impl UnitControlWasm {
             },
         )?;
 
+        linker.func_wrap(
+            "env",
+            "wasm_interface_register_metadata",
+            |caller: Caller<'_, State>, module: u32, register: u32| -> u32 {
+                let res = caller
+                    .data()
+                    .register_interface
+                    .register_metadata(module, register)
+                    .map(|v| v.to_le_bytes());
+                send_bytes_result(caller, res) as u32
+            },
+        )?;
+
         linker.func_wrap(
             "env",
             "wasm_interface_get_i32",
@@ -395,6 +425,33 @@ impl UnitControlWasm {
             },
         )?;
 
+        // The batched calls return the index of the module or value that failed on error, such
+        // that the wasm side can report which register caused it.
+        linker.func_wrap(
+            "env",
+            "wasm_interface_get_values",
+            |mut caller: Caller<'_, State>, src: u32, len: u32| -> u32 {
+                let request = receive_bytes(&mut caller, src, len);
+                let res = get_values_request(&caller.data().register_interface, &request);
+                match res {
+                    Ok(v) => send_bytes_result(caller, Ok(v)) as u32,
+                    Err((e, i)) => send_pod_result(caller, Err(e), i),
+                }
+            },
+        )?;
+        linker.func_wrap(
+            "env",
+            "wasm_interface_set_values",
+            |mut caller: Caller<'_, State>, src: u32, len: u32| -> u32 {
+                let request = receive_bytes(&mut caller, src, len);
+                let res = set_values_request(&mut caller.data_mut().register_interface, &request);
+                match res {
+                    Ok(v) => v,
+                    Err((e, i)) => send_pod_result(caller, Err(e), i),
+                }
+            },
+        )?;
+
         linker.func_wrap(
             "env",
             "wasm_log_record",